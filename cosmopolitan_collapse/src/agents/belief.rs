@@ -1,5 +1,6 @@
 use agents::belief_types::BeliefTypes;
 use components::domain::components::Components;
+use cosmopolitan_collapse_error::CosmopolitanCollapseError;
 
 #[derive(Debug, Clone)]
 pub struct Belief {
@@ -8,9 +9,15 @@ pub struct Belief {
 }
 
 impl Belief {
-    pub fn new(coordinate: Components, belief: BeliefTypes) -> Result<Belief, String> {
+    pub fn new(
+        coordinate: Components,
+        belief: BeliefTypes,
+    ) -> Result<Belief, CosmopolitanCollapseError> {
         if !coordinate.is_coordinate() {
-            panic!("Attempted to create a belief, but the coordinate passed wasn't a Components::Coordinate: {:?}", coordinate);
+            return Err(CosmopolitanCollapseError::NotACoordinate(format!(
+                "{:?}",
+                coordinate
+            )));
         }
 
         Ok(Belief { coordinate, belief })
@@ -1,4 +1,5 @@
 use components::domain::components::Components;
+use cosmopolitan_collapse_error::CosmopolitanCollapseError;
 use std::collections::HashMap;
 
 pub struct ComponentsController {
@@ -20,21 +21,6 @@ impl ComponentsController {
         &mut self.components
     }
 
-    fn crash_if_there_are_no_components_for_entity(&self, entity_id: u32) {
-        if !self.components.contains_key(&entity_id) {
-            panic!("Attempted to retrieve a component of an entity, but there were no entries for entity {:?}.", entity_id);
-        }
-    }
-
-    fn crash_if_there_are_more_than_one_match_for_condition(
-        &self,
-        matched_components: &[&Components],
-    ) {
-        if matched_components.len() > 1 {
-            panic!("Attempted to retrieve a component of an entity, but it had more than one component matching the passed condition. Retrieved: {:?}", matched_components);
-        }
-    }
-
     pub fn get_mut_components(&mut self) -> &mut HashMap<u32, Vec<Components>> {
         &mut self.components
     }
@@ -43,33 +29,33 @@ impl ComponentsController {
         &self,
         entity_id: u32,
         condition: T,
-    ) -> Result<&Components, String> {
-        self.crash_if_there_are_no_components_for_entity(entity_id);
-
-        let matched_components: Vec<&Components> = self
-            .components
-            .get(&entity_id)
-            .unwrap()
-            .iter()
-            .filter(condition)
-            .collect();
-
-        self.crash_if_there_are_more_than_one_match_for_condition(&matched_components);
-
-        Ok(matched_components.first().unwrap())
+    ) -> Result<&Components, CosmopolitanCollapseError> {
+        let matched_components: Vec<&Components> = match self.components.get(&entity_id) {
+            Some(components) => components.iter().filter(condition).collect(),
+            None => return Err(CosmopolitanCollapseError::EntityNotFound { entity_id }),
+        };
+
+        match matched_components.len() {
+            0 => Err(CosmopolitanCollapseError::NoMatchingComponent { entity_id }),
+            1 => Ok(matched_components[0]),
+            number_of_matches => Err(CosmopolitanCollapseError::MoreThanOneMatchingComponent {
+                entity_id,
+                number_of_matches,
+            }),
+        }
     }
 
     pub fn manipulate_component<
         T: Fn(&u32, &mut Vec<Components>) -> bool,
-        U: Fn(&u32, &mut Vec<Components>),
+        U: Fn(&u32, &mut Vec<Components>) -> Result<(), CosmopolitanCollapseError>,
     >(
         &mut self,
         matching_condition: T,
         operation_to_perform_on_matching: U,
-    ) -> Result<(), String> {
+    ) -> Result<(), CosmopolitanCollapseError> {
         for (id, components) in &mut self.components {
             if matching_condition(id, components) {
-                operation_to_perform_on_matching(id, components);
+                operation_to_perform_on_matching(id, components)?;
 
                 break;
             }
@@ -83,18 +69,16 @@ impl ComponentsController {
         entity_id: u32,
         condition: T,
     ) -> bool {
-        if !self.components.contains_key(&entity_id) {
-            return false;
-        }
-
         self.components
             .get(&entity_id)
-            .unwrap()
-            .iter()
-            .any(condition)
+            .is_some_and(|components| components.iter().any(condition))
     }
 
-    pub fn add(&mut self, entity_id: u32, component: Components) -> Result<(), String> {
+    pub fn add(
+        &mut self,
+        entity_id: u32,
+        component: Components,
+    ) -> Result<(), CosmopolitanCollapseError> {
         self.components
             .entry(entity_id)
             .or_insert_with(Vec::new)
@@ -106,7 +90,7 @@ impl ComponentsController {
     pub fn get_entity_id_matching_condition<T: Fn(&Vec<Components>) -> bool>(
         &self,
         condition: T,
-    ) -> Result<u32, String> {
+    ) -> Result<u32, CosmopolitanCollapseError> {
         let matching_ids = self
             .components
             .iter()
@@ -115,11 +99,15 @@ impl ComponentsController {
             .collect::<Vec<&u32>>();
 
         if matching_ids.is_empty() {
-            panic!("Attempted to get the entity id matching a condition, but there was none!");
+            return Err(CosmopolitanCollapseError::NoEntityMatchingCondition);
         }
 
         if matching_ids.len() > 1 {
-            panic!("Attempted to get the entity id matching a condition, but there was more than one! Matching: {:?}", matching_ids);
+            return Err(
+                CosmopolitanCollapseError::MoreThanOneEntityMatchingCondition {
+                    matching_ids: matching_ids.into_iter().copied().collect(),
+                },
+            );
         }
 
         Ok(**matching_ids.first().unwrap())
@@ -179,28 +167,37 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
     fn test_attempting_to_retrieve_a_component_when_the_entity_has_more_than_one_of_them_it_will_fail(
-    ) {
+    ) -> Result<(), String> {
         let mut components_controller = ComponentsController::new();
 
         let mut id_generator = IdGenerator::new();
 
         let entity_id = id_generator.generate();
 
-        components_controller
-            .add(entity_id, Components::Coordinate { x: 0, y: -3, z: 2 })
-            .unwrap();
+        components_controller.add(entity_id, Components::Coordinate { x: 0, y: -3, z: 2 })?;
+        components_controller.add(entity_id, Components::Coordinate { x: 0, y: -4, z: 2 })?;
 
-        components_controller
-            .add(entity_id, Components::Coordinate { x: 0, y: -4, z: 2 })
-            .unwrap();
+        assert_eq!(
+            components_controller
+                .get_component_of_entity(entity_id, |owned_component| owned_component
+                    .is_coordinate())
+                .err(),
+            Some(CosmopolitanCollapseError::MoreThanOneMatchingComponent {
+                entity_id,
+                number_of_matches: 2
+            })
+        );
+        assert_eq!(
+            components_controller
+                .get_component_of_entity(entity_id + 1, |_| true)
+                .err(),
+            Some(CosmopolitanCollapseError::EntityNotFound {
+                entity_id: entity_id + 1
+            })
+        );
 
-        if let Err(error) = components_controller
-            .get_component_of_entity(entity_id, |owned_component| owned_component.is_coordinate())
-        {
-            panic!("There were two components matching. Error: {:?}", error);
-        }
+        Ok(())
     }
 
     #[test]
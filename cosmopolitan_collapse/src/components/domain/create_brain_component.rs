@@ -11,14 +11,27 @@ use constants::SETTLING_IN_A_COMPONENT_WILD_FILENAME;
 
 use agents::agent_traits::AgentTraits;
 use components::domain::components::Components;
+use components::domain::create_settling_in_io_schema::create_settling_in_io_schema;
+use cosmopolitan_collapse_error::CosmopolitanCollapseError;
 
-fn load_json(filename: &str) -> Result<NeuralNetwork<Neuron>, String> {
-    Ok(deserialize_json_from_string::<NeuralNetwork<Neuron>>(
-        &read_file_to_string(filename)?,
-    )?)
+fn load_json(filename: &str) -> Result<NeuralNetwork<Neuron>, CosmopolitanCollapseError> {
+    let neural_network =
+        deserialize_json_from_string::<NeuralNetwork<Neuron>>(&read_file_to_string(filename)?)?;
+
+    // A brain trained for other inputs or outputs would otherwise pick actions at random.
+    neural_network
+        .check_io_schema(&create_settling_in_io_schema()?)
+        .map_err(|error| CosmopolitanCollapseError::MiswiredBrain {
+            filename: filename.to_string(),
+            error,
+        })?;
+
+    Ok(neural_network)
 }
 
-pub fn create_brain_component(traits: &[AgentTraits]) -> Result<Components, String> {
+pub fn create_brain_component(
+    traits: &[AgentTraits],
+) -> Result<Components, CosmopolitanCollapseError> {
     let mut settling_in: Option<NeuralNetwork<Neuron>> = None;
 
     // Should go through each trait in the passed vector and figure out what stored
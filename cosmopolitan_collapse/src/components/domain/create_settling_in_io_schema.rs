@@ -0,0 +1,38 @@
+extern crate neural_networks;
+
+use self::neural_networks::named_io::io_schema::IoSchema;
+use self::neural_networks::named_io::named_value::NamedValue;
+use self::neural_networks::neural_networks_error::NeuralNetworksError;
+
+// What every settling-in brain takes in and puts out. The outputs get compared against each other
+// to pick an action, so only the inputs are bounded.
+pub fn create_settling_in_io_schema() -> Result<IoSchema, NeuralNetworksError> {
+    IoSchema::new(
+        vec![
+            NamedValue::new("CavesPresent", 0.0, 1.0),
+            NamedValue::new("BuildingsPresent", 0.0, 1.0),
+            NamedValue::new("IsHomeless", 0.0, 1.0),
+        ],
+        vec![
+            NamedValue::new_unbounded("SettleInCave"),
+            NamedValue::new_unbounded("SettleInBuilding"),
+            NamedValue::new_unbounded("SetUpCamp"),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_the_settling_in_io_schema_names_three_inputs_and_three_outputs(
+    ) -> Result<(), NeuralNetworksError> {
+        let io_schema = create_settling_in_io_schema()?;
+
+        assert_eq!(io_schema.get_number_of_inputs(), 3);
+        assert_eq!(io_schema.get_outputs()[1].get_name(), "SettleInBuilding");
+
+        Ok(())
+    }
+}
@@ -1,16 +1,18 @@
 use components::domain::components::Components;
+use cosmopolitan_collapse_error::CosmopolitanCollapseError;
 
 pub fn manipulate_component<T: FnMut(&&mut Components) -> bool, U: Fn(&mut Components)>(
+    entity_id: u32,
     components: &mut Vec<Components>,
     matching_condition: T,
     operation_to_perform_on_matching_component: U,
-) -> Result<(), String> {
-    let mut matching_components = components
+) -> Result<(), CosmopolitanCollapseError> {
+    let matching_component = components
         .iter_mut()
-        .filter(matching_condition)
-        .collect::<Vec<&mut Components>>();
+        .find(matching_condition)
+        .ok_or(CosmopolitanCollapseError::NoMatchingComponent { entity_id })?;
 
-    operation_to_perform_on_matching_component(matching_components[0]);
+    operation_to_perform_on_matching_component(matching_component);
 
     Ok(())
 }
@@ -1,3 +1,4 @@
 pub mod components;
 pub mod create_brain_component;
+pub mod create_settling_in_io_schema;
 pub mod manipulate_component;
@@ -0,0 +1,137 @@
+extern crate file_system;
+extern crate neural_networks;
+
+use self::file_system::file_system_error::FileSystemError;
+use self::neural_networks::neural_networks_error::NeuralNetworksError;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CosmopolitanCollapseError {
+    EntityNotFound {
+        entity_id: u32,
+    },
+    NoMatchingComponent {
+        entity_id: u32,
+    },
+    MoreThanOneMatchingComponent {
+        entity_id: u32,
+        number_of_matches: usize,
+    },
+    NoEntityMatchingCondition,
+    MoreThanOneEntityMatchingCondition {
+        matching_ids: Vec<u32>,
+    },
+    NotACoordinate(String),
+    RoomLimitReached {
+        space_id: u32,
+        room_limit: usize,
+    },
+    AlreadyAnInhabitant {
+        agent_id: u32,
+        space_id: u32,
+    },
+    NotACave {
+        space_id: u32,
+    },
+    MiswiredBrain {
+        filename: String,
+        error: NeuralNetworksError,
+    },
+    FileSystem(FileSystemError),
+    NeuralNetworks(NeuralNetworksError),
+}
+
+impl fmt::Display for CosmopolitanCollapseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CosmopolitanCollapseError::EntityNotFound { entity_id } => write!(
+                f,
+                "Attempted to retrieve a component of an entity, but there were no entries for entity {:?}.",
+                entity_id
+            ),
+            CosmopolitanCollapseError::NoMatchingComponent { entity_id } => write!(
+                f,
+                "Attempted to retrieve a component of entity {:?}, but none matched the passed condition.",
+                entity_id
+            ),
+            CosmopolitanCollapseError::MoreThanOneMatchingComponent {
+                entity_id,
+                number_of_matches,
+            } => write!(
+                f,
+                "Attempted to retrieve a component of entity {:?}, but {:?} components matched the passed condition.",
+                entity_id, number_of_matches
+            ),
+            CosmopolitanCollapseError::NoEntityMatchingCondition => write!(
+                f,
+                "Attempted to get the entity id matching a condition, but there was none."
+            ),
+            CosmopolitanCollapseError::MoreThanOneEntityMatchingCondition { matching_ids } => write!(
+                f,
+                "Attempted to get the entity id matching a condition, but there was more than one. Matching: {:?}",
+                matching_ids
+            ),
+            CosmopolitanCollapseError::NotACoordinate(component) => write!(
+                f,
+                "Expected a Components::Coordinate, but got {}.",
+                component
+            ),
+            CosmopolitanCollapseError::RoomLimitReached {
+                space_id,
+                room_limit,
+            } => write!(
+                f,
+                "There is no room left in space {:?}, as its room limit is {:?}.",
+                space_id, room_limit
+            ),
+            CosmopolitanCollapseError::AlreadyAnInhabitant { agent_id, space_id } => write!(
+                f,
+                "Agent {:?} already lives in space {:?}.",
+                agent_id, space_id
+            ),
+            CosmopolitanCollapseError::NotACave { space_id } => write!(
+                f,
+                "Attempted to settle in space {:?} as if it were a cave, but it isn't one.",
+                space_id
+            ),
+            CosmopolitanCollapseError::MiswiredBrain { filename, error } => write!(
+                f,
+                "The brain stored in {:?} is miswired: {}",
+                filename, error
+            ),
+            CosmopolitanCollapseError::FileSystem(error) => write!(f, "{}", error),
+            CosmopolitanCollapseError::NeuralNetworks(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for CosmopolitanCollapseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CosmopolitanCollapseError::MiswiredBrain { error, .. } => Some(error),
+            CosmopolitanCollapseError::FileSystem(error) => Some(error),
+            CosmopolitanCollapseError::NeuralNetworks(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<FileSystemError> for CosmopolitanCollapseError {
+    fn from(error: FileSystemError) -> CosmopolitanCollapseError {
+        CosmopolitanCollapseError::FileSystem(error)
+    }
+}
+
+impl From<NeuralNetworksError> for CosmopolitanCollapseError {
+    fn from(error: NeuralNetworksError) -> CosmopolitanCollapseError {
+        CosmopolitanCollapseError::NeuralNetworks(error)
+    }
+}
+
+// Lets functions that still report errors as strings use '?' on these.
+impl From<CosmopolitanCollapseError> for String {
+    fn from(error: CosmopolitanCollapseError) -> String {
+        error.to_string()
+    }
+}
@@ -4,6 +4,7 @@ extern crate derive_is_enum_variant;
 pub mod agents;
 pub mod components;
 pub mod constants;
+pub mod cosmopolitan_collapse_error;
 pub mod entities;
 pub mod game_definitions;
 pub mod persistence;
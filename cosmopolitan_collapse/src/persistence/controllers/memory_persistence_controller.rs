@@ -1,5 +1,6 @@
 use agents::decisions::Decisions;
 use components::controllers::components_controller::ComponentsController;
+use cosmopolitan_collapse_error::CosmopolitanCollapseError;
 use persistence::domain::persist_setting_up_camp_decision::persist_setting_up_camp_decision;
 use persistence::domain::persist_settle_in_building_decision::persist_settle_in_building_decision;
 use persistence::domain::persist_settle_in_cave_decision::persist_settle_in_cave_decision;
@@ -21,24 +22,27 @@ impl MemoryPersistenceController {
         &self,
         decisions: &[Decisions],
         components_controller: &mut ComponentsController,
-    ) -> Result<(), String> {
+    ) -> Result<(), CosmopolitanCollapseError> {
         // Should go through the decisions one by one and modify the appropriate components depending on the circumstances it finds the involved
         // components in.
-        decisions.iter().for_each(|decision| match decision {
-            Decisions::SettleInCave { agent_id, space_id } => {
-                persist_settle_in_cave_decision(*agent_id, *space_id, components_controller)
-                    .unwrap()
+        for decision in decisions.iter() {
+            match decision {
+                Decisions::SettleInCave { agent_id, space_id } => {
+                    persist_settle_in_cave_decision(*agent_id, *space_id, components_controller)?
+                }
+                Decisions::SettleInBuilding { agent_id, space_id } => {
+                    persist_settle_in_building_decision(
+                        *agent_id,
+                        *space_id,
+                        components_controller,
+                    )?
+                }
+                Decisions::SetUpCamp { agent_id, space_id } => {
+                    persist_setting_up_camp_decision(*agent_id, *space_id, components_controller)?
+                }
+                Decisions::None => (),
             }
-            Decisions::SettleInBuilding { agent_id, space_id } => {
-                persist_settle_in_building_decision(*agent_id, *space_id, components_controller)
-                    .unwrap()
-            }
-            Decisions::SetUpCamp { agent_id, space_id } => {
-                persist_setting_up_camp_decision(*agent_id, *space_id, components_controller)
-                    .unwrap()
-            }
-            Decisions::None => (),
-        });
+        }
 
         Ok(())
     }
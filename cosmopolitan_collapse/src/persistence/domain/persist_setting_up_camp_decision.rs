@@ -1,12 +1,13 @@
 use components::controllers::components_controller::ComponentsController;
 use components::domain::components::Components;
+use cosmopolitan_collapse_error::CosmopolitanCollapseError;
 
 #[allow(clippy::blocks_in_if_conditions)]
 pub fn persist_setting_up_camp_decision(
     agent_id: u32,
     space_id: u32,
     components_controller: &mut ComponentsController,
-) -> Result<(), String> {
+) -> Result<(), CosmopolitanCollapseError> {
     // Rules for setting up camp: there isn't already a camp in the space. It must be built.
     // Then the agent_id gets added to the list of inhabitants.
 
@@ -1,27 +1,31 @@
 use components::controllers::components_controller::ComponentsController;
 use components::domain::components::Components;
 use components::domain::manipulate_component::manipulate_component;
+use cosmopolitan_collapse_error::CosmopolitanCollapseError;
 
 #[allow(clippy::blocks_in_if_conditions)]
 pub fn persist_settle_in_building_decision(
     agent_id: u32,
     space_id: u32,
     components_controller: &mut ComponentsController,
-) -> Result<(), String> {
+) -> Result<(), CosmopolitanCollapseError> {
     if !components_controller
         .does_any_component_of_an_entity_check_a_condition(space_id, |component| {
             component.is_building()
         })
     {
-        panic!("Was going to persist the decision to settle in a building, but there was no building in space with id {:?}", space_id);
+        return Err(CosmopolitanCollapseError::NoMatchingComponent {
+            entity_id: space_id,
+        });
     }
 
     components_controller.manipulate_component(
         |id, components| {
             id == &space_id && components.iter().any(|component| component.is_building())
         },
-        |_id, components| {
+        |id, components| {
             manipulate_component(
+                *id,
                 components,
                 |component| component.is_building(),
                 |component| {
@@ -34,7 +38,6 @@ pub fn persist_settle_in_building_decision(
                     }
                 },
             )
-            .unwrap();
         },
     )?;
 
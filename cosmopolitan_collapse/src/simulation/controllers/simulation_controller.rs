@@ -1,5 +1,6 @@
 use agents::decisions::Decisions;
 use components::controllers::components_controller::ComponentsController;
+use cosmopolitan_collapse_error::CosmopolitanCollapseError;
 use entities::id_generator::IdGenerator;
 use persistence::controllers::memory_persistence_controller::MemoryPersistenceController;
 use std::collections::VecDeque;
@@ -47,7 +48,7 @@ impl SimulationController {
             .collect::<Vec<Decisions>>()
     }
 
-    pub fn step(&mut self) -> Result<(), String> {
+    pub fn step(&mut self) -> Result<(), CosmopolitanCollapseError> {
         // It goes through a single turn of the simulation.
 
         // Every entity that has a brain gets to decide what to do.
@@ -70,7 +71,7 @@ impl SimulationController {
     pub fn add_entity<T: Fn(u32, &mut ComponentsController)>(
         &mut self,
         entity_builder: T,
-    ) -> Result<(), String> {
+    ) -> Result<(), CosmopolitanCollapseError> {
         entity_builder(
             self.id_generator.generate(),
             &mut self.components_controller,
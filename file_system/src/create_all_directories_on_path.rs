@@ -1,8 +1,9 @@
+use file_system_error::FileSystemError;
 use std::fs::create_dir_all;
 
 use std::path::Path;
 
-pub fn create_all_directories_on_path(file_path: &str) -> Result<(), String> {
+pub fn create_all_directories_on_path(file_path: &str) -> Result<(), FileSystemError> {
     // First will need to remove filename from the path, if any.
     let mut path = Path::new(file_path);
 
@@ -10,11 +11,14 @@ pub fn create_all_directories_on_path(file_path: &str) -> Result<(), String> {
         path = path.parent().unwrap();
     }
 
-    path.ancestors().for_each(|directories| {
+    for directories in path.ancestors() {
         if let Err(error) = create_dir_all(directories) {
-            panic!("Attempted to create all directories missing in path {:?}, but failed with the following error: {:?}", file_path, error);
+            return Err(FileSystemError::Io {
+                path: file_path.to_string(),
+                message: error.to_string(),
+            });
         }
-    });
+    }
 
     Ok(())
 }
@@ -1,15 +1,15 @@
 extern crate serde;
 
 use self::serde::Deserialize;
+use file_system_error::FileSystemError;
 
 pub fn deserialize_json_from_string<'a, T: Deserialize<'a>>(
     file_as_string: &'a str,
-) -> Result<T, String> {
+) -> Result<T, FileSystemError> {
     match serde_json::from_str(&file_as_string) {
-        Err(error) => Err(format!(
-            "Failed to deserialize json from string {:?}. Error: {:?}",
-            file_as_string, error
-        )),
+        Err(error) => Err(FileSystemError::Deserialization {
+            message: error.to_string(),
+        }),
         Ok(value) => Ok(value),
     }
 }
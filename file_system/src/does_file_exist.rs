@@ -1,5 +1,6 @@
+use file_system_error::FileSystemError;
 use std::path::Path;
 
-pub fn does_file_exist(file_path: &str) -> Result<bool, String> {
+pub fn does_file_exist(file_path: &str) -> Result<bool, FileSystemError> {
     Ok(Path::new(file_path).exists())
 }
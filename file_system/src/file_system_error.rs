@@ -0,0 +1,40 @@
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FileSystemError {
+    FileNotFound { file_path: String },
+    Io { path: String, message: String },
+    Serialization { file_path: String, message: String },
+    Deserialization { message: String },
+}
+
+impl fmt::Display for FileSystemError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FileSystemError::FileNotFound { file_path } => {
+                write!(f, "There is no file in path {:?}.", file_path)
+            }
+            FileSystemError::Io { path, message } => {
+                write!(f, "Failed to access path {:?}. Error: {}", path, message)
+            }
+            FileSystemError::Serialization { file_path, message } => write!(
+                f,
+                "Failed to save serializable value to path {:?}. Error: {}",
+                file_path, message
+            ),
+            FileSystemError::Deserialization { message } => {
+                write!(f, "Failed to deserialize json. Error: {}", message)
+            }
+        }
+    }
+}
+
+impl Error for FileSystemError {}
+
+// Lets functions that still report errors as strings use '?' on these.
+impl From<FileSystemError> for String {
+    fn from(error: FileSystemError) -> String {
+        error.to_string()
+    }
+}
@@ -1,8 +1,11 @@
+use file_system_error::FileSystemError;
 use std::fs::File;
 use std::path::Path;
 
-pub fn get_file(file_path: &str) -> Result<std::fs::File, String> {
+pub fn get_file(file_path: &str) -> Result<std::fs::File, FileSystemError> {
     let json_file_path = Path::new(file_path);
-    Ok(File::open(json_file_path)
-        .unwrap_or_else(|_| panic!("Didn't find a file in path {:?}", file_path)))
+
+    File::open(json_file_path).map_err(|_| FileSystemError::FileNotFound {
+        file_path: file_path.to_string(),
+    })
 }
@@ -1,12 +1,16 @@
+use file_system_error::FileSystemError;
 use std::fs;
 
 pub fn get_filenames_from_directory_that_end_with_extension(
     path: &str,
     extension: &str,
-) -> Vec<String> {
-    let files = fs::read_dir(path).unwrap();
+) -> Result<Vec<String>, FileSystemError> {
+    let files = fs::read_dir(path).map_err(|error| FileSystemError::Io {
+        path: path.to_string(),
+        message: error.to_string(),
+    })?;
 
-    files
+    Ok(files
         .filter_map(Result::ok)
         .filter(|f| {
             if f.path().extension().is_some() {
@@ -16,5 +20,5 @@ pub fn get_filenames_from_directory_that_end_with_extension(
             false
         })
         .map(|f| f.path().to_str().unwrap().to_string())
-        .collect()
+        .collect())
 }
@@ -1,20 +1,16 @@
 use does_file_exist::does_file_exist;
+use file_system_error::FileSystemError;
 use std::fs;
 
-pub fn read_file_to_string(file_path: &str) -> Result<String, String> {
-    // If there isn't any file in the path given, crash.
+pub fn read_file_to_string(file_path: &str) -> Result<String, FileSystemError> {
     if !does_file_exist(file_path)? {
-        panic!(
-            "Attempted to read a file to a string, but the file didn't exist! File path: {:?}",
-            file_path
-        );
+        return Err(FileSystemError::FileNotFound {
+            file_path: file_path.to_string(),
+        });
     }
 
-    match fs::read_to_string(file_path) {
-        Ok(text) => Ok(text),
-        Err(error) => panic!(
-            "Failed to read file path {:?} to a string. Error: {:?} ",
-            file_path, error
-        ),
-    }
+    fs::read_to_string(file_path).map_err(|error| FileSystemError::Io {
+        path: file_path.to_string(),
+        message: error.to_string(),
+    })
 }
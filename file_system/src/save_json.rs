@@ -3,26 +3,25 @@ extern crate serde_json;
 
 use self::serde::Serialize;
 use create_all_directories_on_path::create_all_directories_on_path;
+use file_system_error::FileSystemError;
 
 use std::fs::File;
 
-pub fn save_json<T: Serialize>(file_path: &str, value_to_serialize: &T) -> Result<(), String> {
+pub fn save_json<T: Serialize>(
+    file_path: &str,
+    value_to_serialize: &T,
+) -> Result<(), FileSystemError> {
     create_all_directories_on_path(file_path)?;
 
-    match &File::create(file_path) {
-        Ok(file) => {
-            if let Err(error) = self::serde_json::to_writer(file, value_to_serialize) {
-                panic!(
-                    "Failed to save serializable value to path {:?} due to error: {:?} ",
-                    file_path, error
-                );
-            }
-        }
-        Err(error) => panic!(
-            "Wasn't able to create a file in the path {:?} due to the next error: {:?}",
-            file_path, error
-        ),
-    }
+    let file = File::create(file_path).map_err(|error| FileSystemError::Io {
+        path: file_path.to_string(),
+        message: error.to_string(),
+    })?;
 
-    Ok(())
+    self::serde_json::to_writer(file, value_to_serialize).map_err(|error| {
+        FileSystemError::Serialization {
+            file_path: file_path.to_string(),
+            message: error.to_string(),
+        }
+    })
 }
@@ -1,38 +1,86 @@
+extern crate file_system;
 extern crate float_ord;
 extern crate neural_networks;
 extern crate randomization;
+extern crate serde;
 
+use self::file_system::deserialize_json_from_string::deserialize_json_from_string;
+use self::file_system::file_system_error::FileSystemError;
+use self::file_system::read_file_to_string::read_file_to_string;
+use self::file_system::save_json::save_json;
 use self::float_ord::FloatOrd;
+use self::neural_networks::evolution::domain::novelty::novelty_search::NoveltySearch;
 use self::neural_networks::evolution::domain::population::Population;
 use self::neural_networks::evolution::domain::population::PopulationTrait;
+use self::neural_networks::neural_networks_error::NeuralNetworksError;
 use controllers::gym_controller::neural_networks::evolution::controllers::create_next_generation::create_next_generation;
+use controllers::gym_controller::neural_networks::evolution::domain::evolution_config::EvolutionConfig;
 use controllers::gym_controller::neural_networks::evolution::domain::genome::GenomeTrait;
+use controllers::gym_controller::neural_networks::evolution::domain::selection::selection_strategy_trait::SelectionStrategyTrait;
+use controllers::gym_controller::neural_networks::neural_network::LayeredNeuralNetworkTrait;
 use controllers::gym_controller::neural_networks::neural_network::NeuralNetworkTrait;
 use controllers::gym_controller::neural_networks::neuron::NeuronTrait;
 use controllers::gym_controller::randomization::randomizer::RandomizerTrait;
+use controllers::gym_controller::randomization::splittable_randomizer_trait::SplittableRandomizerTrait;
+use self::serde::de::DeserializeOwned;
+use self::serde::Serialize;
+use domain::evaluate_genomes_in_parallel::evaluate_genomes_in_parallel;
+use domain::generation_statistics::GenerationStatistics;
+use domain::training_checkpoint::TrainingCheckpoint;
+use domain::training_history::TrainingHistory;
+use gym_error::GymError;
+use std::fs;
 use std::marker::PhantomData;
+use std::time::Instant;
+
+type CheckpointWriter<A, Z> = fn(&A, &Z) -> Result<(), GymError>;
+type GenomesTrainer<T, Z> = Box<dyn Fn(&mut Vec<T>, &mut Z) -> Result<(), GymError>>;
+type BehaviourDescriptor<T> = Box<dyn Fn(&T) -> Result<Vec<f64>, NeuralNetworksError>>;
 
 /// Handles training a previously created population of genomes (which are neural networks).
 ///
 /// The user is able to pass the condition to continue as a closure ( Fn(u32) -> bool ), as well as another closure
-/// that will receive all the genomes of a generation in order to train them ( Fn(&mut Vec<T>, &mut Y) -> Result<(), String> ),
+/// that will receive all the genomes of a generation in order to train them ( Fn(&mut Vec<T>, &mut Y) -> Result<(), GymError> ),
 /// according to the specificities of the model the user is implementing.
 ///
+/// When the fitness of each genome can be computed on its own, 'new_with_parallel_evaluation' takes a fitness
+/// function instead, and the genomes get evaluated across a thread pool.
+/// Once 'enable_novelty_search' gets called, the genomes are scored by the novelty of their behaviour instead.
+///
+/// The parents of every new generation are picked through the given selection strategy, and the rest of the
+/// settings (mutation and crossover rates, elites...) come from the evolution config.
+///
+/// The statistics of every trained generation are handed to the reporter and gathered in a training history,
+/// which gets returned along with the evolved population.
+///
+/// Once 'enable_checkpoints' gets called, the state of the training session is written to disk every few
+/// generations, and 'resume_from_checkpoint' can later continue it. The novelty search gets restored along with its
+/// archive, but its behaviour descriptor has to be given again through 'set_behaviour_descriptor'.
+///
 pub struct GymController<
     T: GenomeTrait<U, V> + Clone,
     U: NeuralNetworkTrait<V> + Clone,
     V: NeuronTrait + Clone,
     W: Fn(u32, &Option<T>) -> bool,
-    X: Fn(&mut Vec<T>, &mut Z) -> Result<(), String>,
-    Y: Fn(&Population<T, U, V>, &mut Z) -> Result<(), String>,
+    X: Fn(&mut Vec<T>, &mut Z) -> Result<(), GymError>,
+    Y: Fn(&Population<T, U, V>, &mut Z) -> Result<(), GymError>,
     Z: RandomizerTrait,
+    S: SelectionStrategyTrait + Clone,
 > {
     population: Population<T, U, V>,
     generations: u32,
     continue_condition: W,
     train_genomes: X,
     operation_to_perform_on_evolved_population: Y,
+    selection_strategy: S,
+    evolution_config: EvolutionConfig,
     winner: Option<T>,
+    training_history: TrainingHistory,
+    novelty_search: Option<NoveltySearch>,
+    behaviour_descriptor: Option<BehaviourDescriptor<T>>,
+    checkpoint_interval: u32,
+    checkpoint_path: String,
+    checkpoint_writer: Option<CheckpointWriter<Self, Z>>,
     phantom_y: PhantomData<Y>,
     phantom_z: PhantomData<Z>,
 }
@@ -42,24 +90,35 @@ impl<
         U: NeuralNetworkTrait<V> + Clone,
         V: NeuronTrait + Clone,
         W: Fn(u32, &Option<T>) -> bool,
-        X: Fn(&mut Vec<T>, &mut Z) -> Result<(), String>,
-        Y: Fn(&Population<T, U, V>, &mut Z) -> Result<(), String>,
+        X: Fn(&mut Vec<T>, &mut Z) -> Result<(), GymError>,
+        Y: Fn(&Population<T, U, V>, &mut Z) -> Result<(), GymError>,
         Z: RandomizerTrait,
-    > GymController<T, U, V, W, X, Y, Z>
+        S: SelectionStrategyTrait + Clone,
+    > GymController<T, U, V, W, X, Y, Z, S>
 {
     pub fn new(
         population: Population<T, U, V>,
         continue_condition: W,
         train_genomes: X,
         operation_to_perform_on_evolved_population: Y,
-    ) -> GymController<T, U, V, W, X, Y, Z> {
+        selection_strategy: S,
+        evolution_config: EvolutionConfig,
+    ) -> GymController<T, U, V, W, X, Y, Z, S> {
         GymController {
             population,
             generations: 0,
             continue_condition,
             train_genomes,
             operation_to_perform_on_evolved_population,
+            selection_strategy,
+            evolution_config,
             winner: None,
+            training_history: TrainingHistory::new(),
+            novelty_search: None,
+            behaviour_descriptor: None,
+            checkpoint_interval: 0,
+            checkpoint_path: String::new(),
+            checkpoint_writer: None,
             phantom_y: PhantomData,
             phantom_z: PhantomData,
         }
@@ -69,7 +128,7 @@ impl<
         A: Fn(u32, U) -> T,
         B: Fn() -> U,
         C: Fn(u32, &mut Z) -> V,
-        D: Fn(u32, &Population<T, U, V>),
+        D: Fn(u32, &Population<T, U, V>, &GenerationStatistics),
     >(
         &mut self,
         genome_creator: A,
@@ -77,10 +136,61 @@ impl<
         neuron_creator: C,
         generation_training_reporter: D,
         randomizer: &mut Z,
-    ) -> Result<Population<T, U, V>, String> {
+    ) -> Result<(Population<T, U, V>, TrainingHistory), GymError>
+    where
+        U: LayeredNeuralNetworkTrait<V>,
+    {
+        let selection_strategy = self.selection_strategy.clone();
+        let evolution_config = self.evolution_config.clone();
+
+        self.train_with_next_generation_creator(
+            |population, randomizer| {
+                create_next_generation(
+                    population,
+                    &selection_strategy,
+                    &evolution_config,
+                    &genome_creator,
+                    &neural_network_creator,
+                    &neuron_creator,
+                    randomizer,
+                )
+            },
+            generation_training_reporter,
+            randomizer,
+        )
+    }
+
+    /// Same as 'train', but the caller decides how each generation gets produced out of the sorted,
+    /// already trained one. That allows evolving genomes that don't cross over layer by layer, such
+    /// as NEAT genomes (see 'create_next_neat_generation') or modular networks (see
+    /// 'create_next_generation_of_parameters'), or sampling the weights of a fixed topology through an
+    /// evolution strategy (see 'create_next_evolution_strategy_generation').
+    pub fn train_with_next_generation_creator<
+        A: FnMut(&Population<T, U, V>, &mut Z) -> Result<Population<T, U, V>, NeuralNetworksError>,
+        D: Fn(u32, &Population<T, U, V>, &GenerationStatistics),
+    >(
+        &mut self,
+        mut next_generation_creator: A,
+        generation_training_reporter: D,
+        randomizer: &mut Z,
+    ) -> Result<(Population<T, U, V>, TrainingHistory), GymError> {
         while (self.continue_condition)(self.generations, &self.winner) {
+            let start_of_generation = Instant::now();
+
             (self.train_genomes)(self.population.get_genomes_mut()?, randomizer)?;
 
+            if let Some(novelty_search) = self.novelty_search.as_mut() {
+                let behaviour_descriptor = self
+                    .behaviour_descriptor
+                    .as_ref()
+                    .ok_or(GymError::NoBehaviourDescriptor)?;
+
+                novelty_search.assign_novelty_scores(
+                    self.population.get_genomes_mut()?,
+                    behaviour_descriptor,
+                )?;
+            }
+
             let population_size_before_evolving = self.population.get_size();
 
             // Sort population by fitness.
@@ -89,49 +199,215 @@ impl<
                 .sort_by(|a, b| FloatOrd(b.get_fitness()).cmp(&FloatOrd(a.get_fitness())));
 
             // Store winner.
-            self.winner = Some(self.population.get_genomes()?[0].clone());
+            let winner = self.population.get_genomes()?[0].clone();
 
             if self
                 .population
                 .get_genomes()?
                 .iter()
-                .any(|genome| genome.get_fitness() > self.winner.as_ref().unwrap().get_fitness())
+                .any(|genome| genome.get_fitness() > winner.get_fitness())
             {
-                panic!("Had stored the winner amongst the generation of genomes, but there was at least another genome in the population with a higher fitness!");
+                return Err(GymError::WinnerIsNotTheFittest);
             }
 
-            self.population = create_next_generation(
-                &self.population,
-                &genome_creator,
-                &neural_network_creator,
-                &neuron_creator,
-                randomizer,
+            self.winner = Some(winner);
+
+            let mut generation_statistics = GenerationStatistics::from_genomes(
+                self.generations,
+                self.population.get_genomes()?,
             )?;
 
-            assert_eq!(population_size_before_evolving, self.population.get_size());
+            self.population = next_generation_creator(&self.population, randomizer)?;
+
+            if self.population.get_size() != population_size_before_evolving {
+                return Err(GymError::PopulationSizeChanged {
+                    expected: population_size_before_evolving,
+                    received: self.population.get_size(),
+                });
+            }
 
-            generation_training_reporter(self.generations, &self.population);
+            generation_statistics.set_elapsed_time(start_of_generation.elapsed());
+
+            generation_training_reporter(
+                self.generations,
+                &self.population,
+                &generation_statistics,
+            );
+
+            self.training_history.add(generation_statistics);
 
             (self.operation_to_perform_on_evolved_population)(&self.population, randomizer)?;
 
             self.generations += 1;
+
+            if let Some(checkpoint_writer) = self.checkpoint_writer {
+                if self.generations.is_multiple_of(self.checkpoint_interval) {
+                    checkpoint_writer(self, randomizer)?;
+                }
+            }
         }
 
-        Ok(self.population.clone())
+        Ok((self.population.clone(), self.training_history.clone()))
     }
 
     pub fn get_generations(&self) -> u32 {
         self.generations
     }
 
-    pub fn get_winner(&self) -> &T {
-        &self.winner.as_ref().unwrap()
+    pub fn get_evolution_config(&self) -> &EvolutionConfig {
+        &self.evolution_config
+    }
+
+    pub fn get_training_history(&self) -> &TrainingHistory {
+        &self.training_history
+    }
+
+    // None until a generation has been trained.
+    pub fn get_winner(&self) -> Option<&T> {
+        self.winner.as_ref()
+    }
+
+    /// From now on, the objective fitness the genomes get trained to is replaced with (or blended into, depending on
+    /// the objective weight of the novelty search) the novelty of the behaviour the descriptor returns for them.
+    pub fn enable_novelty_search<A: Fn(&T) -> Result<Vec<f64>, NeuralNetworksError> + 'static>(
+        &mut self,
+        novelty_search: NoveltySearch,
+        behaviour_descriptor: A,
+    ) {
+        self.novelty_search = Some(novelty_search);
+        self.set_behaviour_descriptor(behaviour_descriptor);
+    }
+
+    pub fn set_behaviour_descriptor<
+        A: Fn(&T) -> Result<Vec<f64>, NeuralNetworksError> + 'static,
+    >(
+        &mut self,
+        behaviour_descriptor: A,
+    ) {
+        self.behaviour_descriptor = Some(Box::new(behaviour_descriptor));
+    }
+
+    pub fn get_novelty_search(&self) -> &Option<NoveltySearch> {
+        &self.novelty_search
+    }
+}
+
+impl<
+        T: GenomeTrait<U, V> + Clone + Send,
+        U: NeuralNetworkTrait<V> + Clone,
+        V: NeuronTrait + Clone,
+        W: Fn(u32, &Option<T>) -> bool,
+        Y: Fn(&Population<T, U, V>, &mut Z) -> Result<(), GymError>,
+        Z: SplittableRandomizerTrait + Send,
+        S: SelectionStrategyTrait + Clone,
+    > GymController<T, U, V, W, GenomesTrainer<T, Z>, Y, Z, S>
+{
+    /// Same as 'new', but every genome gets the fitness the given function returns for it, spreading the genomes
+    /// across rayon's thread pool (see 'evaluate_genomes_in_parallel'). The results are reproducible as long as the
+    /// randomizer is, such as a SeededRandomizer.
+    pub fn new_with_parallel_evaluation<
+        A: Fn(&T, &mut Z) -> Result<f64, GymError> + Sync + 'static,
+    >(
+        population: Population<T, U, V>,
+        continue_condition: W,
+        fitness_function: A,
+        operation_to_perform_on_evolved_population: Y,
+        selection_strategy: S,
+        evolution_config: EvolutionConfig,
+    ) -> Self {
+        GymController::new(
+            population,
+            continue_condition,
+            Box::new(move |genomes: &mut Vec<T>, randomizer: &mut Z| {
+                evaluate_genomes_in_parallel(genomes, &fitness_function, randomizer)
+            }),
+            operation_to_perform_on_evolved_population,
+            selection_strategy,
+            evolution_config,
+        )
+    }
+}
+
+impl<
+        T: GenomeTrait<U, V> + Clone + Serialize + DeserializeOwned,
+        U: NeuralNetworkTrait<V> + Clone + Serialize + DeserializeOwned,
+        V: NeuronTrait + Clone + Serialize + DeserializeOwned,
+        W: Fn(u32, &Option<T>) -> bool,
+        X: Fn(&mut Vec<T>, &mut Z) -> Result<(), GymError>,
+        Y: Fn(&Population<T, U, V>, &mut Z) -> Result<(), GymError>,
+        Z: RandomizerTrait + Clone + Serialize + DeserializeOwned,
+        S: SelectionStrategyTrait + Clone,
+    > GymController<T, U, V, W, X, Y, Z, S>
+{
+    pub fn enable_checkpoints(&mut self, checkpoint_interval: u32, checkpoint_path: &str) {
+        self.checkpoint_interval = checkpoint_interval.max(1);
+        self.checkpoint_path = checkpoint_path.to_string();
+        self.checkpoint_writer = Some(Self::save_checkpoint);
+    }
+
+    pub fn save_checkpoint(&self, randomizer: &Z) -> Result<(), GymError> {
+        let mut checkpoint = TrainingCheckpoint::new(
+            self.population.clone(),
+            self.generations,
+            self.winner.clone(),
+            randomizer.clone(),
+            self.evolution_config.clone(),
+            self.checkpoint_interval,
+            self.training_history.clone(),
+        );
+        checkpoint.set_novelty_search(self.novelty_search.clone());
+
+        // Written aside first, so a process dying halfway through doesn't leave a broken checkpoint.
+        let temporary_path = format!("{}.tmp", self.checkpoint_path);
+
+        save_json(&temporary_path, &checkpoint)?;
+
+        fs::rename(&temporary_path, &self.checkpoint_path).map_err(|error| {
+            FileSystemError::Io {
+                path: self.checkpoint_path.clone(),
+                message: error.to_string(),
+            }
+            .into()
+        })
+    }
+
+    /// Rebuilds the controller out of the checkpoint stored in the given path, along with the randomizer as it was
+    /// when the checkpoint got written. Training with them continues the session exactly where it stopped, and
+    /// keeps writing checkpoints to the same path.
+    pub fn resume_from_checkpoint(
+        checkpoint_path: &str,
+        continue_condition: W,
+        train_genomes: X,
+        operation_to_perform_on_evolved_population: Y,
+        selection_strategy: S,
+    ) -> Result<(Self, Z), GymError> {
+        let checkpoint: TrainingCheckpoint<T, U, V, Z> =
+            deserialize_json_from_string(&read_file_to_string(checkpoint_path)?)?;
+
+        let mut gym_controller = GymController::new(
+            checkpoint.get_population().clone(),
+            continue_condition,
+            train_genomes,
+            operation_to_perform_on_evolved_population,
+            selection_strategy,
+            checkpoint.get_evolution_config().clone(),
+        );
+
+        gym_controller.generations = checkpoint.get_generations();
+        gym_controller.winner = checkpoint.get_winner().clone();
+        gym_controller.training_history = checkpoint.get_training_history().clone();
+        gym_controller.novelty_search = checkpoint.get_novelty_search().clone();
+        gym_controller.enable_checkpoints(checkpoint.get_checkpoint_interval(), checkpoint_path);
+
+        Ok((gym_controller, checkpoint.get_randomizer().clone()))
     }
 }
 
 #[cfg(test)]
 mod tests {
 
+    extern crate serde_json;
+
     use self::neural_networks::neuron_activation::activation_functions::ActivationFunctions;
     use super::*;
     use controllers::gym_controller::neural_networks::evolution::domain::genome::Genome;
@@ -139,9 +415,10 @@ mod tests {
     use controllers::gym_controller::neural_networks::neuron::Neuron;
 
     use self::neural_networks::evolution::domain::create_genome::create_genome;
+    use self::neural_networks::evolution::domain::selection::truncation_selection::TruncationSelection;
 
     #[test]
-    fn test_can_run_a_training_session_and_receive_trained_population() -> Result<(), String> {
+    fn test_can_run_a_training_session_and_receive_trained_population() -> Result<(), GymError> {
         use self::neural_networks::evolution::domain::population::PopulationTrait;
         use self::randomization::randomizer::Randomizer;
 
@@ -165,24 +442,453 @@ mod tests {
                     false
                 }
             },
-            |_genomes_to_train, _randomizer| -> Result<(), String> { Ok(()) },
+            |_genomes_to_train, _randomizer| -> Result<(), GymError> { Ok(()) },
             |_evolved_population, _randomizer| Ok(()),
+            TruncationSelection::new(0.5),
+            EvolutionConfig::new(),
         );
 
         let mut randomizer = Randomizer::new();
 
-        let trained_population = sut.train(
+        let (trained_population, training_history) = sut.train(
             |genome_identifier, neural_network| Genome::new(genome_identifier, neural_network),
             || NeuralNetwork::new(),
             |number_of_inputs, randomizer| {
                 Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
             },
-            |_, _| {},
+            |_, _, _| {},
+            &mut randomizer,
+        )?;
+
+        assert_eq!(trained_population.get_size(), 10);
+        assert_eq!(sut.get_generations(), 10);
+        assert_eq!(training_history.get_generations().len(), 10);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_can_run_a_training_session_with_parallel_evaluation() -> Result<(), GymError> {
+        use self::neural_networks::evolution::domain::population::PopulationTrait;
+        use self::randomization::seeded_randomizer::SeededRandomizer;
+
+        let mut randomizer = SeededRandomizer::new(3);
+
+        let population = Population::new_with_specified_layers(
+            10,
+            &[[2, 2], [2, 1]],
+            create_genome,
+            &mut randomizer,
+        )?;
+
+        let mut sut = GymController::new_with_parallel_evaluation(
+            population,
+            |generations, _current_winner| generations < 5,
+            |genome: &Genome<NeuralNetwork<Neuron>, Neuron>, _randomizer: &mut SeededRandomizer| {
+                Ok(genome.get_neural_network().propagate(&[1.0, 0.0])?[0])
+            },
+            |_evolved_population, _randomizer| Ok(()),
+            TruncationSelection::new(0.5),
+            EvolutionConfig::new(),
+        );
+
+        let (trained_population, training_history) = sut.train(
+            Genome::new,
+            NeuralNetwork::new,
+            |number_of_inputs, randomizer| {
+                Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+            },
+            |_, _, _| {},
+            &mut randomizer,
+        )?;
+
+        assert_eq!(trained_population.get_size(), 10);
+        assert_eq!(training_history.get_generations().len(), 5);
+        assert!(training_history
+            .get_generations()
+            .iter()
+            .all(|generation| generation.get_best_fitness() > 0.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_a_next_generation_of_another_size_fails_the_training() -> Result<(), GymError> {
+        use self::randomization::randomizer::Randomizer;
+
+        let mut randomizer = Randomizer::new();
+
+        let mut sut = GymController::new(
+            Population::new_with_specified_layers(4, &[[2, 1]], create_genome, &mut randomizer)?,
+            |generations, _current_winner| generations < 3,
+            |_genomes_to_train, _randomizer| -> Result<(), GymError> { Ok(()) },
+            |_evolved_population, _randomizer| Ok(()),
+            TruncationSelection::new(0.5),
+            EvolutionConfig::new(),
+        );
+
+        let result = sut.train_with_next_generation_creator(
+            |_population, _randomizer| Ok(Population::new()),
+            |_, _, _| {},
+            &mut randomizer,
+        );
+
+        assert_eq!(
+            result.err(),
+            Some(GymError::PopulationSizeChanged {
+                expected: 4,
+                received: 0
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_can_run_a_neat_training_session() -> Result<(), GymError> {
+        use self::neural_networks::evolution::domain::population::PopulationTrait;
+        use self::neural_networks::neat::controllers::create_next_neat_generation::create_next_neat_generation;
+        use self::neural_networks::neat::domain::innovation_tracker::InnovationTracker;
+        use self::neural_networks::neat::domain::neat_network::NeatNetwork;
+        use self::neural_networks::neat::domain::neat_parameters::NeatParameters;
+        use self::randomization::randomizer::Randomizer;
+
+        let mut randomizer = Randomizer::new();
+
+        let mut population = Population::new();
+
+        for genome_identifier in 1..=10 {
+            population.add(Genome::new(
+                genome_identifier,
+                NeatNetwork::new_with_specified_layers(
+                    &[[2, 1]],
+                    &mut randomizer,
+                    |number_of_inputs, randomizer| {
+                        Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+                    },
+                )?,
+            ))?;
+        }
+
+        let mut sut = GymController::new(
+            population,
+            |generations, _current_winner| generations < 10,
+            |genomes: &mut Vec<Genome<NeatNetwork, Neuron>>, _randomizer| -> Result<(), GymError> {
+                for genome in genomes.iter_mut() {
+                    let outputs = genome.get_neural_network().propagate(&[1.0, 0.0])?;
+                    genome.set_fitness(outputs[0]);
+                }
+
+                Ok(())
+            },
+            |_evolved_population, _randomizer| Ok(()),
+            TruncationSelection::new(0.5),
+            EvolutionConfig::new(),
+        );
+
+        let mut species = Vec::new();
+        let mut innovation_tracker = InnovationTracker::new(2, 1);
+        let neat_parameters = NeatParameters::new();
+
+        let (trained_population, training_history) = sut.train_with_next_generation_creator(
+            |population, randomizer| {
+                create_next_neat_generation(
+                    population,
+                    &mut species,
+                    &mut innovation_tracker,
+                    &neat_parameters,
+                    randomizer,
+                )
+            },
+            |_, _, _| {},
             &mut randomizer,
         )?;
 
         assert_eq!(trained_population.get_size(), 10);
         assert_eq!(sut.get_generations(), 10);
+        assert_eq!(training_history.get_generations().len(), 10);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_can_run_a_training_session_of_modular_neural_networks() -> Result<(), GymError> {
+        use self::neural_networks::evolution::controllers::create_next_generation_of_parameters::create_next_generation_of_parameters;
+        use self::neural_networks::evolution::domain::population::PopulationTrait;
+        use self::neural_networks::layer::Layer;
+        use self::neural_networks::network_layers::layer_normalization::LayerNormalization;
+        use self::neural_networks::network_layers::modular_neural_network::ModularNeuralNetwork;
+        use self::randomization::seeded_randomizer::SeededRandomizer;
+
+        let mut randomizer = SeededRandomizer::new(5);
+        let neuron_creator = |number_of_inputs, randomizer: &mut SeededRandomizer| {
+            Neuron::new(number_of_inputs, ActivationFunctions::Tanh, randomizer)
+        };
+
+        let mut population = Population::new();
+
+        for genome_identifier in 1..=10 {
+            let mut neural_network = ModularNeuralNetwork::new();
+            neural_network.add(Box::new(Layer::create_layer(
+                2,
+                3,
+                &mut randomizer,
+                neuron_creator,
+            )?))?;
+            neural_network.add(Box::new(LayerNormalization::new(3)))?;
+            neural_network.add(Box::new(Layer::create_layer(
+                3,
+                1,
+                &mut randomizer,
+                neuron_creator,
+            )?))?;
+
+            population.add(Genome::new(genome_identifier, neural_network))?;
+        }
+
+        let mut sut = GymController::new(
+            population,
+            |generations, _current_winner| generations < 10,
+            |genomes: &mut Vec<Genome<ModularNeuralNetwork, Neuron>>,
+             _randomizer|
+             -> Result<(), GymError> {
+                for genome in genomes.iter_mut() {
+                    let outputs = genome.get_neural_network().propagate(&[1.0, 0.0])?;
+                    genome.set_fitness(outputs[0]);
+                }
+
+                Ok(())
+            },
+            |_evolved_population, _randomizer| Ok(()),
+            TruncationSelection::new(0.5),
+            EvolutionConfig::new(),
+        );
+
+        let evolution_config = EvolutionConfig::new();
+
+        let (trained_population, training_history) = sut.train_with_next_generation_creator(
+            |population, randomizer| {
+                create_next_generation_of_parameters(
+                    population,
+                    &TruncationSelection::new(0.5),
+                    &evolution_config,
+                    Genome::new,
+                    randomizer,
+                )
+            },
+            |_, _, _| {},
+            &mut randomizer,
+        )?;
+
+        let generations = training_history.get_generations();
+
+        assert_eq!(trained_population.get_size(), 10);
+        assert_eq!(generations.len(), 10);
+        assert!(generations[9].get_best_fitness() >= generations[0].get_best_fitness());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_a_resumed_training_session_continues_exactly_where_it_stopped() -> Result<(), GymError>
+    {
+        use self::neural_networks::evolution::domain::population::PopulationTrait;
+        use self::randomization::seeded_randomizer::SeededRandomizer;
+
+        let checkpoint_path = std::env::temp_dir()
+            .join(format!("gym_checkpoint_{}.json", std::process::id()))
+            .to_str()
+            .unwrap()
+            .to_string();
+
+        let create_population = |randomizer: &mut SeededRandomizer| {
+            Population::new_with_specified_layers(8, &[[2, 3], [3, 1]], create_genome, randomizer)
+        };
+        let train_genomes = |genomes: &mut Vec<Genome<NeuralNetwork<Neuron>, Neuron>>,
+                             _randomizer: &mut SeededRandomizer|
+         -> Result<(), GymError> {
+            for genome in genomes.iter_mut() {
+                let outputs = genome.get_neural_network().propagate(&[0.5, -0.5])?;
+                genome.set_fitness(outputs[0]);
+            }
+
+            Ok(())
+        };
+        let do_nothing =
+            |_evolved_population: &Population<_, _, _>, _randomizer: &mut SeededRandomizer| Ok(());
+        // Function pointers, so the conditions of all the sessions share the same type.
+        type ContinueCondition = fn(u32, &Option<Genome<NeuralNetwork<Neuron>, Neuron>>) -> bool;
+
+        let until_three: ContinueCondition = |generations, _current_winner| generations < 3;
+        let until_six: ContinueCondition = |generations, _current_winner| generations < 6;
+
+        let mut evolution_config = EvolutionConfig::new();
+        evolution_config.set_mutation_rate(0.2)?;
+
+        let train = |sut: &mut GymController<_, _, _, _, _, _, SeededRandomizer, _>,
+                     randomizer: &mut SeededRandomizer| {
+            sut.train(
+                Genome::new,
+                NeuralNetwork::new,
+                |number_of_inputs, randomizer| {
+                    Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+                },
+                |_, _, _| {},
+                randomizer,
+            )
+        };
+
+        // An uninterrupted session of six generations.
+        let mut randomizer = SeededRandomizer::new(9);
+
+        let mut uninterrupted = GymController::new(
+            create_population(&mut randomizer)?,
+            until_six,
+            train_genomes,
+            do_nothing,
+            TruncationSelection::new(0.5),
+            evolution_config.clone(),
+        );
+
+        let (uninterrupted_population, _) = train(&mut uninterrupted, &mut randomizer)?;
+
+        // The same session, stopped after three generations and then resumed.
+        let mut randomizer = SeededRandomizer::new(9);
+
+        let mut interrupted = GymController::new(
+            create_population(&mut randomizer)?,
+            until_three,
+            train_genomes,
+            do_nothing,
+            TruncationSelection::new(0.5),
+            evolution_config,
+        );
+        interrupted.enable_checkpoints(3, &checkpoint_path);
+
+        train(&mut interrupted, &mut randomizer)?;
+
+        let (mut resumed, mut restored_randomizer) = GymController::resume_from_checkpoint(
+            &checkpoint_path,
+            until_six,
+            train_genomes,
+            do_nothing,
+            TruncationSelection::new(0.5),
+        )?;
+
+        assert_eq!(resumed.get_generations(), 3);
+        assert_eq!(restored_randomizer, randomizer);
+
+        let (resumed_population, resumed_history) = train(&mut resumed, &mut restored_randomizer)?;
+
+        std::fs::remove_file(&checkpoint_path).unwrap();
+
+        assert_eq!(resumed.get_generations(), 6);
+        assert_eq!(resumed_population.get_size(), 8);
+        assert_eq!(resumed_history.get_generations().len(), 6);
+        assert_eq!(resumed_history.get_generations()[5].get_generation(), 5);
+        assert_eq!(
+            serde_json::to_string(&resumed_population).unwrap(),
+            serde_json::to_string(&uninterrupted_population).unwrap()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_the_novelty_archive_gets_checkpointed_with_the_training_session() -> Result<(), GymError>
+    {
+        use self::randomization::seeded_randomizer::SeededRandomizer;
+
+        let checkpoint_path = std::env::temp_dir()
+            .join(format!(
+                "gym_novelty_checkpoint_{}.json",
+                std::process::id()
+            ))
+            .to_str()
+            .unwrap()
+            .to_string();
+
+        let describe_behaviour = |genome: &Genome<NeuralNetwork<Neuron>, Neuron>| {
+            genome.get_neural_network().propagate(&[1.0, 0.5])
+        };
+        let train_genomes = |genomes: &mut Vec<Genome<NeuralNetwork<Neuron>, Neuron>>,
+                             _randomizer: &mut SeededRandomizer|
+         -> Result<(), GymError> {
+            for genome in genomes.iter_mut() {
+                genome.set_fitness(1.0);
+            }
+
+            Ok(())
+        };
+        let do_nothing =
+            |_evolved_population: &Population<_, _, _>, _randomizer: &mut SeededRandomizer| Ok(());
+        type ContinueCondition = fn(u32, &Option<Genome<NeuralNetwork<Neuron>, Neuron>>) -> bool;
+
+        let until_three: ContinueCondition = |generations, _current_winner| generations < 3;
+        let until_five: ContinueCondition = |generations, _current_winner| generations < 5;
+
+        let train = |sut: &mut GymController<_, _, _, _, _, _, SeededRandomizer, _>,
+                     randomizer: &mut SeededRandomizer| {
+            sut.train(
+                Genome::new,
+                NeuralNetwork::new,
+                |number_of_inputs, randomizer| {
+                    Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+                },
+                |_, _, _| {},
+                randomizer,
+            )
+        };
+
+        let mut randomizer = SeededRandomizer::new(4);
+
+        let mut sut = GymController::new(
+            Population::new_with_specified_layers(
+                10,
+                &[[2, 2], [2, 2]],
+                create_genome,
+                &mut randomizer,
+            )?,
+            until_three,
+            train_genomes,
+            do_nothing,
+            TruncationSelection::new(0.5),
+            EvolutionConfig::new(),
+        );
+        // With a threshold of zero, every behaviour gets archived.
+        sut.enable_novelty_search(NoveltySearch::new(3, 0.0), describe_behaviour);
+        sut.enable_checkpoints(3, &checkpoint_path);
+
+        train(&mut sut, &mut randomizer)?;
+
+        let (mut resumed, mut restored_randomizer) = GymController::resume_from_checkpoint(
+            &checkpoint_path,
+            until_five,
+            train_genomes,
+            do_nothing,
+            TruncationSelection::new(0.5),
+        )?;
+
+        std::fs::remove_file(&checkpoint_path).unwrap();
+
+        assert_eq!(resumed.get_novelty_search(), sut.get_novelty_search());
+
+        // The descriptor isn't part of the checkpoint, so training without it fails.
+        assert!(train(&mut resumed, &mut restored_randomizer.clone()).is_err());
+
+        resumed.set_behaviour_descriptor(describe_behaviour);
+
+        train(&mut resumed, &mut restored_randomizer)?;
+
+        assert_eq!(
+            resumed
+                .get_novelty_search()
+                .as_ref()
+                .unwrap()
+                .get_archive()
+                .get_size(),
+            50
+        );
 
         Ok(())
     }
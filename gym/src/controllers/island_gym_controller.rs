@@ -0,0 +1,237 @@
+extern crate float_ord;
+extern crate neural_networks;
+extern crate randomization;
+extern crate rayon;
+
+use self::float_ord::FloatOrd;
+use self::neural_networks::evolution::controllers::create_next_generation::create_next_generation;
+use self::neural_networks::evolution::domain::evolution_config::EvolutionConfig;
+use self::neural_networks::evolution::domain::genome::GenomeTrait;
+use self::neural_networks::evolution::domain::islands::migrate_between_islands::migrate_between_islands;
+use self::neural_networks::evolution::domain::islands::migration_policy::MigrationPolicy;
+use self::neural_networks::evolution::domain::population::Population;
+use self::neural_networks::evolution::domain::population::PopulationTrait;
+use self::neural_networks::evolution::domain::selection::selection_strategy_trait::SelectionStrategyTrait;
+use self::neural_networks::neural_network::LayeredNeuralNetworkTrait;
+use self::neural_networks::neural_networks_error::NeuralNetworksError;
+use self::neural_networks::neuron::NeuronTrait;
+use self::randomization::splittable_randomizer_trait::SplittableRandomizerTrait;
+use self::rayon::prelude::*;
+use gym_error::GymError;
+use std::marker::PhantomData;
+
+/// Handles training several populations of genomes (islands) side by side, each of them on its own thread.
+///
+/// Islands evolve independently from each other, just like the population of a GymController, except that every
+/// few generations the best genomes of each island migrate to another one, as the migration policy dictates.
+/// Keeping the populations apart delays them all converging towards the same solution.
+///
+/// Each island gets its own randomizer derived from the one passed to 'train', so a seeded run can be replayed.
+///
+pub struct IslandGymController<
+    T: GenomeTrait<U, V> + Clone + Send + Sync,
+    U: LayeredNeuralNetworkTrait<V> + Clone + Send + Sync,
+    V: NeuronTrait + Clone + Send + Sync,
+    W: Fn(u32, &Option<T>) -> bool,
+    X: Fn(&mut Vec<T>, &mut Z) -> Result<(), GymError> + Sync,
+    Z: SplittableRandomizerTrait + Send,
+    S: SelectionStrategyTrait + Sync,
+> {
+    islands: Vec<Population<T, U, V>>,
+    generations: u32,
+    continue_condition: W,
+    train_genomes: X,
+    selection_strategy: S,
+    evolution_config: EvolutionConfig,
+    migration_policy: MigrationPolicy,
+    winner: Option<T>,
+    phantom_z: PhantomData<Z>,
+}
+
+impl<
+        T: GenomeTrait<U, V> + Clone + Send + Sync,
+        U: LayeredNeuralNetworkTrait<V> + Clone + Send + Sync,
+        V: NeuronTrait + Clone + Send + Sync,
+        W: Fn(u32, &Option<T>) -> bool,
+        X: Fn(&mut Vec<T>, &mut Z) -> Result<(), GymError> + Sync,
+        Z: SplittableRandomizerTrait + Send,
+        S: SelectionStrategyTrait + Sync,
+    > IslandGymController<T, U, V, W, X, Z, S>
+{
+    pub fn new(
+        islands: Vec<Population<T, U, V>>,
+        continue_condition: W,
+        train_genomes: X,
+        selection_strategy: S,
+        evolution_config: EvolutionConfig,
+        migration_policy: MigrationPolicy,
+    ) -> IslandGymController<T, U, V, W, X, Z, S> {
+        IslandGymController {
+            islands,
+            generations: 0,
+            continue_condition,
+            train_genomes,
+            selection_strategy,
+            evolution_config,
+            migration_policy,
+            winner: None,
+            phantom_z: PhantomData,
+        }
+    }
+
+    pub fn train<A: Fn(u32, U) -> T + Sync, B: Fn() -> U + Sync, C: Fn(u32, &mut Z) -> V + Sync>(
+        &mut self,
+        genome_creator: A,
+        neural_network_creator: B,
+        neuron_creator: C,
+        randomizer: &mut Z,
+    ) -> Result<Vec<Population<T, U, V>>, GymError> {
+        if self.islands.is_empty() {
+            return Err(GymError::NoIslands);
+        }
+
+        while (self.continue_condition)(self.generations, &self.winner) {
+            let mut island_randomizers: Vec<Z> = self
+                .islands
+                .iter()
+                .map(|_| randomizer.derive_child())
+                .collect();
+
+            let train_genomes = &self.train_genomes;
+
+            self.islands
+                .par_iter_mut()
+                .zip(island_randomizers.par_iter_mut())
+                .map(|(island, island_randomizer)| {
+                    train_genomes(island.get_genomes_mut()?, island_randomizer)
+                })
+                .collect::<Result<Vec<()>, GymError>>()?;
+
+            // Store winner amongst all the islands.
+            for island in self.islands.iter() {
+                for genome in island.get_genomes()?.iter() {
+                    if self.winner.as_ref().is_none_or(|winner| {
+                        FloatOrd(genome.get_fitness()) > FloatOrd(winner.get_fitness())
+                    }) {
+                        self.winner = Some(genome.clone());
+                    }
+                }
+            }
+
+            if self.migration_policy.is_migration_due(self.generations) {
+                migrate_between_islands(&mut self.islands, &self.migration_policy, randomizer)?;
+            }
+
+            let selection_strategy = &self.selection_strategy;
+            let evolution_config = &self.evolution_config;
+
+            self.islands = self
+                .islands
+                .par_iter()
+                .zip(island_randomizers.par_iter_mut())
+                .map(|(island, island_randomizer)| {
+                    create_next_generation(
+                        island,
+                        selection_strategy,
+                        evolution_config,
+                        &genome_creator,
+                        &neural_network_creator,
+                        &neuron_creator,
+                        island_randomizer,
+                    )
+                })
+                .collect::<Result<Vec<Population<T, U, V>>, NeuralNetworksError>>()?;
+
+            self.generations += 1;
+        }
+
+        Ok(self.islands.clone())
+    }
+
+    pub fn get_generations(&self) -> u32 {
+        self.generations
+    }
+
+    pub fn get_islands(&self) -> &Vec<Population<T, U, V>> {
+        &self.islands
+    }
+
+    pub fn get_migration_policy(&self) -> &MigrationPolicy {
+        &self.migration_policy
+    }
+
+    // None until a generation has been trained.
+    pub fn get_winner(&self) -> Option<&T> {
+        self.winner.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use self::neural_networks::evolution::domain::create_genome::create_genome;
+    use self::neural_networks::evolution::domain::genome::Genome;
+    use self::neural_networks::evolution::domain::islands::migration_topologies::MigrationTopologies;
+    use self::neural_networks::evolution::domain::selection::tournament_selection::TournamentSelection;
+    use self::neural_networks::neural_network::NeuralNetwork;
+    use self::neural_networks::neural_network::NeuralNetworkTrait;
+    use self::neural_networks::neuron::Neuron;
+    use self::neural_networks::neuron_activation::activation_functions::ActivationFunctions;
+    use self::randomization::seeded_randomizer::SeededRandomizer;
+    use super::*;
+
+    #[test]
+    fn test_can_train_several_islands_at_once() -> Result<(), GymError> {
+        let mut randomizer = SeededRandomizer::new(1);
+
+        let islands = (0..4)
+            .map(|_| {
+                Population::new_with_specified_layers(
+                    6,
+                    &[[2, 2], [2, 1]],
+                    create_genome,
+                    &mut randomizer,
+                )
+            })
+            .collect::<Result<
+                Vec<Population<Genome<NeuralNetwork<Neuron>, Neuron>, _, _>>,
+                NeuralNetworksError,
+            >>()?;
+
+        let mut sut = IslandGymController::new(
+            islands,
+            |generations, _current_winner| generations < 7,
+            |genomes: &mut Vec<Genome<NeuralNetwork<Neuron>, Neuron>>,
+             _randomizer: &mut SeededRandomizer|
+             -> Result<(), GymError> {
+                for genome in genomes.iter_mut() {
+                    let outputs = genome.get_neural_network().propagate(&[1.0, 0.0])?;
+                    genome.set_fitness(outputs[0]);
+                }
+
+                Ok(())
+            },
+            TournamentSelection::new(2),
+            EvolutionConfig::new(),
+            MigrationPolicy::new(MigrationTopologies::Random, 3, 1),
+        );
+
+        assert!(sut.get_winner().is_none());
+
+        let trained_islands = sut.train(
+            Genome::new,
+            NeuralNetwork::new,
+            |number_of_inputs, randomizer| {
+                Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+            },
+            &mut randomizer,
+        )?;
+
+        assert_eq!(sut.get_generations(), 7);
+        assert_eq!(trained_islands.len(), 4);
+        assert!(trained_islands.iter().all(|island| island.get_size() == 6));
+        assert!(sut.get_winner().unwrap().get_fitness() > 0.0);
+
+        Ok(())
+    }
+}
@@ -1 +1,3 @@
 pub mod gym_controller;
+pub mod island_gym_controller;
+pub mod multi_objective_gym_controller;
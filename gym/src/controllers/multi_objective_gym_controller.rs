@@ -0,0 +1,320 @@
+extern crate neural_networks;
+extern crate randomization;
+
+use self::neural_networks::evolution::controllers::create_next_generation::create_next_generation;
+use self::neural_networks::evolution::domain::evolution_config::EvolutionConfig;
+use self::neural_networks::evolution::domain::genome::GenomeTrait;
+use self::neural_networks::evolution::domain::multi_objective::assign_rank_and_crowding_as_fitness::assign_rank_and_crowding_as_fitness;
+use self::neural_networks::evolution::domain::multi_objective::pareto_front::ParetoFront;
+use self::neural_networks::evolution::domain::multi_objective::select_survivors_by_rank_and_crowding::select_survivors_by_rank_and_crowding;
+use self::neural_networks::evolution::domain::multi_objective::sort_into_non_dominated_fronts::sort_into_non_dominated_fronts;
+use self::neural_networks::evolution::domain::population::Population;
+use self::neural_networks::evolution::domain::population::PopulationTrait;
+use self::neural_networks::evolution::domain::selection::tournament_selection::TournamentSelection;
+use self::neural_networks::neural_network::LayeredNeuralNetworkTrait;
+use self::neural_networks::neuron::NeuronTrait;
+use self::randomization::randomizer::RandomizerTrait;
+use gym_error::GymError;
+use std::marker::PhantomData;
+
+/// Handles training a population of genomes against several objectives at once, through NSGA-II.
+///
+/// Instead of setting a single fitness, 'evaluate_genomes' returns a value for each of the named objectives of every
+/// genome it gets passed, in the same order. Every objective is maximized; negate those that should be minimized.
+///
+/// Each generation, the offspring of the population gets evaluated, and the genomes that survive out of both are
+/// those on the best non-dominated fronts, preferring the least crowded ones when a front doesn't fit whole.
+/// Parents get picked by a tournament of two that compares fronts first and crowding second.
+///
+/// Rather than a single winner, training produces the Pareto front of the last generation: the genomes for which
+/// no other genome is at least as good in every objective and better in one.
+///
+pub struct MultiObjectiveGymController<
+    T: GenomeTrait<U, V> + Clone,
+    U: LayeredNeuralNetworkTrait<V> + Clone,
+    V: NeuronTrait + Clone,
+    W: Fn(u32, &Option<ParetoFront<T>>) -> bool,
+    X: Fn(&[T], &mut Z) -> Result<Vec<Vec<f64>>, GymError>,
+    Z: RandomizerTrait,
+> {
+    population: Population<T, U, V>,
+    objective_names: Vec<String>,
+    generations: u32,
+    continue_condition: W,
+    evaluate_genomes: X,
+    evolution_config: EvolutionConfig,
+    pareto_front: Option<ParetoFront<T>>,
+    phantom_z: PhantomData<Z>,
+}
+
+impl<
+        T: GenomeTrait<U, V> + Clone,
+        U: LayeredNeuralNetworkTrait<V> + Clone,
+        V: NeuronTrait + Clone,
+        W: Fn(u32, &Option<ParetoFront<T>>) -> bool,
+        X: Fn(&[T], &mut Z) -> Result<Vec<Vec<f64>>, GymError>,
+        Z: RandomizerTrait,
+    > MultiObjectiveGymController<T, U, V, W, X, Z>
+{
+    pub fn new(
+        population: Population<T, U, V>,
+        objective_names: Vec<String>,
+        continue_condition: W,
+        evaluate_genomes: X,
+        evolution_config: EvolutionConfig,
+    ) -> MultiObjectiveGymController<T, U, V, W, X, Z> {
+        MultiObjectiveGymController {
+            population,
+            objective_names,
+            generations: 0,
+            continue_condition,
+            evaluate_genomes,
+            evolution_config,
+            pareto_front: None,
+            phantom_z: PhantomData,
+        }
+    }
+
+    pub fn train<A: Fn(u32, U) -> T, B: Fn() -> U, C: Fn(u32, &mut Z) -> V>(
+        &mut self,
+        genome_creator: A,
+        neural_network_creator: B,
+        neuron_creator: C,
+        randomizer: &mut Z,
+    ) -> Result<ParetoFront<T>, GymError> {
+        if self.population.get_size() == 0 {
+            return Err(GymError::NoGenomes);
+        }
+        if self.objective_names.is_empty() {
+            return Err(GymError::NoObjectives);
+        }
+
+        let population_size = self.population.get_size() as usize;
+
+        // The offspring shouldn't carry copies of their parents, as the parents compete with them anyway.
+        let mut evolution_config = self.evolution_config.clone();
+        evolution_config.set_number_of_elites(0);
+
+        let mut objectives = self.evaluate(self.population.get_genomes()?, randomizer)?;
+
+        while (self.continue_condition)(self.generations, &self.pareto_front) {
+            assign_rank_and_crowding_as_fitness(self.population.get_genomes_mut()?, &objectives)?;
+
+            let offspring = create_next_generation(
+                &self.population,
+                &TournamentSelection::new(2),
+                &evolution_config,
+                &genome_creator,
+                &neural_network_creator,
+                &neuron_creator,
+                randomizer,
+            )?;
+
+            let offspring_objectives = self.evaluate(offspring.get_genomes()?, randomizer)?;
+
+            let mut candidates = self.population.get_genomes()?.clone();
+            candidates.extend(offspring.get_genomes()?.iter().cloned());
+            objectives.extend(offspring_objectives);
+
+            let survivors = select_survivors_by_rank_and_crowding(&objectives, population_size);
+
+            let mut next_generation = Population::new();
+            let mut next_objectives = Vec::with_capacity(population_size);
+
+            for index in survivors {
+                next_generation.add(candidates[index].clone())?;
+                next_objectives.push(objectives[index].clone());
+            }
+
+            self.population = next_generation;
+            objectives = next_objectives;
+
+            self.pareto_front = Some(self.extract_pareto_front(&objectives)?);
+
+            self.generations += 1;
+        }
+
+        assign_rank_and_crowding_as_fitness(self.population.get_genomes_mut()?, &objectives)?;
+
+        let pareto_front = match &self.pareto_front {
+            Some(pareto_front) => pareto_front.clone(),
+            None => self.extract_pareto_front(&objectives)?,
+        };
+
+        self.pareto_front = Some(pareto_front.clone());
+
+        Ok(pareto_front)
+    }
+
+    fn evaluate(&self, genomes: &[T], randomizer: &mut Z) -> Result<Vec<Vec<f64>>, GymError> {
+        let objectives = (self.evaluate_genomes)(genomes, randomizer)?;
+
+        if objectives.len() != genomes.len() {
+            return Err(GymError::ObjectivesDontMatchGenomes {
+                number_of_objectives: objectives.len(),
+                number_of_genomes: genomes.len(),
+            });
+        }
+        if let Some(genome_objectives) = objectives
+            .iter()
+            .find(|genome_objectives| genome_objectives.len() != self.objective_names.len())
+        {
+            return Err(GymError::WrongNumberOfObjectiveValues {
+                values: genome_objectives.clone(),
+                objective_names: self.objective_names.clone(),
+            });
+        }
+
+        Ok(objectives)
+    }
+
+    fn extract_pareto_front(&self, objectives: &[Vec<f64>]) -> Result<ParetoFront<T>, GymError> {
+        let genomes = self.population.get_genomes()?;
+        let first_front = &sort_into_non_dominated_fronts(objectives)[0];
+
+        Ok(ParetoFront::new(
+            self.objective_names.clone(),
+            first_front
+                .iter()
+                .map(|index| genomes[*index].clone())
+                .collect(),
+            first_front
+                .iter()
+                .map(|index| objectives[*index].clone())
+                .collect(),
+        )?)
+    }
+
+    pub fn get_generations(&self) -> u32 {
+        self.generations
+    }
+
+    pub fn get_objective_names(&self) -> &Vec<String> {
+        &self.objective_names
+    }
+
+    pub fn get_population(&self) -> &Population<T, U, V> {
+        &self.population
+    }
+
+    // None until the genomes have been trained.
+    pub fn get_pareto_front(&self) -> Option<&ParetoFront<T>> {
+        self.pareto_front.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use self::neural_networks::evolution::domain::create_genome::create_genome;
+    use self::neural_networks::evolution::domain::genome::Genome;
+    use self::neural_networks::neural_network::NeuralNetwork;
+    use self::neural_networks::neural_network::NeuralNetworkTrait;
+    use self::neural_networks::neuron::Neuron;
+    use self::neural_networks::neuron_activation::activation_functions::ActivationFunctions;
+    use self::randomization::seeded_randomizer::SeededRandomizer;
+    use super::*;
+
+    #[test]
+    fn test_training_produces_a_front_of_mutually_non_dominated_genomes() -> Result<(), GymError> {
+        let mut randomizer = SeededRandomizer::new(3);
+
+        let population = Population::new_with_specified_layers(
+            12,
+            &[[1, 2], [2, 2]],
+            create_genome,
+            &mut randomizer,
+        )?;
+
+        // Both outputs can't be high at once for most networks, so the objectives pull apart.
+        let mut sut = MultiObjectiveGymController::new(
+            population,
+            vec!["first_output".to_string(), "second_output".to_string()],
+            |generations, _pareto_front| generations < 8,
+            |genomes: &[Genome<NeuralNetwork<Neuron>, Neuron>],
+             _randomizer: &mut SeededRandomizer|
+             -> Result<Vec<Vec<f64>>, GymError> {
+                genomes
+                    .iter()
+                    .map(|genome| {
+                        let outputs = genome.get_neural_network().propagate(&[1.0])?;
+                        Ok(vec![outputs[0] - outputs[1], outputs[1] - outputs[0] / 2.0])
+                    })
+                    .collect()
+            },
+            EvolutionConfig::new(),
+        );
+
+        assert!(sut.get_pareto_front().is_none());
+
+        let pareto_front = sut.train(
+            Genome::new,
+            NeuralNetwork::new,
+            |number_of_inputs, randomizer| {
+                Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+            },
+            &mut randomizer,
+        )?;
+
+        assert_eq!(sut.get_generations(), 8);
+        assert_eq!(sut.get_population().get_size(), 12);
+        assert!(pareto_front.get_size() > 0);
+        assert_eq!(
+            sut.get_pareto_front().map(|stored| stored.get_size()),
+            Some(pareto_front.get_size())
+        );
+
+        for first in 0..pareto_front.get_size() {
+            for second in 0..pareto_front.get_size() {
+                let first_objectives = pareto_front.get_objectives(first)?;
+                let second_objectives = pareto_front.get_objectives(second)?;
+
+                assert!(
+                    !(first_objectives[0] >= second_objectives[0]
+                        && first_objectives[1] >= second_objectives[1]
+                        && first_objectives != second_objectives)
+                );
+            }
+
+            assert_eq!(
+                pareto_front.get_objective(first, "second_output")?,
+                pareto_front.get_objectives(first)?[1]
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_training_fails_when_the_objectives_dont_match_their_names() -> Result<(), GymError> {
+        let mut randomizer = SeededRandomizer::new(3);
+
+        let population =
+            Population::new_with_specified_layers(4, &[[1, 1]], create_genome, &mut randomizer)?;
+
+        let mut sut = MultiObjectiveGymController::new(
+            population,
+            vec!["correctness".to_string(), "confidence".to_string()],
+            |generations, _pareto_front| generations < 2,
+            |genomes: &[Genome<NeuralNetwork<Neuron>, Neuron>],
+             _randomizer: &mut SeededRandomizer|
+             -> Result<Vec<Vec<f64>>, GymError> {
+                Ok(genomes.iter().map(|_| vec![1.0]).collect())
+            },
+            EvolutionConfig::new(),
+        );
+
+        assert!(sut
+            .train(
+                Genome::new,
+                NeuralNetwork::new,
+                |number_of_inputs, randomizer| {
+                    Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+                },
+                &mut randomizer,
+            )
+            .is_err());
+
+        Ok(())
+    }
+}
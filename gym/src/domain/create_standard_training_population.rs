@@ -3,12 +3,12 @@ extern crate randomization;
 
 use self::neural_networks::evolution::domain::genome::Genome;
 use self::neural_networks::evolution::domain::population::Population;
+use self::neural_networks::neural_network::LayeredNeuralNetworkTrait;
 use self::neural_networks::neural_network::NeuralNetwork;
-use self::neural_networks::neural_network::NeuralNetworkTrait;
 use self::neural_networks::neuron::Neuron;
-use self::neural_networks::neuron::NeuronTrait;
 use self::neural_networks::neuron_activation::choose_random_activation_function::choose_random_activation_function;
 use self::randomization::randomizer::Randomizer;
+use gym_error::GymError;
 
 type GN = Genome<NeuralNetwork<Neuron>, Neuron>;
 
@@ -16,12 +16,12 @@ pub fn create_standard_training_population(
     number_of_neural_networks: u32,
     layers_definition: &[[usize; 2]],
     randomizer: &mut Randomizer,
-) -> Result<Population<GN, NeuralNetwork<Neuron>, Neuron>, String> {
+) -> Result<Population<GN, NeuralNetwork<Neuron>, Neuron>, GymError> {
     Ok(Population::new_with_specified_layers(
         number_of_neural_networks,
         layers_definition,
         |genome_identifier, layers_definition, randomizer| {
-            Genome::new(
+            Ok(Genome::new(
                 genome_identifier,
                 NeuralNetwork::new_with_specified_layers(
                     layers_definition,
@@ -33,8 +33,8 @@ pub fn create_standard_training_population(
                             randomizer,
                         )
                     },
-                ),
-            )
+                )?,
+            ))
         },
         randomizer,
     )?)
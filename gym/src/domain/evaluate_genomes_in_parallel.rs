@@ -0,0 +1,129 @@
+extern crate neural_networks;
+extern crate randomization;
+extern crate rayon;
+
+use self::neural_networks::evolution::domain::genome::GenomeTrait;
+use self::neural_networks::neural_network::NeuralNetworkTrait;
+use self::neural_networks::neuron::NeuronTrait;
+use self::randomization::splittable_randomizer_trait::SplittableRandomizerTrait;
+use self::rayon::prelude::*;
+use gym_error::GymError;
+
+/// Sets the fitness of every genome to what the given function returns for it, spreading the genomes across rayon's
+/// thread pool. Meant to be called from the closure that trains the genomes of a GymController.
+///
+/// Each genome gets its own randomizer, derived in order from the given one before any thread starts, so the results
+/// don't depend on how the work ends up scheduled. The first error the fitness function returns stops the evaluation
+/// and gets returned.
+pub fn evaluate_genomes_in_parallel<
+    T: GenomeTrait<U, V> + Send,
+    U: NeuralNetworkTrait<V>,
+    V: NeuronTrait,
+    W: SplittableRandomizerTrait + Send,
+    X: Fn(&T, &mut W) -> Result<f64, GymError> + Sync,
+>(
+    genomes: &mut [T],
+    fitness_function: &X,
+    randomizer: &mut W,
+) -> Result<(), GymError> {
+    let mut randomizers: Vec<W> = genomes.iter().map(|_| randomizer.derive_child()).collect();
+
+    genomes
+        .par_iter_mut()
+        .zip(randomizers.par_iter_mut())
+        .try_for_each(|(genome, genome_randomizer)| {
+            let fitness = fitness_function(genome, genome_randomizer)?;
+
+            genome.set_fitness(fitness);
+
+            Ok(())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+
+    use self::neural_networks::evolution::domain::create_genome::create_genome;
+    use self::neural_networks::evolution::domain::genome::Genome;
+    use self::neural_networks::neural_network::NeuralNetwork;
+    use self::neural_networks::neural_networks_error::NeuralNetworksError;
+    use self::neural_networks::neuron::Neuron;
+    use self::randomization::randomizer::RandomizerTrait;
+    use self::randomization::seeded_randomizer::SeededRandomizer;
+    use super::*;
+
+    #[test]
+    fn test_parallel_evaluation_is_deterministic() -> Result<(), GymError> {
+        let mut randomizer = SeededRandomizer::new(11);
+
+        let genomes: Vec<Genome<NeuralNetwork<Neuron>, Neuron>> = (0..50)
+            .map(|index| create_genome(index + 1, &[[2, 2], [2, 1]], &mut randomizer))
+            .collect::<Result<_, _>>()?;
+
+        let fitness_function = |genome: &Genome<NeuralNetwork<Neuron>, Neuron>,
+                                randomizer: &mut SeededRandomizer|
+         -> Result<f64, GymError> {
+            Ok(genome.get_neural_network().propagate(&[0.3, 0.7])?[0]
+                + randomizer.generate_float_from_0_to_1())
+        };
+
+        let mut first_run = genomes.clone();
+        let mut second_run = genomes;
+
+        evaluate_genomes_in_parallel(
+            &mut first_run,
+            &fitness_function,
+            &mut SeededRandomizer::new(5),
+        )?;
+        evaluate_genomes_in_parallel(
+            &mut second_run,
+            &fitness_function,
+            &mut SeededRandomizer::new(5),
+        )?;
+
+        let first_fitnesses: Vec<f64> = first_run
+            .iter()
+            .map(|genome| genome.get_fitness())
+            .collect();
+        let second_fitnesses: Vec<f64> = second_run
+            .iter()
+            .map(|genome| genome.get_fitness())
+            .collect();
+
+        assert_eq!(first_fitnesses, second_fitnesses);
+        assert!(first_fitnesses.iter().all(|fitness| *fitness > 0.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_an_error_of_the_fitness_function_gets_returned() -> Result<(), GymError> {
+        let mut randomizer = SeededRandomizer::new(11);
+
+        let mut genomes: Vec<Genome<NeuralNetwork<Neuron>, Neuron>> = (0..20)
+            .map(|index| create_genome(index + 1, &[[2, 1]], &mut randomizer))
+            .collect::<Result<_, _>>()?;
+
+        // The genomes take two inputs, so propagating three fails.
+        let result = evaluate_genomes_in_parallel(
+            &mut genomes,
+            &|genome: &Genome<NeuralNetwork<Neuron>, Neuron>,
+              _randomizer: &mut SeededRandomizer| {
+                Ok(genome.get_neural_network().propagate(&[0.3, 0.7, 0.1])?[0])
+            },
+            &mut randomizer,
+        );
+
+        assert_eq!(
+            result,
+            Err(GymError::NeuralNetworks(
+                NeuralNetworksError::WrongNumberOfInputs {
+                    expected: 2,
+                    received: 3
+                }
+            ))
+        );
+
+        Ok(())
+    }
+}
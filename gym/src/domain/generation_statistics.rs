@@ -0,0 +1,241 @@
+extern crate float_ord;
+extern crate neural_networks;
+extern crate serde;
+
+use self::float_ord::FloatOrd;
+use self::neural_networks::evolution::domain::genome::GenomeTrait;
+use self::neural_networks::neural_network::NeuralNetworkTrait;
+use self::neural_networks::neuron::NeuronTrait;
+use self::serde::{Deserialize, Serialize};
+use gym_error::GymError;
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GenerationStatistics {
+    generation: u32,
+    best_fitness: f64,
+    worst_fitness: f64,
+    mean_fitness: f64,
+    median_fitness: f64,
+    standard_deviation_of_fitness: f64,
+    diversity: Option<f64>,
+    elapsed_seconds: f64,
+}
+
+impl GenerationStatistics {
+    // Expects the genomes of a generation that has already been trained. The elapsed time is left
+    // at zero until it gets set.
+    pub fn from_genomes<T: GenomeTrait<U, V>, U: NeuralNetworkTrait<V>, V: NeuronTrait>(
+        generation: u32,
+        genomes: &[T],
+    ) -> Result<GenerationStatistics, GymError> {
+        if genomes.is_empty() {
+            return Err(GymError::EmptyGeneration);
+        }
+
+        let mut fitnesses: Vec<f64> = genomes.iter().map(|genome| genome.get_fitness()).collect();
+        fitnesses.sort_by_key(|fitness| FloatOrd(*fitness));
+
+        let number_of_genomes = fitnesses.len() as f64;
+        let mean_fitness = fitnesses.iter().sum::<f64>() / number_of_genomes;
+
+        let middle = fitnesses.len() / 2;
+        let median_fitness = if fitnesses.len().is_multiple_of(2) {
+            (fitnesses[middle - 1] + fitnesses[middle]) / 2.0
+        } else {
+            fitnesses[middle]
+        };
+
+        let variance = fitnesses
+            .iter()
+            .map(|fitness| (fitness - mean_fitness).powi(2))
+            .sum::<f64>()
+            / number_of_genomes;
+
+        Ok(GenerationStatistics {
+            generation,
+            best_fitness: fitnesses[fitnesses.len() - 1],
+            worst_fitness: fitnesses[0],
+            mean_fitness,
+            median_fitness,
+            standard_deviation_of_fitness: variance.sqrt(),
+            diversity: compute_diversity(genomes),
+            elapsed_seconds: 0.0,
+        })
+    }
+
+    pub fn get_generation(&self) -> u32 {
+        self.generation
+    }
+
+    pub fn get_best_fitness(&self) -> f64 {
+        self.best_fitness
+    }
+
+    pub fn get_worst_fitness(&self) -> f64 {
+        self.worst_fitness
+    }
+
+    pub fn get_mean_fitness(&self) -> f64 {
+        self.mean_fitness
+    }
+
+    pub fn get_median_fitness(&self) -> f64 {
+        self.median_fitness
+    }
+
+    pub fn get_standard_deviation_of_fitness(&self) -> f64 {
+        self.standard_deviation_of_fitness
+    }
+
+    // The mean euclidean distance between the weights and biases of each genome and the average
+    // of all of them. It goes towards zero as the population converges. There is none when the
+    // parameters of the genomes don't line up, such as for NEAT genomes that grew apart.
+    pub fn get_diversity(&self) -> Option<f64> {
+        self.diversity
+    }
+
+    pub fn get_elapsed_seconds(&self) -> f64 {
+        self.elapsed_seconds
+    }
+
+    pub fn set_elapsed_time(&mut self, elapsed_time: Duration) {
+        self.elapsed_seconds = elapsed_time.as_secs_f64();
+    }
+}
+
+fn compute_diversity<T: GenomeTrait<U, V>, U: NeuralNetworkTrait<V>, V: NeuronTrait>(
+    genomes: &[T],
+) -> Option<f64> {
+    let first_neural_network = genomes[0].get_neural_network();
+
+    if genomes.iter().any(|genome| {
+        !genome
+            .get_neural_network()
+            .has_same_parameter_layout(first_neural_network)
+    }) {
+        return None;
+    }
+
+    let parameters: Vec<Vec<f64>> = genomes
+        .iter()
+        .map(|genome| genome.get_neural_network().parameters())
+        .collect();
+
+    let mut centroid = vec![0.0; parameters[0].len()];
+
+    for genome_parameters in parameters.iter() {
+        for (total, parameter) in centroid.iter_mut().zip(genome_parameters.iter()) {
+            *total += parameter / parameters.len() as f64;
+        }
+    }
+
+    let total_distance: f64 = parameters
+        .iter()
+        .map(|genome_parameters| {
+            genome_parameters
+                .iter()
+                .zip(centroid.iter())
+                .map(|(parameter, center)| (parameter - center).powi(2))
+                .sum::<f64>()
+                .sqrt()
+        })
+        .sum();
+
+    Some(total_distance / parameters.len() as f64)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use self::neural_networks::evolution::domain::create_genome::create_genome;
+    use self::neural_networks::evolution::domain::genome::Genome;
+    use self::neural_networks::neural_network::NeuralNetwork;
+    use self::neural_networks::neural_networks_error::NeuralNetworksError;
+    use self::neural_networks::neuron::Neuron;
+    use super::*;
+
+    extern crate randomization;
+
+    use self::randomization::randomizer::Randomizer;
+
+    #[test]
+    fn test_computes_the_statistics_of_the_fitnesses() -> Result<(), GymError> {
+        let mut randomizer = Randomizer::new();
+
+        let genomes: Vec<Genome<NeuralNetwork<Neuron>, Neuron>> = [4.0, 1.0, 3.0, 8.0]
+            .iter()
+            .enumerate()
+            .map(|(index, fitness)| {
+                let mut genome = create_genome(index as u32 + 1, &[[2, 1]], &mut randomizer)?;
+                genome.set_fitness(*fitness);
+                Ok(genome)
+            })
+            .collect::<Result<_, NeuralNetworksError>>()?;
+
+        let mut statistics = GenerationStatistics::from_genomes(3, &genomes)?;
+        statistics.set_elapsed_time(Duration::from_millis(1500));
+
+        assert_eq!(statistics.get_generation(), 3);
+        assert_eq!(statistics.get_best_fitness(), 8.0);
+        assert_eq!(statistics.get_worst_fitness(), 1.0);
+        assert_eq!(statistics.get_mean_fitness(), 4.0);
+        assert_eq!(statistics.get_median_fitness(), 3.5);
+        assert!((statistics.get_standard_deviation_of_fitness() - 2.5495097).abs() < 1e-6);
+        assert!(statistics.get_diversity().unwrap() > 0.0);
+        assert_eq!(statistics.get_elapsed_seconds(), 1.5);
+
+        // Identical genomes have no diversity at all.
+        let clones = vec![genomes[0].clone(), genomes[0].clone()];
+
+        assert_eq!(
+            GenerationStatistics::from_genomes(0, &clones)?.get_diversity(),
+            Some(0.0)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_genomes_whose_parameters_dont_line_up_have_no_diversity() -> Result<(), GymError> {
+        use self::neural_networks::neat::domain::innovation_tracker::InnovationTracker;
+        use self::neural_networks::neat::domain::mutate_add_node::mutate_add_node;
+        use self::neural_networks::neat::domain::neat_network::NeatNetwork;
+        use self::neural_networks::neuron_activation::activation_functions::ActivationFunctions;
+
+        let mut randomizer = Randomizer::new();
+        let mut innovation_tracker = InnovationTracker::new(2, 1);
+
+        let mut genomes: Vec<Genome<NeatNetwork, Neuron>> = (1..=2)
+            .map(|genome_identifier| {
+                Ok(Genome::new(
+                    genome_identifier,
+                    NeatNetwork::new_with_specified_layers(
+                        &[[2, 1]],
+                        &mut randomizer,
+                        |number_of_inputs, randomizer| {
+                            Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+                        },
+                    )?,
+                ))
+            })
+            .collect::<Result<_, GymError>>()?;
+
+        assert!(GenerationStatistics::from_genomes(0, &genomes)?
+            .get_diversity()
+            .is_some());
+
+        mutate_add_node(
+            genomes[1].get_neural_network_mut(),
+            &mut innovation_tracker,
+            &mut randomizer,
+        )?;
+
+        assert_eq!(
+            GenerationStatistics::from_genomes(0, &genomes)?.get_diversity(),
+            None
+        );
+
+        Ok(())
+    }
+}
@@ -1,2 +1,6 @@
 pub mod create_standard_training_population;
+pub mod evaluate_genomes_in_parallel;
+pub mod generation_statistics;
 pub mod models;
+pub mod training_checkpoint;
+pub mod training_history;
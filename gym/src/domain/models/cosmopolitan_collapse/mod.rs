@@ -1 +1,2 @@
 pub mod train_for_domain;
+pub mod train_for_domain_on_multiple_objectives;
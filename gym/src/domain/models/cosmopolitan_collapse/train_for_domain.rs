@@ -2,24 +2,26 @@ extern crate cosmopolitan_collapse;
 extern crate neural_networks;
 extern crate randomization;
 
+use self::neural_networks::evolution::domain::evolution_config::EvolutionConfig;
 use self::neural_networks::evolution::domain::genome::Genome;
 use self::neural_networks::evolution::domain::population::Population;
+use self::neural_networks::evolution::domain::selection::truncation_selection::TruncationSelection;
 use self::neural_networks::neural_network::NeuralNetwork;
 use self::neural_networks::neuron::Neuron;
-use self::neural_networks::neuron::NeuronTrait;
 use self::neural_networks::neuron_activation::choose_random_activation_function::choose_random_activation_function;
 use self::randomization::randomizer::Randomizer;
 use controllers::gym_controller::GymController;
 use domain::create_standard_training_population::create_standard_training_population;
+use gym_error::GymError;
 
 pub fn train_for_domain<
     T: Fn(u32, &Option<Genome<NeuralNetwork<Neuron>, Neuron>>) -> bool,
-    U: Fn(&mut Vec<Genome<NeuralNetwork<Neuron>, Neuron>>, &mut Randomizer) -> Result<(), String>,
+    U: Fn(&mut Vec<Genome<NeuralNetwork<Neuron>, Neuron>>, &mut Randomizer) -> Result<(), GymError>,
 >(
     layers_definition: &[[usize; 2]],
     continue_condition: T,
     train_genomes: U,
-) -> Result<Genome<NeuralNetwork<Neuron>, Neuron>, String> {
+) -> Result<Genome<NeuralNetwork<Neuron>, Neuron>, GymError> {
     let number_of_neural_networks = 20;
 
     let mut randomizer = Randomizer::new();
@@ -43,25 +45,25 @@ pub fn train_for_domain<
         continue_condition,
         train_genomes,
         operation_to_perform_on_evolved_population,
+        TruncationSelection::new(0.5),
+        EvolutionConfig::new(),
     );
 
-    let _ = gym
-        .train(
-            Genome::new,
-            NeuralNetwork::new,
-            |number_of_inputs, randomizer| {
-                Neuron::new(
-                    number_of_inputs,
-                    choose_random_activation_function(randomizer),
-                    randomizer,
-                )
-            },
-            |_generation_number, _population| {},
-            &mut randomizer,
-        )
-        .unwrap();
+    let _ = gym.train(
+        Genome::new,
+        NeuralNetwork::new,
+        |number_of_inputs, randomizer| {
+            Neuron::new(
+                number_of_inputs,
+                choose_random_activation_function(randomizer),
+                randomizer,
+            )
+        },
+        |_generation_number, _population, _generation_statistics| {},
+        &mut randomizer,
+    )?;
 
-    Ok(gym.get_winner().clone())
+    gym.get_winner().cloned().ok_or(GymError::NoWinner)
 }
 
 #[cfg(test)]
@@ -74,7 +76,7 @@ mod tests {
     use self::neural_networks::neural_network::NeuralNetworkTrait;
 
     #[test]
-    fn test_can_train_urban_neural_network_for_settling_in_component() -> Result<(), String> {
+    fn test_can_train_urban_neural_network_for_settling_in_component() -> Result<(), GymError> {
         let winner = train_for_domain(
             &[[3, 4], [4, 4], [4, 3]],
             |generation_number: u32,
@@ -88,7 +90,7 @@ mod tests {
             },
             |genomes: &mut Vec<Genome<NeuralNetwork<Neuron>, Neuron>>,
              _randomizer: &mut Randomizer|
-             -> Result<(), String> {
+             -> Result<(), GymError> {
                 for genome in genomes.iter_mut() {
                     // For this domain:
                     // Inputs: [0] CavesPresent [1] BuildingsPresent [2] IsHomeless
@@ -99,7 +101,7 @@ mod tests {
                         .propagate(&[1.0, 1.0, 1.0])
                         .unwrap();
 
-                    if get_index_max_output(&outputs) == 1 {
+                    if get_index_max_output(&outputs)? == 1 {
                         let current_fitness = genome.get_fitness();
                         genome.set_fitness(current_fitness + 10.0);
                     }
@@ -109,7 +111,7 @@ mod tests {
                         .propagate(&[0.0, 1.0, 1.0])
                         .unwrap();
 
-                    if get_index_max_output(&outputs) == 1 {
+                    if get_index_max_output(&outputs)? == 1 {
                         let current_fitness = genome.get_fitness();
                         genome.set_fitness(current_fitness + 10.0);
                     }
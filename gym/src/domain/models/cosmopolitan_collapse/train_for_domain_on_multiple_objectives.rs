@@ -0,0 +1,128 @@
+extern crate neural_networks;
+extern crate randomization;
+
+use self::neural_networks::evolution::domain::evolution_config::EvolutionConfig;
+use self::neural_networks::evolution::domain::genome::Genome;
+use self::neural_networks::evolution::domain::multi_objective::pareto_front::ParetoFront;
+use self::neural_networks::neural_network::NeuralNetwork;
+use self::neural_networks::neuron::Neuron;
+use self::neural_networks::neuron_activation::choose_random_activation_function::choose_random_activation_function;
+use self::randomization::randomizer::Randomizer;
+use controllers::multi_objective_gym_controller::MultiObjectiveGymController;
+use domain::create_standard_training_population::create_standard_training_population;
+use gym_error::GymError;
+
+pub fn train_for_domain_on_multiple_objectives<
+    T: Fn(u32, &Option<ParetoFront<Genome<NeuralNetwork<Neuron>, Neuron>>>) -> bool,
+    U: Fn(
+        &[Genome<NeuralNetwork<Neuron>, Neuron>],
+        &mut Randomizer,
+    ) -> Result<Vec<Vec<f64>>, GymError>,
+>(
+    layers_definition: &[[usize; 2]],
+    objective_names: Vec<String>,
+    continue_condition: T,
+    evaluate_genomes: U,
+) -> Result<ParetoFront<Genome<NeuralNetwork<Neuron>, Neuron>>, GymError> {
+    let number_of_neural_networks = 20;
+
+    let mut randomizer = Randomizer::new();
+
+    let training_population = create_standard_training_population(
+        number_of_neural_networks,
+        layers_definition,
+        &mut randomizer,
+    )?;
+
+    let mut gym = MultiObjectiveGymController::new(
+        training_population,
+        objective_names,
+        continue_condition,
+        evaluate_genomes,
+        EvolutionConfig::new(),
+    );
+
+    gym.train(
+        Genome::new,
+        NeuralNetwork::new,
+        |number_of_inputs, randomizer| {
+            Neuron::new(
+                number_of_inputs,
+                choose_random_activation_function(randomizer),
+                randomizer,
+            )
+        },
+        &mut randomizer,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use self::neural_networks::evolution::domain::genome::GenomeTrait;
+    use self::neural_networks::get_index_max_output::get_index_max_output;
+    use self::neural_networks::neural_network::LayeredNeuralNetworkTrait;
+    use self::neural_networks::neural_network::NeuralNetworkTrait;
+
+    #[test]
+    fn test_can_trade_off_settling_correctly_against_size_and_confidence() -> Result<(), GymError> {
+        let pareto_front = train_for_domain_on_multiple_objectives(
+            &[[3, 4], [4, 3]],
+            vec![
+                "correctness".to_string(),
+                "size".to_string(),
+                "confidence".to_string(),
+            ],
+            |generation_number, _pareto_front| generation_number < 10,
+            |genomes: &[Genome<NeuralNetwork<Neuron>, Neuron>],
+             _randomizer: &mut Randomizer|
+             -> Result<Vec<Vec<f64>>, GymError> {
+                genomes
+                    .iter()
+                    .map(|genome| {
+                        // Inputs: [0] CavesPresent [1] BuildingsPresent [2] IsHomeless
+                        // Outputs: [0] SettleInCave [1] SettleInBuilding [2] SetUpCamp
+                        let outputs = genome.get_neural_network().propagate(&[1.0, 1.0, 1.0])?;
+
+                        let correctness = if get_index_max_output(&outputs)? == 1 {
+                            1.0
+                        } else {
+                            0.0
+                        };
+
+                        // The layers are fixed, so the size that counts is the weights that do anything.
+                        let size = genome
+                            .get_neural_network()
+                            .get_layers()
+                            .iter()
+                            .flat_map(|layer| layer.get_weights().iter())
+                            .filter(|weight| weight.abs() > 0.1)
+                            .count() as f64;
+
+                        let confidence = outputs[1]
+                            - outputs
+                                .iter()
+                                .enumerate()
+                                .filter(|(index, _)| *index != 1)
+                                .map(|(_, output)| *output)
+                                .fold(f64::MIN, f64::max);
+
+                        Ok(vec![correctness, -size, confidence])
+                    })
+                    .collect()
+            },
+        )?;
+
+        assert!(pareto_front.get_size() > 0);
+        assert_eq!(pareto_front.get_objective_names().len(), 3);
+
+        for index in 0..pareto_front.get_size() {
+            assert!(pareto_front.get_genomes()[index].get_fitness() >= 0.0);
+            assert!(pareto_front.get_objective(index, "size")? <= 0.0);
+        }
+
+        Ok(())
+    }
+}
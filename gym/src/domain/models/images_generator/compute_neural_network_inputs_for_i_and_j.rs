@@ -1,25 +1,13 @@
 extern crate geometry;
-extern crate neural_networks;
-extern crate randomization;
 
 use self::geometry::manhattan_distance::manhattan_distance;
-use self::neural_networks::neural_network::NeuralNetworkTrait;
-use self::neural_networks::neuron::NeuronTrait;
-use self::randomization::randomizer::RandomizerTrait;
 
-pub fn request_rgb_from_neural_network_for_i_and_j<
-    T: NeuralNetworkTrait<U>,
-    U: NeuronTrait,
-    V: RandomizerTrait,
->(
+pub fn compute_neural_network_inputs_for_i_and_j(
     i: u32,
     j: u32,
     image_width: u32,
     image_height: u32,
-    neural_network: &T,
-    neural_network_outputs: &mut Vec<f64>,
-    _randomizer: &mut V,
-) {
+) -> [f64; 7] {
     let distance_from_top =
         (manhattan_distance(i, j, image_width / 2, 0) as f64) / (image_height as f64);
     let distance_from_top_left = (manhattan_distance(i, j, 0, 0) as f64) / (image_height as f64);
@@ -49,19 +37,13 @@ pub fn request_rgb_from_neural_network_for_i_and_j<
 
     // They come from 0.0 to 2.0.
 
-    let outputs = neural_network
-        .propagate(&[
-            distance_from_top / 2.0,
-            distance_from_top_left / 2.0,
-            distance_from_left / 2.0,
-            distance_from_center / 2.0,
-            distance_from_right / 2.0,
-            distance_from_bottom / 2.0,
-            distance_from_bottom_right / 2.0,
-        ])
-        .unwrap();
-
-    assert!(outputs.len() == 4);
-
-    neural_network_outputs.extend(outputs);
+    [
+        distance_from_top / 2.0,
+        distance_from_top_left / 2.0,
+        distance_from_left / 2.0,
+        distance_from_center / 2.0,
+        distance_from_right / 2.0,
+        distance_from_bottom / 2.0,
+        distance_from_bottom_right / 2.0,
+    ]
 }
@@ -0,0 +1,89 @@
+// Turns the pixels written into the PNGs, row after row and RGBA after RGBA, into the
+// (channels, height, width) layout the convolutional layers read, with values from 0 to 1.
+pub fn convert_rgba_pixels_to_tensor(
+    pixels: &[u8],
+    image_width: u32,
+    image_height: u32,
+) -> Vec<f64> {
+    let number_of_pixels = (image_width * image_height) as usize;
+
+    (0..4)
+        .flat_map(|channel| {
+            pixels
+                .chunks(4)
+                .take(number_of_pixels)
+                .map(move |pixel| pixel[channel] as f64 / 255.0)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    extern crate neural_networks;
+    extern crate randomization;
+
+    use self::neural_networks::layer::Layer;
+    use self::neural_networks::network_layers::conv2d::Conv2d;
+    use self::neural_networks::network_layers::flatten::Flatten;
+    use self::neural_networks::network_layers::modular_neural_network::ModularNeuralNetwork;
+    use self::neural_networks::network_layers::pooling2d::{Pooling2d, Poolings};
+    use self::neural_networks::neural_network::{
+        LayeredNeuralNetworkTrait, NeuralNetwork, NeuralNetworkTrait,
+    };
+    use self::neural_networks::neuron::Neuron;
+    use self::neural_networks::neuron_activation::activation_functions::ActivationFunctions;
+    use self::neural_networks::neuron_initialization::initializers::Initializers;
+    use self::randomization::seeded_randomizer::SeededRandomizer;
+    use domain::models::images_generator::generate_pixels_from_neural_network::generate_pixels_from_neural_network;
+    use gym_error::GymError;
+
+    #[test]
+    fn test_a_convolutional_critic_can_score_a_generated_image() -> Result<(), GymError> {
+        let mut randomizer = SeededRandomizer::new(5);
+
+        let neural_network = NeuralNetwork::<Neuron>::new_with_specified_layers(
+            &[[7, 4]],
+            &mut randomizer,
+            |number_of_inputs, randomizer| {
+                Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+            },
+        )?;
+
+        let pixels = generate_pixels_from_neural_network(&neural_network, 8, 6)?;
+        let tensor = convert_rgba_pixels_to_tensor(&pixels, 8, 6);
+
+        assert_eq!(tensor.len(), 4 * 6 * 8);
+        assert_eq!(tensor[8], pixels[8 * 4] as f64 / 255.0);
+        assert_eq!(tensor[6 * 8], pixels[1] as f64 / 255.0);
+
+        let mut critic = ModularNeuralNetwork::new();
+        critic.add(Box::new(Conv2d::new(
+            [4, 6, 8],
+            3,
+            [3, 3],
+            ActivationFunctions::Relu,
+            Initializers::HeNormal,
+            &mut randomizer,
+        )?))?;
+        critic.add(Box::new(Pooling2d::new([3, 4, 6], [2, 2], Poolings::Max)?))?;
+        critic.add(Box::new(Flatten::new(vec![3, 2, 3])))?;
+        critic.add(Box::new(Layer::create_layer(
+            18,
+            1,
+            &mut randomizer,
+            |number_of_inputs, randomizer| {
+                Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+            },
+        )?))?;
+
+        let score = critic.propagate(&tensor)?;
+
+        assert_eq!(score.len(), 1);
+        assert!(score[0] > 0.0 && score[0] < 1.0);
+
+        Ok(())
+    }
+}
@@ -14,6 +14,7 @@ use self::neural_networks::neuron::NeuronTrait;
 
 use self::file_system::deserialize_json_from_string::deserialize_json_from_string;
 use self::serde::Deserialize;
+use gym_error::GymError;
 
 pub fn create_population_with_stored_genomes<
     'a,
@@ -26,7 +27,7 @@ pub fn create_population_with_stored_genomes<
     display_controller: &W,
     population_creator: X,
     saved_genomes_as_strings: &'a [String],
-) -> Result<Option<Population<T, U, V>>, String> {
+) -> Result<Option<Population<T, U, V>>, GymError> {
     display_controller.write_information("Found previous genomes saved in 'data/images_generation'. Will create a population with them.").unwrap();
 
     let mut population = population_creator();
@@ -7,6 +7,7 @@ extern crate user_interface;
 use self::neural_networks::evolution::domain::genome::GenomeTrait;
 use self::neural_networks::evolution::domain::population::Population;
 use self::neural_networks::neural_network::NeuralNetworkTrait;
+use self::neural_networks::neural_networks_error::NeuralNetworksError;
 use self::neural_networks::neuron::NeuronTrait;
 use self::randomization::randomizer::RandomizerTrait;
 use self::serde::Deserialize;
@@ -17,6 +18,7 @@ use domain::models::images_generator::constants::SAVED_GENOMES_DIRECTORY;
 use self::file_system::are_there_filenames_with_extension_in_directory::are_there_filenames_with_extension_in_directory;
 use domain::models::images_generator::create_new_population::create_new_population;
 use domain::models::images_generator::create_population_with_stored_genomes::create_population_with_stored_genomes;
+use gym_error::GymError;
 
 pub fn establish_training_population<
     'a,
@@ -26,14 +28,14 @@ pub fn establish_training_population<
     W: DisplayControllerTrait,
     X: RandomizerTrait,
     Y: Fn() -> Population<T, U, V>,
-    Z: Fn(&mut X) -> Result<Population<T, U, V>, String>,
+    Z: Fn(&mut X) -> Result<Population<T, U, V>, NeuralNetworksError>,
 >(
     population_creator: Y,
     defined_population_creator: Z,
     display_controller: &W,
     saved_genomes_as_strings: &'a [String],
     randomizer: &mut X,
-) -> Result<Option<Population<T, U, V>>, String> {
+) -> Result<Option<Population<T, U, V>>, GymError> {
     if are_there_filenames_with_extension_in_directory(SAVED_GENOMES_DIRECTORY, "json").unwrap() {
         match create_population_with_stored_genomes(
             display_controller,
@@ -51,10 +53,7 @@ pub fn establish_training_population<
                         .as_str(),
                     )
                     .unwrap();
-                Err(format!(
-                    "Failed to create a population with stores genomes. Error: {}",
-                    error
-                ))
+                Err(error)
             }
         }
     } else {
@@ -64,10 +63,7 @@ pub fn establish_training_population<
             Ok(population) => Ok(population),
             Err(error) => {
                 display_controller.write_alert(format!("Failed while creating a new population to start from scratch. Error: {}", error).as_str()).unwrap();
-                Err(format!(
-                    "Failed to create a new training population. Error: {}",
-                    error
-                ))
+                Err(error)
             }
         }
     }
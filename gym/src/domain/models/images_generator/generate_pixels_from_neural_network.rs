@@ -1,45 +1,43 @@
 extern crate geometry;
 extern crate neural_networks;
-extern crate randomization;
 
 use self::neural_networks::neural_network::NeuralNetworkTrait;
+use self::neural_networks::neural_networks_error::NeuralNetworksError;
 use self::neural_networks::neuron::NeuronTrait;
-use self::randomization::randomizer::RandomizerTrait;
+use domain::models::images_generator::compute_neural_network_inputs_for_i_and_j::compute_neural_network_inputs_for_i_and_j;
 use domain::models::images_generator::normalize_to_rgba_range::normalize_to_rgba_range;
-use domain::models::images_generator::request_rgb_from_neural_network_for_i_and_j::request_rgb_from_neural_network_for_i_and_j;
 
-pub fn generate_pixels_from_neural_network<
-    T: NeuralNetworkTrait<U>,
-    U: NeuronTrait,
-    V: RandomizerTrait,
->(
+pub fn generate_pixels_from_neural_network<T: NeuralNetworkTrait<U>, U: NeuronTrait>(
     neural_network: &T,
     image_width: u32,
     image_height: u32,
-    randomizer: &mut V,
-) -> Result<Vec<u8>, String> {
-    let mut image: Vec<u8> = Vec::new();
+) -> Result<Vec<u8>, NeuralNetworksError> {
+    let mut image: Vec<u8> = Vec::with_capacity((image_width * image_height * 4) as usize);
 
-    let mut neural_network_outputs: Vec<f64> = Vec::new();
+    // Propagating a row of pixels at a time keeps both buffers to the size of a row, and reuses
+    // them for every row.
+    let mut row_inputs: Vec<f64> = Vec::with_capacity(image_width as usize * 7);
+    let mut row_outputs: Vec<f64> = Vec::with_capacity(image_width as usize * 4);
 
     for i in 0..image_height {
+        row_inputs.clear();
+
         for j in 0..image_width {
-            request_rgb_from_neural_network_for_i_and_j(
+            row_inputs.extend_from_slice(&compute_neural_network_inputs_for_i_and_j(
                 i,
                 j,
                 image_width,
                 image_height,
-                neural_network,
-                &mut neural_network_outputs,
-                randomizer,
-            );
+            ));
         }
-    }
 
-    for index in 0..image_width * image_height * 4 {
-        image.push(normalize_to_rgba_range(
-            neural_network_outputs[index as usize].abs(),
-        ));
+        neural_network.propagate_batch(&row_inputs, &mut row_outputs)?;
+
+        image.extend(
+            row_outputs
+                .iter()
+                .map(|output| normalize_to_rgba_range(output.abs())),
+        );
     }
 
     Ok(image)
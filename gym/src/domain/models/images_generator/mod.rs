@@ -1,4 +1,6 @@
+pub mod compute_neural_network_inputs_for_i_and_j;
 pub mod constants;
+pub mod convert_rgba_pixels_to_tensor;
 pub mod create_new_population;
 pub mod create_population_with_stored_genomes;
 pub mod establish_training_population;
@@ -7,5 +9,4 @@ pub mod generate_png_from_neural_network;
 pub mod generate_time_tag_as_string;
 pub mod normalize_to_rgba_range;
 pub mod process_generation_of_images_from_neural_networks;
-pub mod request_rgb_from_neural_network_for_i_and_j;
 pub mod save_evolved_population;
@@ -1,28 +1,25 @@
 extern crate neural_networks;
-extern crate randomization;
 extern crate rayon;
 extern crate user_interface;
 
 use domain::models::images_generator::generate_png_from_neural_network::generate_png_from_neural_network;
 use domain::models::images_generator::generate_time_tag_as_string::generate_time_tag_as_string;
+use gym_error::GymError;
 
 use self::neural_networks::evolution::domain::genome::GenomeTrait;
 use self::neural_networks::neural_network::NeuralNetworkTrait;
 use self::neural_networks::neuron::NeuronTrait;
-use self::randomization::randomizer::RandomizerTrait;
 use self::user_interface::controllers::display_controller_trait::DisplayControllerTrait;
 
 pub fn process_generation_of_images_from_neural_networks<
     T: GenomeTrait<U, V> + Clone + Sync,
     U: NeuralNetworkTrait<V>,
     V: NeuronTrait,
-    W: RandomizerTrait,
-    X: DisplayControllerTrait,
+    W: DisplayControllerTrait,
 >(
     genomes: &[T],
-    randomizer: &mut W,
-    display_controller: &X,
-) -> Result<(), String> {
+    display_controller: &W,
+) -> Result<(), GymError> {
     display_controller
         .write_information(
             format!("Will render PNGs from {:?} neural networks.", genomes.len()).as_str(),
@@ -54,9 +51,7 @@ pub fn process_generation_of_images_from_neural_networks<
             image_dimension,
             genome.get_neural_network(),
             filename.as_str(),
-            randomizer,
-        )
-        .unwrap();
+        )?;
     }
 
     Ok(())
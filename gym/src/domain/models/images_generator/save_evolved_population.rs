@@ -10,6 +10,7 @@ use self::neural_networks::evolution::domain::population::PopulationTrait;
 use self::neural_networks::neural_network::NeuralNetworkTrait;
 use self::neural_networks::neuron::NeuronTrait;
 use self::serde::Serialize;
+use gym_error::GymError;
 
 pub fn save_evolved_population<
     T: GenomeTrait<U, V> + Clone + Serialize,
@@ -17,29 +18,27 @@ pub fn save_evolved_population<
     V: NeuronTrait + Clone,
 >(
     evolved_population: &Population<T, U, V>,
-) -> Result<(), String> {
+) -> Result<(), GymError> {
     let mut genome_identifiers: Vec<u32> = Vec::new();
 
-    evolved_population
-                .get_genomes()
-                .unwrap()
-                .iter()
-                .for_each(|evolved_genome| {
-                    if genome_identifiers.iter().any(|identifier| identifier == &evolved_genome.get_identifier()){
-                        panic!("There were repeated identifiers for the genomes in the population. That should never happen.");
-                    }else{
-                        genome_identifiers.push(evolved_genome.get_identifier());
-                    }
-                    save_json(
-                        format!(
-                            "data/images_generation/genome_{}.json",
-                            evolved_genome.get_identifier()
-                        )
-                        .as_str(),
-                        evolved_genome,
-                    )
-                    .unwrap()
-                });
+    for evolved_genome in evolved_population.get_genomes()?.iter() {
+        if genome_identifiers.contains(&evolved_genome.get_identifier()) {
+            return Err(GymError::RepeatedGenomeIdentifier {
+                identifier: evolved_genome.get_identifier(),
+            });
+        }
+
+        genome_identifiers.push(evolved_genome.get_identifier());
+
+        save_json(
+            format!(
+                "data/images_generation/genome_{}.json",
+                evolved_genome.get_identifier()
+            )
+            .as_str(),
+            evolved_genome,
+        )?;
+    }
 
     Ok(())
 }
@@ -0,0 +1,91 @@
+extern crate neural_networks;
+extern crate serde;
+
+use self::neural_networks::evolution::domain::evolution_config::EvolutionConfig;
+use self::neural_networks::evolution::domain::genome::GenomeTrait;
+use self::neural_networks::evolution::domain::novelty::novelty_search::NoveltySearch;
+use self::neural_networks::evolution::domain::population::Population;
+use self::neural_networks::neural_network::NeuralNetworkTrait;
+use self::neural_networks::neuron::NeuronTrait;
+use self::serde::{Deserialize, Serialize};
+use domain::training_history::TrainingHistory;
+
+// Everything a GymController needs to continue a training session exactly where it stopped.
+#[derive(Serialize, Deserialize)]
+pub struct TrainingCheckpoint<
+    T: GenomeTrait<U, V> + Clone,
+    U: NeuralNetworkTrait<V> + Clone,
+    V: NeuronTrait + Clone,
+    Z,
+> {
+    population: Population<T, U, V>,
+    generations: u32,
+    winner: Option<T>,
+    randomizer: Z,
+    evolution_config: EvolutionConfig,
+    checkpoint_interval: u32,
+    training_history: TrainingHistory,
+    #[serde(default)]
+    novelty_search: Option<NoveltySearch>,
+}
+
+impl<T: GenomeTrait<U, V> + Clone, U: NeuralNetworkTrait<V> + Clone, V: NeuronTrait + Clone, Z>
+    TrainingCheckpoint<T, U, V, Z>
+{
+    pub fn new(
+        population: Population<T, U, V>,
+        generations: u32,
+        winner: Option<T>,
+        randomizer: Z,
+        evolution_config: EvolutionConfig,
+        checkpoint_interval: u32,
+        training_history: TrainingHistory,
+    ) -> Self {
+        TrainingCheckpoint {
+            population,
+            generations,
+            winner,
+            randomizer,
+            evolution_config,
+            checkpoint_interval,
+            training_history,
+            novelty_search: None,
+        }
+    }
+
+    pub fn get_population(&self) -> &Population<T, U, V> {
+        &self.population
+    }
+
+    pub fn get_generations(&self) -> u32 {
+        self.generations
+    }
+
+    pub fn get_winner(&self) -> &Option<T> {
+        &self.winner
+    }
+
+    pub fn get_randomizer(&self) -> &Z {
+        &self.randomizer
+    }
+
+    pub fn get_evolution_config(&self) -> &EvolutionConfig {
+        &self.evolution_config
+    }
+
+    pub fn get_checkpoint_interval(&self) -> u32 {
+        self.checkpoint_interval
+    }
+
+    pub fn get_training_history(&self) -> &TrainingHistory {
+        &self.training_history
+    }
+
+    pub fn get_novelty_search(&self) -> &Option<NoveltySearch> {
+        &self.novelty_search
+    }
+
+    pub fn set_novelty_search(&mut self, novelty_search: Option<NoveltySearch>) {
+        self.novelty_search = novelty_search;
+    }
+}
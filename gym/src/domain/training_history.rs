@@ -0,0 +1,162 @@
+extern crate file_system;
+extern crate serde;
+extern crate serde_json;
+
+use self::file_system::create_all_directories_on_path::create_all_directories_on_path;
+use self::file_system::file_system_error::FileSystemError;
+use self::serde::{Deserialize, Serialize};
+use domain::generation_statistics::GenerationStatistics;
+use gym_error::GymError;
+use std::fs;
+
+const CSV_HEADER: &str = "generation,best_fitness,worst_fitness,mean_fitness,median_fitness,standard_deviation_of_fitness,diversity,elapsed_seconds";
+
+// The statistics of every generation a GymController has trained, oldest first.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TrainingHistory {
+    generations: Vec<GenerationStatistics>,
+}
+
+impl TrainingHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, generation_statistics: GenerationStatistics) {
+        self.generations.push(generation_statistics);
+    }
+
+    pub fn get_generations(&self) -> &Vec<GenerationStatistics> {
+        &self.generations
+    }
+
+    pub fn get_last(&self) -> Option<&GenerationStatistics> {
+        self.generations.last()
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = format!("{}\n", CSV_HEADER);
+
+        for statistics in self.generations.iter() {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{},{}\n",
+                statistics.get_generation(),
+                statistics.get_best_fitness(),
+                statistics.get_worst_fitness(),
+                statistics.get_mean_fitness(),
+                statistics.get_median_fitness(),
+                statistics.get_standard_deviation_of_fitness(),
+                statistics
+                    .get_diversity()
+                    .map_or(String::new(), |diversity| diversity.to_string()),
+                statistics.get_elapsed_seconds()
+            ));
+        }
+
+        csv
+    }
+
+    // One JSON object per generation and per line.
+    pub fn to_json_lines(&self) -> Result<String, GymError> {
+        let mut json_lines = String::new();
+
+        for statistics in self.generations.iter() {
+            match serde_json::to_string(statistics) {
+                Ok(line) => {
+                    json_lines.push_str(&line);
+                    json_lines.push('\n');
+                }
+                Err(error) => {
+                    return Err(GymError::StatisticsSerialization {
+                        generation: statistics.get_generation(),
+                        message: error.to_string(),
+                    })
+                }
+            }
+        }
+
+        Ok(json_lines)
+    }
+
+    pub fn save_as_csv(&self, file_path: &str) -> Result<(), GymError> {
+        write_to_file(file_path, &self.to_csv())
+    }
+
+    pub fn save_as_json_lines(&self, file_path: &str) -> Result<(), GymError> {
+        write_to_file(file_path, &self.to_json_lines()?)
+    }
+}
+
+fn write_to_file(file_path: &str, contents: &str) -> Result<(), GymError> {
+    create_all_directories_on_path(file_path)?;
+
+    fs::write(file_path, contents).map_err(|error| {
+        FileSystemError::Io {
+            path: file_path.to_string(),
+            message: error.to_string(),
+        }
+        .into()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    extern crate neural_networks;
+    extern crate randomization;
+
+    use self::neural_networks::evolution::domain::create_genome::create_genome;
+    use self::neural_networks::evolution::domain::genome::Genome;
+    use self::neural_networks::evolution::domain::genome::GenomeTrait;
+    use self::neural_networks::neural_network::NeuralNetwork;
+    use self::neural_networks::neuron::Neuron;
+    use self::randomization::randomizer::Randomizer;
+    use std::time::Duration;
+
+    fn setup_history() -> Result<TrainingHistory, GymError> {
+        let mut randomizer = Randomizer::new();
+        let mut history = TrainingHistory::new();
+
+        for generation in 0..2 {
+            let mut genome: Genome<NeuralNetwork<Neuron>, Neuron> =
+                create_genome(1, &[[2, 1]], &mut randomizer)?;
+            genome.set_fitness(generation as f64 + 0.5);
+
+            let mut statistics = GenerationStatistics::from_genomes(generation, &[genome])?;
+            statistics.set_elapsed_time(Duration::from_secs(2));
+
+            history.add(statistics);
+        }
+
+        Ok(history)
+    }
+
+    #[test]
+    fn test_can_export_the_history_as_csv() -> Result<(), GymError> {
+        let csv = setup_history()?.to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], CSV_HEADER);
+        assert_eq!(lines[2], "1,1.5,1.5,1.5,1.5,0,0,2");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_every_json_line_holds_the_statistics_of_a_generation() -> Result<(), GymError> {
+        let history = setup_history()?;
+        let json_lines = history.to_json_lines()?;
+
+        let deserialized: Vec<GenerationStatistics> = json_lines
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(&deserialized, history.get_generations());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+extern crate file_system;
+extern crate neural_networks;
+
+use self::file_system::file_system_error::FileSystemError;
+use self::neural_networks::neural_networks_error::NeuralNetworksError;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GymError {
+    FileAlreadyExists {
+        path: String,
+    },
+    ImageEncoding {
+        path: String,
+        message: String,
+    },
+    RepeatedGenomeIdentifier {
+        identifier: u32,
+    },
+    WinnerIsNotTheFittest,
+    NoWinner,
+    PopulationSizeChanged {
+        expected: u32,
+        received: u32,
+    },
+    FileSystem(FileSystemError),
+    NeuralNetworks(NeuralNetworksError),
+    NoBehaviourDescriptor,
+    NoIslands,
+    EmptyGeneration,
+    StatisticsSerialization {
+        generation: u32,
+        message: String,
+    },
+    NoGenomes,
+    NoObjectives,
+    ObjectivesDontMatchGenomes {
+        number_of_objectives: usize,
+        number_of_genomes: usize,
+    },
+    WrongNumberOfObjectiveValues {
+        values: Vec<f64>,
+        objective_names: Vec<String>,
+    },
+}
+
+impl fmt::Display for GymError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GymError::FileAlreadyExists { path } => write!(
+                f,
+                "Was going to save to {:?}, but there was already a file with that name in that path.",
+                path
+            ),
+            GymError::ImageEncoding { path, message } => write!(
+                f,
+                "Failed to save an image to {:?}. Error: {}",
+                path, message
+            ),
+            GymError::RepeatedGenomeIdentifier { identifier } => write!(
+                f,
+                "There were repeated identifiers ({:?}) for the genomes in the population.",
+                identifier
+            ),
+            GymError::WinnerIsNotTheFittest => write!(
+                f,
+                "Had stored the winner amongst the generation of genomes, but there was at least another genome in the population with a higher fitness!"
+            ),
+            GymError::NoWinner => write!(
+                f,
+                "Asked for the winner before a single generation had been trained."
+            ),
+            GymError::PopulationSizeChanged { expected, received } => write!(
+                f,
+                "The next generation has {:?} genomes, but the population had {:?}.",
+                received, expected
+            ),
+            GymError::NoBehaviourDescriptor => write!(
+                f,
+                "The novelty search needs a behaviour descriptor to score the genomes."
+            ),
+            GymError::NoIslands => write!(f, "Attempted to train without any islands."),
+            GymError::EmptyGeneration => write!(
+                f,
+                "Attempted to compute the statistics of a generation without genomes."
+            ),
+            GymError::StatisticsSerialization {
+                generation,
+                message,
+            } => write!(
+                f,
+                "Failed to serialize the statistics of generation {:?}. Error: {}",
+                generation, message
+            ),
+            GymError::NoGenomes => write!(f, "Attempted to train without any genomes."),
+            GymError::NoObjectives => write!(f, "Attempted to train without any objectives."),
+            GymError::ObjectivesDontMatchGenomes {
+                number_of_objectives,
+                number_of_genomes,
+            } => write!(
+                f,
+                "Evaluated {:?} genomes, but got the objectives of {:?}.",
+                number_of_genomes, number_of_objectives
+            ),
+            GymError::WrongNumberOfObjectiveValues {
+                values,
+                objective_names,
+            } => write!(
+                f,
+                "Got the values {:?}, but the objectives are {:?}.",
+                values, objective_names
+            ),
+            GymError::FileSystem(error) => write!(f, "{}", error),
+            GymError::NeuralNetworks(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for GymError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GymError::FileSystem(error) => Some(error),
+            GymError::NeuralNetworks(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<FileSystemError> for GymError {
+    fn from(error: FileSystemError) -> GymError {
+        GymError::FileSystem(error)
+    }
+}
+
+impl From<NeuralNetworksError> for GymError {
+    fn from(error: NeuralNetworksError) -> GymError {
+        GymError::NeuralNetworks(error)
+    }
+}
+
+// Lets functions that still report errors as strings use '?' on these.
+impl From<GymError> for String {
+    fn from(error: GymError) -> String {
+        error.to_string()
+    }
+}
@@ -1,2 +1,3 @@
 pub mod controllers;
 pub mod domain;
+pub mod gym_error;
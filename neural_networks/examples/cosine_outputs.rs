@@ -1,10 +1,10 @@
 extern crate neural_networks;
 extern crate randomization;
 
+use self::neural_networks::neural_network::LayeredNeuralNetworkTrait;
 use self::neural_networks::neural_network::NeuralNetwork;
 use self::neural_networks::neural_network::NeuralNetworkTrait;
 use self::neural_networks::neuron::Neuron;
-use self::neural_networks::neuron::NeuronTrait;
 use self::neural_networks::neuron_activation::activation_functions::ActivationFunctions;
 use self::randomization::randomizer::Randomizer;
 
@@ -17,7 +17,8 @@ fn main() {
         |number_of_inputs, randomizer| {
             Neuron::new(number_of_inputs, ActivationFunctions::Cosine, randomizer)
         },
-    );
+    )
+    .unwrap();
 
     let outputs = neural_network
         .propagate(&[0.5, 0.2, 0.7, 0.3, 0.6, 0.21, 0.67])
@@ -0,0 +1,15 @@
+extern crate serde;
+
+use self::serde::{Deserialize, Serialize};
+use action_selection::tie_breaks::TieBreaks;
+
+// How an agent turns the outputs of its neural network into the index of the action it takes.
+// Greedy always takes the highest output. Sampled treats the outputs as logits and draws from
+// their softmax, where a higher temperature flattens the probabilities. EpsilonGreedy acts greedily
+// except for a random action with probability epsilon.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub enum ActionSelections {
+    Greedy { tie_break: TieBreaks },
+    Sampled { temperature: f64 },
+    EpsilonGreedy { epsilon: f64, tie_break: TieBreaks },
+}
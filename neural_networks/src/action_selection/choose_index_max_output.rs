@@ -0,0 +1,84 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use action_selection::tie_breaks::TieBreaks;
+use neural_networks_error::NeuralNetworksError;
+
+// Like get_index_max_output, but deciding explicitly between outputs that share the maximum.
+// Outputs that aren't numbers never get chosen.
+pub fn choose_index_max_output<T: RandomizerTrait>(
+    outputs: &[f64],
+    tie_break: &TieBreaks,
+    randomizer: &mut T,
+) -> Result<usize, NeuralNetworksError> {
+    let max = outputs
+        .iter()
+        .filter(|output| !output.is_nan())
+        .cloned()
+        .fold(f64::NEG_INFINITY, f64::max);
+
+    let candidates: Vec<usize> = outputs
+        .iter()
+        .enumerate()
+        .filter(|(_, output)| **output == max)
+        .map(|(index, _)| index)
+        .collect();
+
+    if candidates.is_empty() {
+        return Err(NeuralNetworksError::NoOutputsToSelectFrom);
+    }
+
+    Ok(match tie_break {
+        TieBreaks::First => candidates[0],
+        TieBreaks::Last => candidates[candidates.len() - 1],
+        TieBreaks::Random => randomizer.choose_random_from_vec(&candidates),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    extern crate randomization;
+    use self::randomization::seeded_randomizer::SeededRandomizer;
+
+    #[test]
+    fn test_ties_get_broken_as_asked() -> Result<(), NeuralNetworksError> {
+        let mut randomizer = SeededRandomizer::new(3);
+
+        let outputs = [0.0, 0.0, 0.0];
+
+        assert_eq!(
+            choose_index_max_output(&outputs, &TieBreaks::First, &mut randomizer)?,
+            0
+        );
+        assert_eq!(
+            choose_index_max_output(&outputs, &TieBreaks::Last, &mut randomizer)?,
+            2
+        );
+
+        let mut chosen = [false; 3];
+
+        for _ in 0..100 {
+            chosen[choose_index_max_output(&outputs, &TieBreaks::Random, &mut randomizer)?] = true;
+        }
+
+        assert_eq!(chosen, [true, true, true]);
+
+        assert_eq!(
+            choose_index_max_output(
+                &[0.4, f64::NAN, 0.9, 0.9],
+                &TieBreaks::First,
+                &mut randomizer
+            )?,
+            2
+        );
+        assert_eq!(
+            choose_index_max_output(&[], &TieBreaks::First, &mut randomizer),
+            Err(NeuralNetworksError::NoOutputsToSelectFrom)
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+pub mod action_selections;
+pub mod choose_index_max_output;
+pub mod sample_index_from_outputs;
+pub mod select_action;
+pub mod tie_breaks;
@@ -0,0 +1,67 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use neural_networks_error::NeuralNetworksError;
+use output_transformation::softmax::softmax;
+
+pub fn sample_index_from_outputs<T: RandomizerTrait>(
+    outputs: &[f64],
+    temperature: f64,
+    randomizer: &mut T,
+) -> Result<usize, NeuralNetworksError> {
+    if outputs.is_empty() || outputs.iter().any(|output| output.is_nan()) {
+        return Err(NeuralNetworksError::NoOutputsToSelectFrom);
+    }
+    if temperature <= 0.0 {
+        return Err(NeuralNetworksError::NonPositiveTemperature(temperature));
+    }
+
+    let mut probabilities: Vec<f64> = outputs.iter().map(|output| output / temperature).collect();
+    softmax(&mut probabilities);
+
+    let mut remaining = randomizer.generate_float_from_0_to_1();
+
+    for (index, probability) in probabilities.iter().enumerate() {
+        if remaining < *probability {
+            return Ok(index);
+        }
+
+        remaining -= probability;
+    }
+
+    // Rounding can leave a sliver after the last probability.
+    Ok(probabilities.len() - 1)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    extern crate randomization;
+    use self::randomization::seeded_randomizer::SeededRandomizer;
+
+    #[test]
+    fn test_the_temperature_controls_how_often_the_highest_output_gets_sampled(
+    ) -> Result<(), NeuralNetworksError> {
+        let mut randomizer = SeededRandomizer::new(12);
+
+        let outputs = [1.0, 2.0, 0.5];
+
+        let count_highest = |temperature, randomizer: &mut SeededRandomizer| {
+            (0..1000)
+                .filter(|_| sample_index_from_outputs(&outputs, temperature, randomizer) == Ok(1))
+                .count()
+        };
+
+        let cold = count_highest(0.05, &mut randomizer);
+        let warm = count_highest(1.0, &mut randomizer);
+
+        assert!(cold > 990);
+        assert!(warm > 450 && warm < 750);
+
+        assert!(sample_index_from_outputs(&outputs, 0.0, &mut randomizer).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use action_selection::action_selections::ActionSelections;
+use action_selection::choose_index_max_output::choose_index_max_output;
+use action_selection::sample_index_from_outputs::sample_index_from_outputs;
+use neural_networks_error::NeuralNetworksError;
+
+pub fn select_action<T: RandomizerTrait>(
+    outputs: &[f64],
+    action_selection: &ActionSelections,
+    randomizer: &mut T,
+) -> Result<usize, NeuralNetworksError> {
+    match action_selection {
+        ActionSelections::Greedy { tie_break } => {
+            choose_index_max_output(outputs, tie_break, randomizer)
+        }
+        ActionSelections::Sampled { temperature } => {
+            sample_index_from_outputs(outputs, *temperature, randomizer)
+        }
+        ActionSelections::EpsilonGreedy { epsilon, tie_break } => {
+            if !outputs.is_empty() && randomizer.generate_float_from_0_to_1() < *epsilon {
+                Ok(randomizer.choose_random_from_vec(&(0..outputs.len()).collect::<Vec<usize>>()))
+            } else {
+                choose_index_max_output(outputs, tie_break, randomizer)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use action_selection::tie_breaks::TieBreaks;
+
+    extern crate randomization;
+    use self::randomization::seeded_randomizer::SeededRandomizer;
+
+    #[test]
+    fn test_epsilon_greedy_explores_about_as_often_as_asked() -> Result<(), NeuralNetworksError> {
+        let mut randomizer = SeededRandomizer::new(4);
+
+        let outputs = [0.1, 0.9, 0.3, 0.2];
+
+        let action_selection = ActionSelections::EpsilonGreedy {
+            epsilon: 0.2,
+            tie_break: TieBreaks::First,
+        };
+
+        let mut greedy = 0;
+
+        for _ in 0..1000 {
+            if select_action(&outputs, &action_selection, &mut randomizer)? == 1 {
+                greedy += 1;
+            }
+        }
+
+        // The greedy action also comes up a quarter of the times it explores.
+        assert!(greedy > 810 && greedy < 890);
+
+        assert_eq!(
+            select_action(
+                &outputs,
+                &ActionSelections::Greedy {
+                    tie_break: TieBreaks::Random
+                },
+                &mut randomizer
+            )?,
+            1
+        );
+
+        Ok(())
+    }
+}
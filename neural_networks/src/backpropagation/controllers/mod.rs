@@ -0,0 +1 @@
+pub mod train_with_backpropagation;
@@ -0,0 +1,196 @@
+use backpropagation::domain::compute_gradients::compute_gradients;
+use backpropagation::domain::layer_gradients::LayerGradients;
+use backpropagation::domain::loss_trait::LossTrait;
+use backpropagation::domain::optimizer_trait::OptimizerTrait;
+use neural_network::LayeredNeuralNetworkTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::NeuronTrait;
+
+pub fn train_with_backpropagation<
+    T: LayeredNeuralNetworkTrait<U>,
+    U: NeuronTrait,
+    V: LossTrait,
+    W: OptimizerTrait,
+>(
+    neural_network: &mut T,
+    samples: &[(Vec<f64>, Vec<f64>)],
+    loss: &V,
+    optimizer: &mut W,
+    epochs: u32,
+) -> Result<f64, NeuralNetworksError> {
+    if samples.is_empty() {
+        return Err(NeuralNetworksError::NoSamples);
+    }
+
+    let mut average_loss = 0f64;
+
+    // Every epoch averages the gradients of all the samples before asking the optimizer for a single step.
+    for _ in 0..epochs {
+        let mut accumulated_gradients = LayerGradients::new_for_layers(neural_network.get_layers());
+        let mut accumulated_loss = 0f64;
+
+        for (inputs, targets) in samples {
+            let (gradients, loss_value) = compute_gradients(neural_network, inputs, targets, loss)?;
+
+            for (accumulated, layer_gradients) in
+                accumulated_gradients.iter_mut().zip(gradients.iter())
+            {
+                accumulated.accumulate(layer_gradients)?;
+            }
+
+            accumulated_loss += loss_value;
+        }
+
+        for accumulated in accumulated_gradients.iter_mut() {
+            accumulated.scale(1.0 / samples.len() as f64);
+        }
+
+        average_loss = accumulated_loss / samples.len() as f64;
+
+        optimizer.update(neural_network, &accumulated_gradients)?;
+    }
+
+    Ok(average_loss)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use backpropagation::domain::adam::Adam;
+    use backpropagation::domain::cross_entropy::CrossEntropy;
+    use backpropagation::domain::mean_squared_error::MeanSquaredError;
+    use backpropagation::domain::momentum::Momentum;
+    use backpropagation::domain::stochastic_gradient_descent::StochasticGradientDescent;
+    use neural_network::NeuralNetwork;
+    use neural_network::NeuralNetworkTrait;
+    use neural_networks_error::NeuralNetworksError;
+    use neuron::Neuron;
+    use neuron_activation::activation_functions::ActivationFunctions;
+
+    extern crate randomization;
+    use self::randomization::randomizer::Randomizer;
+
+    fn setup_samples() -> Vec<(Vec<f64>, Vec<f64>)> {
+        // Same shape as the settling-in domain: [0] CavesPresent [1] BuildingsPresent [2] IsHomeless
+        vec![
+            (vec![1.0, 1.0, 1.0], vec![0.0, 1.0, 0.0]),
+            (vec![0.0, 1.0, 1.0], vec![0.0, 1.0, 0.0]),
+            (vec![1.0, 0.0, 1.0], vec![1.0, 0.0, 0.0]),
+            (vec![0.0, 0.0, 1.0], vec![0.0, 0.0, 1.0]),
+        ]
+    }
+
+    fn setup_neural_network() -> Result<NeuralNetwork<Neuron>, NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+
+        NeuralNetwork::new_with_specified_layers(
+            &[[3, 4], [4, 3]],
+            &mut randomizer,
+            |number_of_inputs, randomizer| {
+                Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+            },
+        )
+    }
+
+    #[test]
+    fn test_training_with_each_optimizer_reduces_the_loss() -> Result<(), NeuralNetworksError> {
+        let samples = setup_samples();
+        let loss = MeanSquaredError::new();
+
+        let mut neural_network = setup_neural_network()?;
+        let initial_loss = train_with_backpropagation(
+            &mut neural_network,
+            &samples,
+            &loss,
+            &mut StochasticGradientDescent::new(0.0),
+            1,
+        )?;
+        let final_loss = train_with_backpropagation(
+            &mut neural_network,
+            &samples,
+            &loss,
+            &mut StochasticGradientDescent::new(2.0),
+            500,
+        )?;
+        assert!(final_loss < initial_loss);
+
+        let mut neural_network = setup_neural_network()?;
+        let initial_loss = train_with_backpropagation(
+            &mut neural_network,
+            &samples,
+            &loss,
+            &mut StochasticGradientDescent::new(0.0),
+            1,
+        )?;
+        let final_loss = train_with_backpropagation(
+            &mut neural_network,
+            &samples,
+            &loss,
+            &mut Momentum::new(0.5, 0.9),
+            500,
+        )?;
+        assert!(final_loss < initial_loss);
+
+        let mut neural_network = setup_neural_network()?;
+        let initial_loss = train_with_backpropagation(
+            &mut neural_network,
+            &samples,
+            &loss,
+            &mut StochasticGradientDescent::new(0.0),
+            1,
+        )?;
+        let final_loss = train_with_backpropagation(
+            &mut neural_network,
+            &samples,
+            &loss,
+            &mut Adam::new(0.05),
+            500,
+        )?;
+        assert!(final_loss < initial_loss);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_can_fit_a_labelled_table_with_cross_entropy() -> Result<(), NeuralNetworksError> {
+        let samples = setup_samples();
+
+        let mut neural_network = setup_neural_network()?;
+
+        train_with_backpropagation(
+            &mut neural_network,
+            &samples,
+            &CrossEntropy::new(),
+            &mut Adam::new(0.05),
+            2000,
+        )?;
+
+        use get_index_max_output::get_index_max_output;
+
+        for (inputs, targets) in &samples {
+            assert_eq!(
+                get_index_max_output(&neural_network.propagate(inputs)?)?,
+                get_index_max_output(targets)?
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_training_without_samples_fails() -> Result<(), NeuralNetworksError> {
+        let mut neural_network = setup_neural_network()?;
+
+        assert!(train_with_backpropagation(
+            &mut neural_network,
+            &[],
+            &MeanSquaredError::new(),
+            &mut StochasticGradientDescent::new(0.1),
+            1
+        )
+        .is_err());
+
+        Ok(())
+    }
+}
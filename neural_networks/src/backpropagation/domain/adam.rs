@@ -0,0 +1,84 @@
+use backpropagation::domain::apply_deltas_to_neural_network::apply_deltas_to_neural_network;
+use backpropagation::domain::layer_gradients::LayerGradients;
+use backpropagation::domain::optimizer_trait::OptimizerTrait;
+use neural_network::LayeredNeuralNetworkTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::NeuronTrait;
+
+#[derive(Debug, Clone)]
+pub struct Adam {
+    learning_rate: f64,
+    first_decay: f64,
+    second_decay: f64,
+    epsilon: f64,
+    steps: i32,
+    first_moments: Vec<LayerGradients>,
+    second_moments: Vec<LayerGradients>,
+}
+
+impl Adam {
+    pub fn new(learning_rate: f64) -> Self {
+        Adam::new_with_decays(learning_rate, 0.9, 0.999, 1e-8)
+    }
+
+    pub fn new_with_decays(
+        learning_rate: f64,
+        first_decay: f64,
+        second_decay: f64,
+        epsilon: f64,
+    ) -> Self {
+        Adam {
+            learning_rate,
+            first_decay,
+            second_decay,
+            epsilon,
+            steps: 0,
+            first_moments: Vec::new(),
+            second_moments: Vec::new(),
+        }
+    }
+}
+
+impl OptimizerTrait for Adam {
+    fn update<T: LayeredNeuralNetworkTrait<U>, U: NeuronTrait>(
+        &mut self,
+        neural_network: &mut T,
+        gradients: &[LayerGradients],
+    ) -> Result<(), NeuralNetworksError> {
+        if self.first_moments.is_empty() {
+            self.first_moments = LayerGradients::new_for_layers(neural_network.get_layers());
+            self.second_moments = self.first_moments.clone();
+        }
+
+        self.steps += 1;
+
+        let first_correction = 1.0 - self.first_decay.powi(self.steps);
+        let second_correction = 1.0 - self.second_decay.powi(self.steps);
+
+        let mut deltas = self.first_moments.clone();
+
+        for (((first_moment, second_moment), layer_gradients), layer_deltas) in self
+            .first_moments
+            .iter_mut()
+            .zip(self.second_moments.iter_mut())
+            .zip(gradients.iter())
+            .zip(deltas.iter_mut())
+        {
+            for (((first, second), gradient), delta) in first_moment
+                .get_values_mut()
+                .zip(second_moment.get_values_mut())
+                .zip(layer_gradients.get_values())
+                .zip(layer_deltas.get_values_mut())
+            {
+                *first = self.first_decay * *first + (1.0 - self.first_decay) * gradient;
+                *second =
+                    self.second_decay * *second + (1.0 - self.second_decay) * gradient.powi(2);
+
+                *delta = -self.learning_rate * (*first / first_correction)
+                    / ((*second / second_correction).sqrt() + self.epsilon);
+            }
+        }
+
+        apply_deltas_to_neural_network(neural_network, &deltas)
+    }
+}
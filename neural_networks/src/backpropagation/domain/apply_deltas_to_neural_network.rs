@@ -0,0 +1,36 @@
+use backpropagation::domain::layer_gradients::LayerGradients;
+use layer::LayerTrait;
+use neural_network::LayeredNeuralNetworkTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::MutableNeuronTrait;
+use neuron::NeuronTrait;
+
+pub fn apply_deltas_to_neural_network<T: LayeredNeuralNetworkTrait<U>, U: NeuronTrait>(
+    neural_network: &mut T,
+    deltas: &[LayerGradients],
+) -> Result<(), NeuralNetworksError> {
+    if neural_network.get_number_of_layers() as usize != deltas.len() {
+        return Err(NeuralNetworksError::WrongNumberOfDeltas {
+            expected: neural_network.get_number_of_layers() as usize,
+            received: deltas.len(),
+        });
+    }
+
+    for (layer, layer_deltas) in neural_network
+        .get_layers_mut()
+        .iter_mut()
+        .zip(deltas.iter())
+    {
+        for (index, neuron) in layer.get_neurons_mut().iter_mut().enumerate() {
+            neuron.set_bias(neuron.get_bias() + layer_deltas.get_biases()[index]);
+
+            for (weight_index, delta) in layer_deltas.get_weights()[index].iter().enumerate() {
+                let weight = neuron.get_weight(weight_index)?;
+
+                neuron.set_weight(weight_index, weight + delta)?;
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,220 @@
+use backpropagation::domain::layer_gradients::LayerGradients;
+use backpropagation::domain::loss_trait::LossTrait;
+use layer::LayerTrait;
+use neural_network::LayeredNeuralNetworkTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::NeuronTrait;
+use neuron_activation::activate_neuron::activate_neuron;
+use neuron_activation::derive_activation::derive_activation;
+use output_transformation::derive_output_transform::derive_output_transform;
+use output_transformation::transform_outputs::transform_outputs;
+
+type ResultComputeGradients = Result<(Vec<LayerGradients>, f64), NeuralNetworksError>;
+
+pub fn compute_gradients<T: LayeredNeuralNetworkTrait<U>, U: NeuronTrait, V: LossTrait>(
+    neural_network: &T,
+    inputs: &[f64],
+    targets: &[f64],
+    loss: &V,
+) -> ResultComputeGradients {
+    if neural_network.get_number_of_layers() == 0 {
+        return Err(NeuralNetworksError::NoLayers);
+    }
+    if neural_network.get_layer(0).get_number_of_inputs() as usize != inputs.len() {
+        return Err(NeuralNetworksError::WrongNumberOfInputs {
+            expected: neural_network.get_layer(0).get_number_of_inputs() as usize,
+            received: inputs.len(),
+        });
+    }
+
+    if neural_network
+        .get_layers()
+        .iter()
+        .any(|layer| !layer.get_recurrence().is_none())
+    {
+        return Err(NeuralNetworksError::RecurrentLayersInBackpropagation);
+    }
+
+    // Forward pass, keeping the weighted sums and the activations of every layer around, as well
+    // as the activations before the output transform of their layer.
+    let mut weighted_sums: Vec<Vec<f64>> = Vec::new();
+    let mut activations: Vec<Vec<f64>> = vec![inputs.to_vec()];
+    let mut untransformed_activations: Vec<Vec<f64>> = Vec::new();
+
+    for layer in neural_network.get_layers() {
+        let mut layer_sums = Vec::new();
+        let mut layer_activations = Vec::new();
+
+        for index in 0..layer.get_number_of_neurons() as usize {
+            let neuron = layer.get_neuron(index)?;
+            let weighted_sum = neuron.get_weighted_sum(activations.last().unwrap())?;
+
+            layer_sums.push(weighted_sum);
+            layer_activations.push(activate_neuron(
+                weighted_sum,
+                neuron.get_activation_function(),
+            ));
+        }
+
+        untransformed_activations.push(layer_activations.clone());
+        transform_outputs(&mut layer_activations, layer.get_output_transform());
+
+        weighted_sums.push(layer_sums);
+        activations.push(layer_activations);
+    }
+
+    let outputs = activations.last().unwrap();
+
+    let loss_value = loss.compute(outputs, targets)?;
+
+    // Backward pass. The errors start as the derivative of the loss with respect to the outputs,
+    // and get pushed back through each layer.
+    let mut errors = loss.derive(outputs, targets)?;
+
+    let mut gradients = LayerGradients::new_for_layers(neural_network.get_layers());
+
+    for layer_index in (0..neural_network.get_number_of_layers() as usize).rev() {
+        let layer = neural_network.get_layer(layer_index);
+
+        derive_output_transform(
+            &untransformed_activations[layer_index],
+            &mut errors,
+            layer.get_output_transform(),
+        );
+
+        for (index, error) in errors.iter_mut().enumerate() {
+            *error *= derive_activation(
+                weighted_sums[layer_index][index],
+                layer.get_neuron(index)?.get_activation_function(),
+            );
+        }
+
+        let layer_gradients = &mut gradients[layer_index];
+
+        for (index, error) in errors.iter().enumerate() {
+            layer_gradients.get_biases_mut()[index] = *error;
+
+            for (input_index, input) in activations[layer_index].iter().enumerate() {
+                layer_gradients.get_weights_mut()[index][input_index] = error * input;
+            }
+        }
+
+        let mut previous_errors = vec![0f64; layer.get_number_of_inputs() as usize];
+
+        for (index, error) in errors.iter().enumerate() {
+            let neuron = layer.get_neuron(index)?;
+
+            for (input_index, previous_error) in previous_errors.iter_mut().enumerate() {
+                *previous_error += neuron.get_weight(input_index)? * error;
+            }
+        }
+
+        errors = previous_errors;
+    }
+
+    Ok((gradients, loss_value))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use backpropagation::domain::mean_squared_error::MeanSquaredError;
+    use neural_network::NeuralNetwork;
+    use neural_network::NeuralNetworkTrait;
+    use neuron::MutableNeuronTrait;
+    use neuron::Neuron;
+    use neuron_activation::activation_functions::ActivationFunctions;
+    use output_transformation::output_transforms::OutputTransforms;
+
+    extern crate randomization;
+    use self::randomization::randomizer::Randomizer;
+
+    #[test]
+    fn test_gradients_match_numerical_approximations() -> Result<(), NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+
+        let mut neural_network = NeuralNetwork::<Neuron>::new_with_specified_layers(
+            &[[3, 4], [4, 2]],
+            &mut randomizer,
+            |number_of_inputs, randomizer| {
+                Neuron::new(number_of_inputs, ActivationFunctions::Tanh, randomizer)
+            },
+        )?;
+
+        for output_transform in &[
+            OutputTransforms::None,
+            OutputTransforms::Softmax,
+            OutputTransforms::LogSoftmax,
+        ] {
+            let inputs = [0.5, -0.2, 0.9];
+            let targets = [0.3, -0.6];
+            let loss = MeanSquaredError::new();
+
+            neural_network.get_layers_mut()[1].set_output_transform(*output_transform);
+
+            let (gradients, _) = compute_gradients(&neural_network, &inputs, &targets, &loss)?;
+
+            let step = 1e-6;
+
+            for (layer_index, layer_gradients) in gradients.iter().enumerate() {
+                for index in 0..layer_gradients.get_biases().len() {
+                    let weight = neural_network
+                        .get_layer(layer_index)
+                        .get_neuron(index)?
+                        .get_weight(0)?;
+
+                    neural_network.get_layers_mut()[layer_index]
+                        .get_neuron_mut(index)?
+                        .set_weight(0, weight + step)?;
+                    let higher = loss.compute(&neural_network.propagate(&inputs)?, &targets)?;
+
+                    neural_network.get_layers_mut()[layer_index]
+                        .get_neuron_mut(index)?
+                        .set_weight(0, weight - step)?;
+                    let lower = loss.compute(&neural_network.propagate(&inputs)?, &targets)?;
+
+                    neural_network.get_layers_mut()[layer_index]
+                        .get_neuron_mut(index)?
+                        .set_weight(0, weight)?;
+
+                    let approximation = (higher - lower) / (2.0 * step);
+                    let gradient = layer_gradients.get_weights()[index][0];
+
+                    assert!(
+                        (approximation - gradient).abs() < 1e-5,
+                        "Gradient was {:?}, but expected around {:?}",
+                        gradient,
+                        approximation
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_computing_gradients_fails_with_wrong_number_of_inputs(
+    ) -> Result<(), NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+
+        let neural_network = NeuralNetwork::<Neuron>::new_with_specified_layers(
+            &[[3, 2]],
+            &mut randomizer,
+            |number_of_inputs, randomizer| {
+                Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+            },
+        )?;
+
+        assert!(compute_gradients(
+            &neural_network,
+            &[1.0],
+            &[1.0, 0.0],
+            &MeanSquaredError::new()
+        )
+        .is_err());
+
+        Ok(())
+    }
+}
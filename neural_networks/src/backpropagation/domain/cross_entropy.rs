@@ -0,0 +1,76 @@
+use backpropagation::domain::loss_trait::LossTrait;
+use neural_networks_error::NeuralNetworksError;
+
+// Outputs get clamped to this distance from 0 and 1 so that the logarithms stay finite.
+const EPSILON: f64 = 1e-12;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CrossEntropy {}
+
+impl CrossEntropy {
+    pub fn new() -> Self {
+        CrossEntropy {}
+    }
+}
+
+impl LossTrait for CrossEntropy {
+    fn compute(&self, outputs: &[f64], targets: &[f64]) -> Result<f64, NeuralNetworksError> {
+        if outputs.len() != targets.len() {
+            return Err(NeuralNetworksError::WrongNumberOfTargets {
+                outputs: outputs.len(),
+                targets: targets.len(),
+            });
+        }
+
+        Ok(-outputs
+            .iter()
+            .zip(targets.iter())
+            .map(|(output, target)| {
+                let output = output.clamp(EPSILON, 1.0 - EPSILON);
+
+                target * output.ln() + (1.0 - target) * (1.0 - output).ln()
+            })
+            .sum::<f64>()
+            / outputs.len() as f64)
+    }
+
+    fn derive(&self, outputs: &[f64], targets: &[f64]) -> Result<Vec<f64>, NeuralNetworksError> {
+        if outputs.len() != targets.len() {
+            return Err(NeuralNetworksError::WrongNumberOfTargets {
+                outputs: outputs.len(),
+                targets: targets.len(),
+            });
+        }
+
+        Ok(outputs
+            .iter()
+            .zip(targets.iter())
+            .map(|(output, target)| {
+                let output = output.clamp(EPSILON, 1.0 - EPSILON);
+
+                (output - target) / (output * (1.0 - output)) / outputs.len() as f64
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_cross_entropy_is_lower_for_closer_outputs() -> Result<(), NeuralNetworksError> {
+        let loss = CrossEntropy::new();
+
+        assert!(loss.compute(&[0.9, 0.1], &[1.0, 0.0])? < loss.compute(&[0.6, 0.4], &[1.0, 0.0])?);
+        assert!(loss.compute(&[0.0, 1.0], &[1.0, 0.0])?.is_finite());
+
+        let derivatives = loss.derive(&[0.9, 0.1], &[1.0, 0.0])?;
+
+        assert!(derivatives[0] < 0.0);
+        assert!(derivatives[1] > 0.0);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use layer::Layer;
+use layer::LayerTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::NeuronTrait;
+
+#[derive(Debug, Clone)]
+pub struct LayerGradients {
+    weights: Vec<Vec<f64>>,
+    biases: Vec<f64>,
+}
+
+impl LayerGradients {
+    pub fn new(number_of_inputs: u32, number_of_neurons: u32) -> Self {
+        LayerGradients {
+            weights: vec![vec![0f64; number_of_inputs as usize]; number_of_neurons as usize],
+            biases: vec![0f64; number_of_neurons as usize],
+        }
+    }
+
+    pub fn new_for_layers<T: NeuronTrait>(layers: &[Layer<T>]) -> Vec<Self> {
+        layers
+            .iter()
+            .map(|layer| {
+                LayerGradients::new(layer.get_number_of_inputs(), layer.get_number_of_neurons())
+            })
+            .collect()
+    }
+
+    pub fn get_weights(&self) -> &Vec<Vec<f64>> {
+        &self.weights
+    }
+
+    pub fn get_weights_mut(&mut self) -> &mut Vec<Vec<f64>> {
+        &mut self.weights
+    }
+
+    pub fn get_biases(&self) -> &Vec<f64> {
+        &self.biases
+    }
+
+    pub fn get_biases_mut(&mut self) -> &mut Vec<f64> {
+        &mut self.biases
+    }
+
+    pub fn get_values(&self) -> impl Iterator<Item = &f64> {
+        self.weights
+            .iter()
+            .flat_map(|weights| weights.iter())
+            .chain(self.biases.iter())
+    }
+
+    pub fn get_values_mut(&mut self) -> impl Iterator<Item = &mut f64> {
+        self.weights
+            .iter_mut()
+            .flat_map(|weights| weights.iter_mut())
+            .chain(self.biases.iter_mut())
+    }
+
+    pub fn accumulate(&mut self, other: &LayerGradients) -> Result<(), NeuralNetworksError> {
+        if self.biases.len() != other.biases.len() {
+            return Err(NeuralNetworksError::GradientsOfDifferentSizes {
+                expected: self.biases.len(),
+                received: other.biases.len(),
+            });
+        }
+
+        for (value, other_value) in self.get_values_mut().zip(other.get_values()) {
+            *value += other_value;
+        }
+
+        Ok(())
+    }
+
+    pub fn scale(&mut self, factor: f64) {
+        self.get_values_mut().for_each(|value| *value *= factor);
+    }
+}
@@ -0,0 +1,5 @@
+use neural_networks_error::NeuralNetworksError;
+pub trait LossTrait {
+    fn compute(&self, outputs: &[f64], targets: &[f64]) -> Result<f64, NeuralNetworksError>;
+    fn derive(&self, outputs: &[f64], targets: &[f64]) -> Result<Vec<f64>, NeuralNetworksError>;
+}
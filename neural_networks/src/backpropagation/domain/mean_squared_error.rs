@@ -0,0 +1,68 @@
+use backpropagation::domain::loss_trait::LossTrait;
+use neural_networks_error::NeuralNetworksError;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MeanSquaredError {}
+
+impl MeanSquaredError {
+    pub fn new() -> Self {
+        MeanSquaredError {}
+    }
+}
+
+impl LossTrait for MeanSquaredError {
+    fn compute(&self, outputs: &[f64], targets: &[f64]) -> Result<f64, NeuralNetworksError> {
+        if outputs.len() != targets.len() {
+            return Err(NeuralNetworksError::WrongNumberOfTargets {
+                outputs: outputs.len(),
+                targets: targets.len(),
+            });
+        }
+
+        Ok(outputs
+            .iter()
+            .zip(targets.iter())
+            .map(|(output, target)| (output - target).powi(2))
+            .sum::<f64>()
+            / outputs.len() as f64)
+    }
+
+    fn derive(&self, outputs: &[f64], targets: &[f64]) -> Result<Vec<f64>, NeuralNetworksError> {
+        if outputs.len() != targets.len() {
+            return Err(NeuralNetworksError::WrongNumberOfTargets {
+                outputs: outputs.len(),
+                targets: targets.len(),
+            });
+        }
+
+        Ok(outputs
+            .iter()
+            .zip(targets.iter())
+            .map(|(output, target)| 2.0 * (output - target) / outputs.len() as f64)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_mean_squared_error_has_expected_value_and_derivative() -> Result<(), NeuralNetworksError>
+    {
+        let loss = MeanSquaredError::new();
+
+        assert_eq!(loss.compute(&[1.0, 0.0], &[0.0, 0.0])?, 0.5);
+        assert_eq!(loss.derive(&[1.0, 0.0], &[0.0, 0.0])?, vec![1.0, 0.0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mean_squared_error_fails_if_lengths_dont_match() {
+        assert!(MeanSquaredError::new()
+            .compute(&[1.0], &[1.0, 0.0])
+            .is_err());
+    }
+}
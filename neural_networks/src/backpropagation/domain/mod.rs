@@ -0,0 +1,10 @@
+pub mod adam;
+pub mod apply_deltas_to_neural_network;
+pub mod compute_gradients;
+pub mod cross_entropy;
+pub mod layer_gradients;
+pub mod loss_trait;
+pub mod mean_squared_error;
+pub mod momentum;
+pub mod optimizer_trait;
+pub mod stochastic_gradient_descent;
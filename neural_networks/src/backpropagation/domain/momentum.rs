@@ -0,0 +1,44 @@
+use backpropagation::domain::apply_deltas_to_neural_network::apply_deltas_to_neural_network;
+use backpropagation::domain::layer_gradients::LayerGradients;
+use backpropagation::domain::optimizer_trait::OptimizerTrait;
+use neural_network::LayeredNeuralNetworkTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::NeuronTrait;
+
+#[derive(Debug, Clone)]
+pub struct Momentum {
+    learning_rate: f64,
+    momentum: f64,
+    velocities: Vec<LayerGradients>,
+}
+
+impl Momentum {
+    pub fn new(learning_rate: f64, momentum: f64) -> Self {
+        Momentum {
+            learning_rate,
+            momentum,
+            velocities: Vec::new(),
+        }
+    }
+}
+
+impl OptimizerTrait for Momentum {
+    fn update<T: LayeredNeuralNetworkTrait<U>, U: NeuronTrait>(
+        &mut self,
+        neural_network: &mut T,
+        gradients: &[LayerGradients],
+    ) -> Result<(), NeuralNetworksError> {
+        // The velocities get shaped after the neural network the first time it gets updated.
+        if self.velocities.is_empty() {
+            self.velocities = LayerGradients::new_for_layers(neural_network.get_layers());
+        }
+
+        for (velocity, layer_gradients) in self.velocities.iter_mut().zip(gradients.iter()) {
+            for (value, gradient) in velocity.get_values_mut().zip(layer_gradients.get_values()) {
+                *value = self.momentum * *value - self.learning_rate * gradient;
+            }
+        }
+
+        apply_deltas_to_neural_network(neural_network, &self.velocities)
+    }
+}
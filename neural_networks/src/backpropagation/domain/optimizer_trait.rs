@@ -0,0 +1,12 @@
+use backpropagation::domain::layer_gradients::LayerGradients;
+use neural_network::LayeredNeuralNetworkTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::NeuronTrait;
+
+pub trait OptimizerTrait {
+    fn update<T: LayeredNeuralNetworkTrait<U>, U: NeuronTrait>(
+        &mut self,
+        neural_network: &mut T,
+        gradients: &[LayerGradients],
+    ) -> Result<(), NeuralNetworksError>;
+}
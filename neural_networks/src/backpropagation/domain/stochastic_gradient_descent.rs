@@ -0,0 +1,40 @@
+use backpropagation::domain::apply_deltas_to_neural_network::apply_deltas_to_neural_network;
+use backpropagation::domain::layer_gradients::LayerGradients;
+use backpropagation::domain::optimizer_trait::OptimizerTrait;
+use neural_network::LayeredNeuralNetworkTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::NeuronTrait;
+
+#[derive(Debug, Clone)]
+pub struct StochasticGradientDescent {
+    learning_rate: f64,
+}
+
+impl StochasticGradientDescent {
+    pub fn new(learning_rate: f64) -> Self {
+        StochasticGradientDescent { learning_rate }
+    }
+
+    pub fn get_learning_rate(&self) -> f64 {
+        self.learning_rate
+    }
+}
+
+impl OptimizerTrait for StochasticGradientDescent {
+    fn update<T: LayeredNeuralNetworkTrait<U>, U: NeuronTrait>(
+        &mut self,
+        neural_network: &mut T,
+        gradients: &[LayerGradients],
+    ) -> Result<(), NeuralNetworksError> {
+        let deltas: Vec<LayerGradients> = gradients
+            .iter()
+            .map(|layer_gradients| {
+                let mut layer_deltas = layer_gradients.clone();
+                layer_deltas.scale(-self.learning_rate);
+                layer_deltas
+            })
+            .collect();
+
+        apply_deltas_to_neural_network(neural_network, &deltas)
+    }
+}
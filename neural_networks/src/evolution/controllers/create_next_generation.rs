@@ -1,69 +1,89 @@
 extern crate randomization;
 use self::randomization::randomizer::RandomizerTrait;
+use evolution::domain::evolution_config::EvolutionConfig;
 use evolution::domain::genome::GenomeTrait;
 use evolution::domain::genome_couple::GenomeCouple;
-use evolution::domain::is_population_ordered_by_fitness::is_population_ordered_by_fitness;
 use evolution::domain::mechanics::crossover_genomes::crossover_genomes;
 use evolution::domain::mechanics::mutate_genome::mutate_genome;
 use evolution::domain::population::Population;
 use evolution::domain::population::PopulationTrait;
-use neural_network::NeuralNetworkTrait;
+use evolution::domain::selection::selection_strategy_trait::SelectionStrategyTrait;
+use evolution::domain::selection::sort_indexes_by_fitness::sort_indexes_by_fitness;
+use neural_network::LayeredNeuralNetworkTrait;
+use neural_networks_error::NeuralNetworksError;
 use neuron::NeuronTrait;
 
 pub fn create_next_generation<
     T: GenomeTrait<V, W> + Clone,
-    V: NeuralNetworkTrait<W> + Clone,
+    V: LayeredNeuralNetworkTrait<W> + Clone,
     W: NeuronTrait + Clone,
     X: RandomizerTrait,
     Y: Fn(u32, V) -> T,
     Z: Fn() -> V,
     A: Fn(u32, &mut X) -> W,
+    B: SelectionStrategyTrait,
 >(
     population: &Population<T, V, W>,
+    selection_strategy: &B,
+    evolution_config: &EvolutionConfig,
     genome_creator: Y,
     neural_network_creator: Z,
     neuron_creator: A,
     randomizer: &mut X,
-) -> Result<Population<T, V, W>, String> {
-    // Note: for the crossing over to make sense, it should be sorted in descending order of fitness. Otherwise
-    // you are just crossing over the genomes it comes across first, and discards the rest.
-    if !is_population_ordered_by_fitness(&population)? {
-        panic!("Was going to create the next generation, but the population wasn't ordered by fitness.!");
+) -> Result<Population<T, V, W>, NeuralNetworksError> {
+    let genomes = population.get_genomes()?;
+    let number_of_elites = evolution_config.get_number_of_elites();
+
+    if genomes.is_empty() {
+        return Err(NeuralNetworksError::EmptyPopulation);
+    }
+    if number_of_elites > population.get_size() {
+        return Err(NeuralNetworksError::TooManyElites {
+            number_of_elites: number_of_elites as usize,
+            population_size: population.get_size() as usize,
+        });
     }
 
     let mut next_generation = Population::new();
 
-    for index in 0..population.get_midpoint() {
+    // The elites go through unchanged, so the best genomes can't be lost to mutation.
+    for index in sort_indexes_by_fitness(genomes)
+        .iter()
+        .take(number_of_elites as usize)
+    {
+        let mut elite = genomes[*index].clone();
+        elite.set_fitness(0f64);
+
+        next_generation.add(elite)?;
+    }
+
+    while next_generation.get_size() < population.get_size() {
+        let first_parent = selection_strategy.select(genomes, randomizer)?;
+        let second_parent = selection_strategy.select(genomes, randomizer)?;
+
         let (mut first_child, mut second_child) = crossover_genomes(
-            GenomeCouple::new(
-                &population.get_genomes()?[index as usize],
-                &population.get_genomes()?[(index as usize) + 1],
-            )?,
+            GenomeCouple::new(&genomes[first_parent], &genomes[second_parent])?,
             &genome_creator,
             &neural_network_creator,
             &neuron_creator,
+            evolution_config,
             randomizer,
         )?;
 
-        mutate_genome(&mut first_child, randomizer)?;
-        mutate_genome(&mut second_child, randomizer)?;
+        mutate_genome(&mut first_child, evolution_config, randomizer)?;
+        mutate_genome(&mut second_child, evolution_config, randomizer)?;
 
         next_generation.add(first_child)?;
 
-        if !(index == population.get_midpoint() - 1 && population.get_size() % 2 == 1) {
+        if next_generation.get_size() < population.get_size() {
             next_generation.add(second_child)?;
         }
     }
 
     // Give new identifiers to the genomes.
-    (0..next_generation.get_size()).for_each(|index| {
-        next_generation.get_genomes_mut().unwrap()[index as usize].set_identifier(index + 1);
-    });
-
-    assert!(!next_generation
-        .get_genomes()?
-        .iter()
-        .any(|evolved_genome| evolved_genome.get_identifier() == 0));
+    for (index, genome) in next_generation.get_genomes_mut()?.iter_mut().enumerate() {
+        genome.set_identifier(index as u32 + 1);
+    }
 
     Ok(next_generation)
 }
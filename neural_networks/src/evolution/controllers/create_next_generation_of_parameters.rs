@@ -0,0 +1,192 @@
+extern crate randomization;
+use self::randomization::randomizer::RandomizerTrait;
+use evolution::controllers::create_next_generation::create_next_generation;
+use evolution::domain::evolution_config::EvolutionConfig;
+use evolution::domain::genome::Genome;
+use evolution::domain::genome::GenomeTrait;
+use evolution::domain::population::Population;
+use evolution::domain::population::PopulationTrait;
+use evolution::domain::selection::selection_strategy_trait::SelectionStrategyTrait;
+use layer::Layer;
+use neural_network::LayeredNeuralNetworkTrait;
+use neural_network::NeuralNetwork;
+use neural_network::NeuralNetworkTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::MutableNeuronTrait;
+use neuron::Neuron;
+use neuron::NeuronTrait;
+use neuron_activation::activation_functions::ActivationFunctions;
+
+// create_next_generation for networks that aren't a stack of layers of neurons, such as modular
+// or graph networks. Their parameters get laid out as the weights of a single neuron, which the
+// configured crossover and mutation operators work on, and get copied back into a clone of the
+// first genome's network. Operators that swap whole neurons or layers swap every parameter at
+// once, and the bias of that neuron goes unused.
+pub fn create_next_generation_of_parameters<
+    T: GenomeTrait<U, V> + Clone,
+    U: NeuralNetworkTrait<V> + Clone,
+    V: NeuronTrait + Clone,
+    X: RandomizerTrait,
+    Y: Fn(u32, U) -> T,
+    B: SelectionStrategyTrait,
+>(
+    population: &Population<T, U, V>,
+    selection_strategy: &B,
+    evolution_config: &EvolutionConfig,
+    genome_creator: Y,
+    randomizer: &mut X,
+) -> Result<Population<T, U, V>, NeuralNetworksError> {
+    let genomes = population.get_genomes()?;
+
+    if genomes.is_empty() {
+        return Err(NeuralNetworksError::EmptyPopulation);
+    }
+
+    let template = genomes[0].get_neural_network();
+
+    if template.get_number_of_parameters() == 0 {
+        return Err(NeuralNetworksError::NoParameters);
+    }
+    if genomes.iter().any(|genome| {
+        !genome
+            .get_neural_network()
+            .has_same_parameter_layout(template)
+    }) {
+        return Err(NeuralNetworksError::DifferentParameterLayouts);
+    }
+
+    let neuron_creator = |number_of_inputs, randomizer: &mut X| {
+        Neuron::new(number_of_inputs, ActivationFunctions::Identity, randomizer)
+    };
+
+    let mut parameters_population = Population::new();
+
+    for genome in genomes.iter() {
+        let parameters = genome.get_neural_network().parameters();
+        let mut neuron = neuron_creator(parameters.len() as u32, randomizer);
+
+        for (index, parameter) in parameters.iter().enumerate() {
+            neuron.set_weight(index, *parameter)?;
+        }
+
+        let mut neural_network = NeuralNetwork::new();
+        neural_network.add(Layer::new(parameters.len() as u32, vec![neuron])?)?;
+
+        let mut parameters_genome = Genome::new(genome.get_identifier(), neural_network);
+        parameters_genome.set_fitness(genome.get_fitness());
+
+        parameters_population.add(parameters_genome)?;
+    }
+
+    let next_parameters_population = create_next_generation(
+        &parameters_population,
+        selection_strategy,
+        evolution_config,
+        Genome::new,
+        NeuralNetwork::new,
+        neuron_creator,
+        randomizer,
+    )?;
+
+    let mut next_generation = Population::new();
+
+    for parameters_genome in next_parameters_population.get_genomes()?.iter() {
+        let mut neural_network = template.clone();
+        neural_network.reset_state();
+        neural_network.set_parameters(
+            parameters_genome
+                .get_neural_network()
+                .get_layer(0)
+                .get_weights(),
+        )?;
+
+        next_generation.add(genome_creator(
+            parameters_genome.get_identifier(),
+            neural_network,
+        ))?;
+    }
+
+    Ok(next_generation)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use evolution::domain::crossover_operators::CrossoverOperators;
+    use evolution::domain::mutation_operators::MutationOperators;
+    use evolution::domain::selection::tournament_selection::TournamentSelection;
+    use network_layers::layer_normalization::LayerNormalization;
+    use network_layers::modular_neural_network::ModularNeuralNetwork;
+
+    use self::randomization::randomizer::Randomizer;
+
+    #[test]
+    fn test_modular_neural_networks_evolve_through_their_parameters(
+    ) -> Result<(), NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+        let neuron_creator = |number_of_inputs, randomizer: &mut Randomizer| {
+            Neuron::new(number_of_inputs, ActivationFunctions::Tanh, randomizer)
+        };
+        let mut population = Population::new();
+
+        for identifier in 1..=6 {
+            let mut neural_network = ModularNeuralNetwork::new();
+            neural_network.add(Box::new(Layer::create_layer(
+                2,
+                3,
+                &mut randomizer,
+                neuron_creator,
+            )?))?;
+            neural_network.add(Box::new(LayerNormalization::new(3)))?;
+            neural_network.add(Box::new(Layer::create_layer(
+                3,
+                1,
+                &mut randomizer,
+                neuron_creator,
+            )?))?;
+
+            let mut genome = Genome::new(identifier, neural_network);
+            genome.set_fitness(f64::from(identifier));
+            population.add(genome)?;
+        }
+
+        let mut evolution_config = EvolutionConfig::new();
+        evolution_config.set_crossover_operator(CrossoverOperators::Uniform);
+        evolution_config
+            .set_mutation_operator(MutationOperators::GaussianPerturbation { sigma: 0.1 });
+        evolution_config.set_number_of_elites(1);
+
+        let next_generation = create_next_generation_of_parameters(
+            &population,
+            &TournamentSelection::new(3),
+            &evolution_config,
+            Genome::new,
+            &mut randomizer,
+        )?;
+
+        let genomes = next_generation.get_genomes()?;
+        let fittest = population.get_genomes()?[5].get_neural_network();
+
+        assert_eq!(genomes.len(), 6);
+        assert!(genomes.iter().all(|genome| genome
+            .get_neural_network()
+            .has_same_parameter_layout(fittest)));
+        assert_eq!(
+            genomes[0].get_neural_network().parameters(),
+            fittest.parameters()
+        );
+        assert!(genomes[1..]
+            .iter()
+            .any(|genome| genome.get_neural_network().parameters() != fittest.parameters()));
+        assert_eq!(
+            genomes[1]
+                .get_neural_network()
+                .propagate(&[0.5, -0.5])?
+                .len(),
+            1
+        );
+
+        Ok(())
+    }
+}
@@ -1 +1,2 @@
 pub mod create_next_generation;
+pub mod create_next_generation_of_parameters;
@@ -1,2 +1,3 @@
-pub const CROSSOVER_PROBABILITY: f64 = 0.01;
-pub const MUTATION_PROBABILITY: f64 = 0.005;
+pub const DEFAULT_CROSSOVER_RATE: f64 = 0.01;
+pub const DEFAULT_MUTATION_RATE: f64 = 0.005;
+pub const DEFAULT_NUMBER_OF_ELITES: u32 = 1;
@@ -1,19 +1,19 @@
 extern crate randomization;
 
-use self::randomization::randomizer::Randomizer;
+use self::randomization::randomizer::RandomizerTrait;
 use evolution::domain::genome::Genome;
+use neural_network::LayeredNeuralNetworkTrait;
 use neural_network::NeuralNetwork;
-use neural_network::NeuralNetworkTrait;
+use neural_networks_error::NeuralNetworksError;
 use neuron::Neuron;
-use neuron::NeuronTrait;
 use neuron_activation::activation_functions::ActivationFunctions;
 
-pub fn create_genome(
+pub fn create_genome<T: RandomizerTrait>(
     genome_identifier: u32,
     layers_definition: &[[usize; 2]],
-    randomizer: &mut Randomizer,
-) -> Genome<NeuralNetwork<Neuron>, Neuron> {
-    Genome::new(
+    randomizer: &mut T,
+) -> Result<Genome<NeuralNetwork<Neuron>, Neuron>, NeuralNetworksError> {
+    Ok(Genome::new(
         genome_identifier,
         NeuralNetwork::new_with_specified_layers(
             layers_definition,
@@ -21,6 +21,6 @@ pub fn create_genome(
             |number_of_inputs, randomizer| {
                 Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
             },
-        ),
-    )
+        )?,
+    ))
 }
@@ -0,0 +1,26 @@
+extern crate randomization;
+
+use self::randomization::randomizer::Randomizer;
+use evolution::domain::create_genome::create_genome;
+use evolution::domain::genome::Genome;
+use evolution::domain::genome::GenomeTrait;
+use neural_network::NeuralNetwork;
+use neural_networks_error::NeuralNetworksError;
+use neuron::Neuron;
+
+// One small genome per fitness, numbered from 1, for the tests that only care about fitnesses.
+pub fn create_genomes_with_fitnesses(
+    fitnesses: &[f64],
+) -> Result<Vec<Genome<NeuralNetwork<Neuron>, Neuron>>, NeuralNetworksError> {
+    let mut randomizer = Randomizer::new();
+
+    fitnesses
+        .iter()
+        .enumerate()
+        .map(|(index, fitness)| {
+            let mut genome = create_genome(index as u32 + 1, &[[2, 1]], &mut randomizer)?;
+            genome.set_fitness(*fitness);
+            Ok(genome)
+        })
+        .collect()
+}
@@ -0,0 +1,136 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use evolution::domain::crossover::crossover_operator_trait::CrossoverOperatorTrait;
+use evolution::domain::crossover::neuron_genes::get_gene;
+use evolution::domain::crossover::neuron_genes::get_number_of_genes;
+use evolution::domain::crossover::neuron_genes::set_gene;
+use evolution::domain::layer_couple::LayerCouple;
+use layer::Layer;
+use layer::LayerTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::MutableNeuronTrait;
+use neuron::NeuronTrait;
+
+// BLX-alpha: each gene of a child is drawn uniformly from the interval spanned by the genes of
+// its parents, stretched by alpha times its length on both sides. With an alpha of 0 the children
+// are arithmetic blends of their parents. Activation functions can't be blended, so they get
+// swapped with the crossover rate as the probability.
+#[derive(Debug, Clone)]
+pub struct ArithmeticBlendCrossover {
+    alpha: f64,
+}
+
+impl ArithmeticBlendCrossover {
+    pub fn new(alpha: f64) -> Self {
+        ArithmeticBlendCrossover { alpha }
+    }
+
+    fn blend<U: RandomizerTrait>(
+        &self,
+        first_gene: f64,
+        second_gene: f64,
+        randomizer: &mut U,
+    ) -> f64 {
+        let extension = self.alpha * (first_gene - second_gene).abs();
+        let lower = first_gene.min(second_gene) - extension;
+        let upper = first_gene.max(second_gene) + extension;
+
+        lower + randomizer.generate_float_from_0_to_1() * (upper - lower)
+    }
+}
+
+impl CrossoverOperatorTrait for ArithmeticBlendCrossover {
+    fn crossover<T: NeuronTrait, U: RandomizerTrait>(
+        &self,
+        layer_couple: &LayerCouple<T>,
+        first_child: &mut Layer<T>,
+        second_child: &mut Layer<T>,
+        crossover_rate: f64,
+        randomizer: &mut U,
+    ) -> Result<(), NeuralNetworksError> {
+        for index in 0..layer_couple.get_first_parent().get_number_of_neurons() as usize {
+            let first_parent = layer_couple.get_first_parent().get_neuron(index)?;
+            let second_parent = layer_couple.get_second_parent().get_neuron(index)?;
+
+            let mut first_neuron = first_child.get_neuron_mut(index)?;
+            let mut second_neuron = second_child.get_neuron_mut(index)?;
+
+            for gene_index in 0..get_number_of_genes(&first_parent) {
+                let first_gene = get_gene(&first_parent, gene_index)?;
+                let second_gene = get_gene(&second_parent, gene_index)?;
+
+                set_gene(
+                    &mut first_neuron,
+                    gene_index,
+                    self.blend(first_gene, second_gene, randomizer),
+                )?;
+                set_gene(
+                    &mut second_neuron,
+                    gene_index,
+                    self.blend(first_gene, second_gene, randomizer),
+                )?;
+            }
+
+            let (first_source, second_source) =
+                if Layer::<T>::should_crossover(crossover_rate, randomizer)? {
+                    (second_parent, first_parent)
+                } else {
+                    (first_parent, second_parent)
+                };
+
+            first_neuron.set_activation_function(*first_source.get_activation_function())?;
+            second_neuron.set_activation_function(*second_source.get_activation_function())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use neuron::Neuron;
+    use neuron_activation::activation_functions::ActivationFunctions;
+
+    use self::randomization::randomizer::Randomizer;
+
+    #[test]
+    fn test_genes_fall_within_the_stretched_interval_of_the_parents(
+    ) -> Result<(), NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+        let neuron_creator = |number_of_inputs, randomizer: &mut Randomizer| {
+            Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+        };
+
+        let first_parent = Layer::create_layer(10, 5, &mut randomizer, neuron_creator)?;
+        let second_parent = Layer::create_layer(10, 5, &mut randomizer, neuron_creator)?;
+        let mut first_child = first_parent.clone();
+        let mut second_child = second_parent.clone();
+
+        ArithmeticBlendCrossover::new(0.5).crossover(
+            &LayerCouple::new(&first_parent, &second_parent)?,
+            &mut first_child,
+            &mut second_child,
+            0.0,
+            &mut randomizer,
+        )?;
+
+        for (index, weight) in first_child
+            .get_weights()
+            .iter()
+            .chain(second_child.get_weights().iter())
+            .enumerate()
+        {
+            let first_weight = first_parent.get_weights()[index % 50];
+            let second_weight = second_parent.get_weights()[index % 50];
+            let extension = 0.5 * (first_weight - second_weight).abs();
+
+            assert!(*weight >= first_weight.min(second_weight) - extension);
+            assert!(*weight <= first_weight.max(second_weight) + extension);
+        }
+
+        Ok(())
+    }
+}
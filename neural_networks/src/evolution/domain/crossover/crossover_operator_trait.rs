@@ -0,0 +1,19 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use evolution::domain::layer_couple::LayerCouple;
+use layer::Layer;
+use neural_networks_error::NeuralNetworksError;
+use neuron::NeuronTrait;
+
+pub trait CrossoverOperatorTrait {
+    // Fills every gene of both children, which must have the same shape as their parents.
+    fn crossover<T: NeuronTrait, U: RandomizerTrait>(
+        &self,
+        layer_couple: &LayerCouple<T>,
+        first_child: &mut Layer<T>,
+        second_child: &mut Layer<T>,
+        crossover_rate: f64,
+        randomizer: &mut U,
+    ) -> Result<(), NeuralNetworksError>;
+}
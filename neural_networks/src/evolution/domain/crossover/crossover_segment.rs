@@ -0,0 +1,71 @@
+use evolution::domain::crossover::neuron_genes::get_gene;
+use evolution::domain::crossover::neuron_genes::get_number_of_genes;
+use evolution::domain::crossover::neuron_genes::set_gene;
+use evolution::domain::layer_couple::LayerCouple;
+use layer::Layer;
+use layer::LayerTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::MutableNeuronTrait;
+use neuron::NeuronTrait;
+
+// Lays the genes of every neuron in the layer one after the other, and swaps between the children
+// those whose position falls in [start, end). The activation function of a neuron goes along
+// with its bias.
+pub fn crossover_segment<T: NeuronTrait>(
+    layer_couple: &LayerCouple<T>,
+    first_child: &mut Layer<T>,
+    second_child: &mut Layer<T>,
+    start: usize,
+    end: usize,
+) -> Result<(), NeuralNetworksError> {
+    let mut position = 0;
+
+    for index in 0..layer_couple.get_first_parent().get_number_of_neurons() as usize {
+        let first_parent = layer_couple.get_first_parent().get_neuron(index)?;
+        let second_parent = layer_couple.get_second_parent().get_neuron(index)?;
+
+        let mut first_neuron = first_child.get_neuron_mut(index)?;
+        let mut second_neuron = second_child.get_neuron_mut(index)?;
+
+        for gene_index in 0..get_number_of_genes(&first_parent) {
+            let (first_source, second_source) = if position >= start && position < end {
+                (second_parent, first_parent)
+            } else {
+                (first_parent, second_parent)
+            };
+
+            set_gene(
+                &mut first_neuron,
+                gene_index,
+                get_gene(&first_source, gene_index)?,
+            )?;
+            set_gene(
+                &mut second_neuron,
+                gene_index,
+                get_gene(&second_source, gene_index)?,
+            )?;
+
+            if gene_index == 0 {
+                first_neuron.set_activation_function(*first_source.get_activation_function())?;
+                second_neuron.set_activation_function(*second_source.get_activation_function())?;
+            }
+
+            position += 1;
+        }
+    }
+
+    Ok(())
+}
+
+// The number of positions crossover_segment works with.
+pub fn get_number_of_genes_in_layer<T: NeuronTrait>(
+    layer: &Layer<T>,
+) -> Result<usize, NeuralNetworksError> {
+    let mut number_of_genes = 0;
+
+    for index in 0..layer.get_number_of_neurons() as usize {
+        number_of_genes += get_number_of_genes(&layer.get_neuron(index)?);
+    }
+
+    Ok(number_of_genes)
+}
@@ -0,0 +1,68 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use evolution::domain::crossover::crossover_operator_trait::CrossoverOperatorTrait;
+use evolution::domain::layer_couple::LayerCouple;
+use evolution::domain::mechanics::crossover_activation_functions_of_neurons::crossover_activation_functions_of_neurons;
+use evolution::domain::mechanics::crossover_biases_of_neurons::crossover_biases_of_neurons;
+use evolution::domain::mechanics::crossover_weights_of_neurons::crossover_weights_of_neurons;
+use layer::Layer;
+use layer::LayerTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::NeuronTrait;
+
+// Swaps each bias, weight and activation function independently, with the crossover rate as
+// the probability.
+#[derive(Debug, Clone, Default)]
+pub struct GeneSwapCrossover {}
+
+impl GeneSwapCrossover {
+    pub fn new() -> Self {
+        GeneSwapCrossover {}
+    }
+}
+
+impl CrossoverOperatorTrait for GeneSwapCrossover {
+    fn crossover<T: NeuronTrait, U: RandomizerTrait>(
+        &self,
+        layer_couple: &LayerCouple<T>,
+        first_child: &mut Layer<T>,
+        second_child: &mut Layer<T>,
+        crossover_rate: f64,
+        randomizer: &mut U,
+    ) -> Result<(), NeuralNetworksError> {
+        // Cannot iter() over here since destructuring assignments are not allowed
+        // https://github.com/rust-lang/rfcs/issues/372
+
+        for index in 0..layer_couple.get_first_parent().get_number_of_neurons() as usize {
+            crossover_biases_of_neurons(
+                layer_couple,
+                first_child,
+                second_child,
+                index,
+                crossover_rate,
+                randomizer,
+            )?;
+
+            crossover_weights_of_neurons(
+                layer_couple,
+                first_child,
+                second_child,
+                index,
+                crossover_rate,
+                randomizer,
+            )?;
+
+            crossover_activation_functions_of_neurons(
+                layer_couple,
+                first_child,
+                second_child,
+                index,
+                crossover_rate,
+                randomizer,
+            )?;
+        }
+
+        Ok(())
+    }
+}
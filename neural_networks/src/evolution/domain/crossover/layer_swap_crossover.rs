@@ -0,0 +1,106 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use evolution::domain::crossover::crossover_operator_trait::CrossoverOperatorTrait;
+use evolution::domain::crossover::neuron_genes::inherit_neuron;
+use evolution::domain::layer_couple::LayerCouple;
+use layer::Layer;
+use layer::LayerTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::NeuronTrait;
+
+// Swaps the whole layer between the children, with the crossover rate as the probability.
+#[derive(Debug, Clone, Default)]
+pub struct LayerSwapCrossover {}
+
+impl LayerSwapCrossover {
+    pub fn new() -> Self {
+        LayerSwapCrossover {}
+    }
+}
+
+impl CrossoverOperatorTrait for LayerSwapCrossover {
+    fn crossover<T: NeuronTrait, U: RandomizerTrait>(
+        &self,
+        layer_couple: &LayerCouple<T>,
+        first_child: &mut Layer<T>,
+        second_child: &mut Layer<T>,
+        crossover_rate: f64,
+        randomizer: &mut U,
+    ) -> Result<(), NeuralNetworksError> {
+        let (first_source, second_source) =
+            if Layer::<T>::should_crossover(crossover_rate, randomizer)? {
+                (
+                    layer_couple.get_second_parent(),
+                    layer_couple.get_first_parent(),
+                )
+            } else {
+                (
+                    layer_couple.get_first_parent(),
+                    layer_couple.get_second_parent(),
+                )
+            };
+
+        for index in 0..first_source.get_number_of_neurons() as usize {
+            inherit_neuron(
+                &first_source.get_neuron(index)?,
+                &mut first_child.get_neuron_mut(index)?,
+            )?;
+            inherit_neuron(
+                &second_source.get_neuron(index)?,
+                &mut second_child.get_neuron_mut(index)?,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use neuron::Neuron;
+    use neuron_activation::activation_functions::ActivationFunctions;
+
+    use self::randomization::randomizer::Randomizer;
+
+    #[test]
+    fn test_the_layer_gets_swapped_or_kept_as_a_whole() -> Result<(), NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+        let neuron_creator = |number_of_inputs, randomizer: &mut Randomizer| {
+            Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+        };
+
+        let first_parent = Layer::create_layer(3, 2, &mut randomizer, neuron_creator)?;
+        let second_parent = Layer::create_layer(3, 2, &mut randomizer, neuron_creator)?;
+        let layer_couple = LayerCouple::new(&first_parent, &second_parent)?;
+
+        let mut first_child = Layer::create_layer(3, 2, &mut randomizer, neuron_creator)?;
+        let mut second_child = Layer::create_layer(3, 2, &mut randomizer, neuron_creator)?;
+
+        LayerSwapCrossover::new().crossover(
+            &layer_couple,
+            &mut first_child,
+            &mut second_child,
+            1.0,
+            &mut randomizer,
+        )?;
+
+        assert_eq!(first_child.get_weights(), second_parent.get_weights());
+        assert_eq!(second_child.get_biases(), first_parent.get_biases());
+
+        LayerSwapCrossover::new().crossover(
+            &layer_couple,
+            &mut first_child,
+            &mut second_child,
+            0.0,
+            &mut randomizer,
+        )?;
+
+        assert_eq!(first_child.get_weights(), first_parent.get_weights());
+        assert_eq!(second_child.get_biases(), second_parent.get_biases());
+
+        Ok(())
+    }
+}
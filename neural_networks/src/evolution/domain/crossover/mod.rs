@@ -0,0 +1,10 @@
+pub mod arithmetic_blend_crossover;
+pub mod crossover_operator_trait;
+pub mod crossover_segment;
+pub mod gene_swap_crossover;
+pub mod layer_swap_crossover;
+pub mod neuron_genes;
+pub mod neuron_swap_crossover;
+pub mod single_point_crossover;
+pub mod two_point_crossover;
+pub mod uniform_crossover;
@@ -0,0 +1,41 @@
+use neural_networks_error::NeuralNetworksError;
+use neuron::MutableNeuronTrait;
+use neuron::NeuronTrait;
+
+// The genes of a neuron are its bias (gene 0) followed by its weights.
+pub fn get_number_of_genes<T: NeuronTrait>(neuron: &T) -> usize {
+    neuron.get_number_of_weights() as usize + 1
+}
+
+pub fn get_gene<T: NeuronTrait>(neuron: &T, gene_index: usize) -> Result<f64, NeuralNetworksError> {
+    if gene_index == 0 {
+        Ok(neuron.get_bias())
+    } else {
+        Ok(neuron.get_weight(gene_index - 1)?)
+    }
+}
+
+pub fn set_gene<T: MutableNeuronTrait>(
+    neuron: &mut T,
+    gene_index: usize,
+    value: f64,
+) -> Result<(), NeuralNetworksError> {
+    if gene_index == 0 {
+        neuron.set_bias(value);
+
+        Ok(())
+    } else {
+        Ok(neuron.set_weight(gene_index - 1, value)?)
+    }
+}
+
+pub fn inherit_neuron<T: NeuronTrait, U: MutableNeuronTrait>(
+    parent: &T,
+    child: &mut U,
+) -> Result<(), NeuralNetworksError> {
+    for gene_index in 0..get_number_of_genes(parent) {
+        set_gene(child, gene_index, get_gene(parent, gene_index)?)?;
+    }
+
+    child.set_activation_function(*parent.get_activation_function())
+}
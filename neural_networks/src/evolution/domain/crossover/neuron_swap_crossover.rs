@@ -0,0 +1,95 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use evolution::domain::crossover::crossover_operator_trait::CrossoverOperatorTrait;
+use evolution::domain::crossover::neuron_genes::inherit_neuron;
+use evolution::domain::layer_couple::LayerCouple;
+use layer::Layer;
+use layer::LayerTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::NeuronTrait;
+
+// Swaps whole neurons (bias, weights and activation function) between the children, each one
+// with the crossover rate as the probability.
+#[derive(Debug, Clone, Default)]
+pub struct NeuronSwapCrossover {}
+
+impl NeuronSwapCrossover {
+    pub fn new() -> Self {
+        NeuronSwapCrossover {}
+    }
+}
+
+impl CrossoverOperatorTrait for NeuronSwapCrossover {
+    fn crossover<T: NeuronTrait, U: RandomizerTrait>(
+        &self,
+        layer_couple: &LayerCouple<T>,
+        first_child: &mut Layer<T>,
+        second_child: &mut Layer<T>,
+        crossover_rate: f64,
+        randomizer: &mut U,
+    ) -> Result<(), NeuralNetworksError> {
+        for index in 0..layer_couple.get_first_parent().get_number_of_neurons() as usize {
+            let first_parent = layer_couple.get_first_parent().get_neuron(index)?;
+            let second_parent = layer_couple.get_second_parent().get_neuron(index)?;
+
+            if Layer::<T>::should_crossover(crossover_rate, randomizer)? {
+                inherit_neuron(&second_parent, &mut first_child.get_neuron_mut(index)?)?;
+                inherit_neuron(&first_parent, &mut second_child.get_neuron_mut(index)?)?;
+            } else {
+                inherit_neuron(&first_parent, &mut first_child.get_neuron_mut(index)?)?;
+                inherit_neuron(&second_parent, &mut second_child.get_neuron_mut(index)?)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use neuron::Neuron;
+    use neuron_activation::activation_functions::ActivationFunctions;
+
+    use self::randomization::randomizer::Randomizer;
+
+    #[test]
+    fn test_neurons_get_swapped_as_a_whole() -> Result<(), NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+
+        let first_parent =
+            Layer::create_layer(3, 2, &mut randomizer, |number_of_inputs, randomizer| {
+                Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+            })?;
+        let second_parent =
+            Layer::create_layer(3, 2, &mut randomizer, |number_of_inputs, randomizer| {
+                Neuron::new(number_of_inputs, ActivationFunctions::Tanh, randomizer)
+            })?;
+        let mut first_child = first_parent.clone();
+        let mut second_child = second_parent.clone();
+
+        NeuronSwapCrossover::new().crossover(
+            &LayerCouple::new(&first_parent, &second_parent)?,
+            &mut first_child,
+            &mut second_child,
+            1.0,
+            &mut randomizer,
+        )?;
+
+        assert_eq!(first_child.get_weights(), second_parent.get_weights());
+        assert_eq!(first_child.get_biases(), second_parent.get_biases());
+        assert_eq!(second_child.get_weights(), first_parent.get_weights());
+        assert_eq!(
+            first_child.get_neuron(1)?.get_activation_function(),
+            &ActivationFunctions::Tanh
+        );
+        assert_eq!(
+            second_child.get_neuron(0)?.get_activation_function(),
+            &ActivationFunctions::Sigmoid
+        );
+
+        Ok(())
+    }
+}
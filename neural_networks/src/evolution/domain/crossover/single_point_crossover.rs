@@ -0,0 +1,108 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use evolution::domain::crossover::crossover_operator_trait::CrossoverOperatorTrait;
+use evolution::domain::crossover::crossover_segment::crossover_segment;
+use evolution::domain::crossover::crossover_segment::get_number_of_genes_in_layer;
+use evolution::domain::layer_couple::LayerCouple;
+use layer::Layer;
+use neural_networks_error::NeuralNetworksError;
+use neuron::NeuronTrait;
+
+// Picks a point in the genes of the layer, and the children swap everything after it.
+#[derive(Debug, Clone, Default)]
+pub struct SinglePointCrossover {}
+
+impl SinglePointCrossover {
+    pub fn new() -> Self {
+        SinglePointCrossover {}
+    }
+}
+
+impl CrossoverOperatorTrait for SinglePointCrossover {
+    fn crossover<T: NeuronTrait, U: RandomizerTrait>(
+        &self,
+        layer_couple: &LayerCouple<T>,
+        first_child: &mut Layer<T>,
+        second_child: &mut Layer<T>,
+        _crossover_rate: f64,
+        randomizer: &mut U,
+    ) -> Result<(), NeuralNetworksError> {
+        let number_of_genes = get_number_of_genes_in_layer(layer_couple.get_first_parent())?;
+
+        let point =
+            randomizer.choose_random_from_vec(&(0..=number_of_genes).collect::<Vec<usize>>());
+
+        crossover_segment(
+            layer_couple,
+            first_child,
+            second_child,
+            point,
+            number_of_genes,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use evolution::domain::crossover::neuron_genes::get_gene;
+    use layer::LayerTrait;
+    use neuron::Neuron;
+    use neuron_activation::activation_functions::ActivationFunctions;
+
+    struct FakeRandomizer {
+        normal: f64,
+    }
+
+    impl RandomizerTrait for FakeRandomizer {
+        fn get_normal(&mut self) -> f64 {
+            self.normal
+        }
+        fn generate_float_from_0_to_1(&mut self) -> f64 {
+            todo!()
+        }
+        fn choose_random_from_vec<T>(&mut self, vector: &[T]) -> T
+        where
+            T: std::clone::Clone,
+        {
+            vector[5].clone()
+        }
+    }
+
+    #[test]
+    fn test_the_genes_after_the_point_get_swapped() -> Result<(), NeuralNetworksError> {
+        let neuron_creator = |number_of_inputs, randomizer: &mut FakeRandomizer| {
+            Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+        };
+
+        let first_parent =
+            Layer::create_layer(3, 2, &mut FakeRandomizer { normal: 1.0 }, neuron_creator)?;
+        let second_parent =
+            Layer::create_layer(3, 2, &mut FakeRandomizer { normal: 2.0 }, neuron_creator)?;
+        let mut first_child = first_parent.clone();
+        let mut second_child = second_parent.clone();
+
+        SinglePointCrossover::new().crossover(
+            &LayerCouple::new(&first_parent, &second_parent)?,
+            &mut first_child,
+            &mut second_child,
+            0.0,
+            &mut FakeRandomizer { normal: 0.0 },
+        )?;
+
+        // Each neuron has 4 genes, so the point falls on the second gene of the second neuron.
+        for gene_index in 0..4 {
+            assert_eq!(get_gene(&first_child.get_neuron(0)?, gene_index)?, 1.0);
+            assert_eq!(get_gene(&second_child.get_neuron(0)?, gene_index)?, 2.0);
+        }
+        assert_eq!(get_gene(&first_child.get_neuron(1)?, 0)?, 1.0);
+        for gene_index in 1..4 {
+            assert_eq!(get_gene(&first_child.get_neuron(1)?, gene_index)?, 2.0);
+            assert_eq!(get_gene(&second_child.get_neuron(1)?, gene_index)?, 1.0);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use evolution::domain::crossover::crossover_operator_trait::CrossoverOperatorTrait;
+use evolution::domain::crossover::crossover_segment::crossover_segment;
+use evolution::domain::crossover::crossover_segment::get_number_of_genes_in_layer;
+use evolution::domain::layer_couple::LayerCouple;
+use layer::Layer;
+use neural_networks_error::NeuralNetworksError;
+use neuron::NeuronTrait;
+
+// Picks two points in the genes of the layer, and the children swap what lies between them.
+#[derive(Debug, Clone, Default)]
+pub struct TwoPointCrossover {}
+
+impl TwoPointCrossover {
+    pub fn new() -> Self {
+        TwoPointCrossover {}
+    }
+}
+
+impl CrossoverOperatorTrait for TwoPointCrossover {
+    fn crossover<T: NeuronTrait, U: RandomizerTrait>(
+        &self,
+        layer_couple: &LayerCouple<T>,
+        first_child: &mut Layer<T>,
+        second_child: &mut Layer<T>,
+        _crossover_rate: f64,
+        randomizer: &mut U,
+    ) -> Result<(), NeuralNetworksError> {
+        let points: Vec<usize> =
+            (0..=get_number_of_genes_in_layer(layer_couple.get_first_parent())?).collect();
+
+        let first_point = randomizer.choose_random_from_vec(&points);
+        let second_point = randomizer.choose_random_from_vec(&points);
+
+        crossover_segment(
+            layer_couple,
+            first_child,
+            second_child,
+            first_point.min(second_point),
+            first_point.max(second_point),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use evolution::domain::crossover::neuron_genes::get_gene;
+    use layer::LayerTrait;
+    use neuron::Neuron;
+    use neuron_activation::activation_functions::ActivationFunctions;
+
+    use self::randomization::randomizer::Randomizer;
+
+    #[test]
+    fn test_a_single_segment_of_genes_gets_swapped() -> Result<(), NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+        let neuron_creator = |number_of_inputs, randomizer: &mut Randomizer| {
+            Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+        };
+
+        let first_parent = Layer::create_layer(5, 4, &mut randomizer, neuron_creator)?;
+        let second_parent = Layer::create_layer(5, 4, &mut randomizer, neuron_creator)?;
+        let mut first_child = first_parent.clone();
+        let mut second_child = second_parent.clone();
+
+        TwoPointCrossover::new().crossover(
+            &LayerCouple::new(&first_parent, &second_parent)?,
+            &mut first_child,
+            &mut second_child,
+            0.0,
+            &mut randomizer,
+        )?;
+
+        let mut origins = Vec::new();
+
+        for index in 0..4 {
+            for gene_index in 0..6 {
+                let gene = get_gene(&first_child.get_neuron(index)?, gene_index)?;
+                let is_swapped = gene == get_gene(&second_parent.get_neuron(index)?, gene_index)?;
+
+                assert_eq!(
+                    get_gene(&second_child.get_neuron(index)?, gene_index)?,
+                    if is_swapped {
+                        get_gene(&first_parent.get_neuron(index)?, gene_index)?
+                    } else {
+                        get_gene(&second_parent.get_neuron(index)?, gene_index)?
+                    }
+                );
+
+                origins.push(is_swapped);
+            }
+        }
+
+        // Going through the genes in order, the origin changes at most twice.
+        assert!(origins.windows(2).filter(|pair| pair[0] != pair[1]).count() <= 2);
+
+        Ok(())
+    }
+}
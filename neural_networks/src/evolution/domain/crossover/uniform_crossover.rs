@@ -0,0 +1,87 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use evolution::domain::crossover::crossover_operator_trait::CrossoverOperatorTrait;
+use evolution::domain::crossover::gene_swap_crossover::GeneSwapCrossover;
+use evolution::domain::layer_couple::LayerCouple;
+use layer::Layer;
+use neural_networks_error::NeuralNetworksError;
+use neuron::NeuronTrait;
+
+// Every gene comes from either parent with the same probability, whatever the crossover rate.
+#[derive(Debug, Clone, Default)]
+pub struct UniformCrossover {}
+
+impl UniformCrossover {
+    pub fn new() -> Self {
+        UniformCrossover {}
+    }
+}
+
+impl CrossoverOperatorTrait for UniformCrossover {
+    fn crossover<T: NeuronTrait, U: RandomizerTrait>(
+        &self,
+        layer_couple: &LayerCouple<T>,
+        first_child: &mut Layer<T>,
+        second_child: &mut Layer<T>,
+        _crossover_rate: f64,
+        randomizer: &mut U,
+    ) -> Result<(), NeuralNetworksError> {
+        GeneSwapCrossover::new().crossover(layer_couple, first_child, second_child, 0.5, randomizer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use evolution::domain::crossover::neuron_genes::get_gene;
+    use layer::LayerTrait;
+    use neuron::Neuron;
+    use neuron_activation::activation_functions::ActivationFunctions;
+
+    use self::randomization::randomizer::Randomizer;
+
+    #[test]
+    fn test_children_take_genes_from_both_parents_evenly() -> Result<(), NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+        let neuron_creator = |number_of_inputs, randomizer: &mut Randomizer| {
+            Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+        };
+
+        let first_parent = Layer::create_layer(99, 10, &mut randomizer, neuron_creator)?;
+        let second_parent = Layer::create_layer(99, 10, &mut randomizer, neuron_creator)?;
+        let mut first_child = first_parent.clone();
+        let mut second_child = second_parent.clone();
+
+        UniformCrossover::new().crossover(
+            &LayerCouple::new(&first_parent, &second_parent)?,
+            &mut first_child,
+            &mut second_child,
+            0.0,
+            &mut randomizer,
+        )?;
+
+        let mut genes_from_second_parent = 0;
+
+        for index in 0..10 {
+            for gene_index in 0..100 {
+                let gene = get_gene(&first_child.get_neuron(index)?, gene_index)?;
+
+                if gene == get_gene(&second_parent.get_neuron(index)?, gene_index)? {
+                    genes_from_second_parent += 1;
+
+                    assert_eq!(
+                        get_gene(&second_child.get_neuron(index)?, gene_index)?,
+                        get_gene(&first_parent.get_neuron(index)?, gene_index)?
+                    );
+                }
+            }
+        }
+
+        // 1000 genes, about half of them swapped.
+        assert!(genes_from_second_parent > 400 && genes_from_second_parent < 600);
+
+        Ok(())
+    }
+}
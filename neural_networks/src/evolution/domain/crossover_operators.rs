@@ -0,0 +1,17 @@
+extern crate serde;
+
+use self::serde::{Deserialize, Serialize};
+
+// Which crossover operator a run uses (see the 'crossover' module), along with its settings.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum CrossoverOperators {
+    // Each bias, weight and activation function gets swapped between the children independently,
+    // with the crossover rate as its probability.
+    GeneSwap,
+    Uniform,
+    SinglePoint,
+    TwoPoint,
+    NeuronSwap,
+    LayerSwap,
+    ArithmeticBlend { alpha: f64 },
+}
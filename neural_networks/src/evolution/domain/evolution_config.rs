@@ -0,0 +1,180 @@
+extern crate serde;
+
+use self::serde::{Deserialize, Serialize};
+use evolution::domain::constants::DEFAULT_CROSSOVER_RATE;
+use evolution::domain::constants::DEFAULT_MUTATION_RATE;
+use evolution::domain::constants::DEFAULT_NUMBER_OF_ELITES;
+use evolution::domain::crossover_operators::CrossoverOperators;
+use evolution::domain::mutation_operators::MutationOperators;
+use neural_networks_error::NeuralNetworksError;
+use std::convert::TryFrom;
+
+// The settings a training run evolves its population with. It gets serialized along with the
+// rest of a run so the results can be traced back to what produced them.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(try_from = "SerializedEvolutionConfig")]
+pub struct EvolutionConfig {
+    mutation_rate: f64,
+    crossover_rate: f64,
+    number_of_elites: u32,
+    mutation_operator: MutationOperators,
+    crossover_operator: CrossoverOperators,
+}
+
+#[derive(Deserialize)]
+struct SerializedEvolutionConfig {
+    mutation_rate: f64,
+    crossover_rate: f64,
+    number_of_elites: u32,
+    mutation_operator: MutationOperators,
+    crossover_operator: CrossoverOperators,
+}
+
+// Goes through the setters, so a config edited by hand gets the same checks.
+impl TryFrom<SerializedEvolutionConfig> for EvolutionConfig {
+    type Error = NeuralNetworksError;
+
+    fn try_from(serialized: SerializedEvolutionConfig) -> Result<Self, NeuralNetworksError> {
+        let mut evolution_config = EvolutionConfig::new();
+
+        evolution_config.set_mutation_rate(serialized.mutation_rate)?;
+        evolution_config.set_crossover_rate(serialized.crossover_rate)?;
+        evolution_config.set_number_of_elites(serialized.number_of_elites);
+        evolution_config.set_mutation_operator(serialized.mutation_operator);
+        evolution_config.set_crossover_operator(serialized.crossover_operator);
+
+        Ok(evolution_config)
+    }
+}
+
+impl Default for EvolutionConfig {
+    fn default() -> Self {
+        EvolutionConfig {
+            mutation_rate: DEFAULT_MUTATION_RATE,
+            crossover_rate: DEFAULT_CROSSOVER_RATE,
+            number_of_elites: DEFAULT_NUMBER_OF_ELITES,
+            mutation_operator: MutationOperators::ResetToRandom,
+            crossover_operator: CrossoverOperators::GeneSwap,
+        }
+    }
+}
+
+impl EvolutionConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get_mutation_rate(&self) -> f64 {
+        self.mutation_rate
+    }
+
+    pub fn set_mutation_rate(&mut self, mutation_rate: f64) -> Result<(), NeuralNetworksError> {
+        if !(0.0..=1.0).contains(&mutation_rate) {
+            return Err(NeuralNetworksError::MutationRateOutOfRange(mutation_rate));
+        }
+
+        self.mutation_rate = mutation_rate;
+
+        Ok(())
+    }
+
+    pub fn get_crossover_rate(&self) -> f64 {
+        self.crossover_rate
+    }
+
+    pub fn set_crossover_rate(&mut self, crossover_rate: f64) -> Result<(), NeuralNetworksError> {
+        if !(0.0..=1.0).contains(&crossover_rate) {
+            return Err(NeuralNetworksError::CrossoverRateOutOfRange(crossover_rate));
+        }
+
+        self.crossover_rate = crossover_rate;
+
+        Ok(())
+    }
+
+    pub fn get_number_of_elites(&self) -> u32 {
+        self.number_of_elites
+    }
+
+    pub fn set_number_of_elites(&mut self, number_of_elites: u32) {
+        self.number_of_elites = number_of_elites;
+    }
+
+    pub fn get_mutation_operator(&self) -> &MutationOperators {
+        &self.mutation_operator
+    }
+
+    pub fn set_mutation_operator(&mut self, mutation_operator: MutationOperators) {
+        self.mutation_operator = mutation_operator;
+    }
+
+    pub fn get_crossover_operator(&self) -> &CrossoverOperators {
+        &self.crossover_operator
+    }
+
+    pub fn set_crossover_operator(&mut self, crossover_operator: CrossoverOperators) {
+        self.crossover_operator = crossover_operator;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn test_the_default_config_keeps_the_previous_rates() {
+        let evolution_config = EvolutionConfig::new();
+
+        assert_eq!(evolution_config.get_mutation_rate(), 0.005);
+        assert_eq!(evolution_config.get_crossover_rate(), 0.01);
+        assert_eq!(evolution_config.get_number_of_elites(), 1);
+        assert_eq!(
+            evolution_config.get_mutation_operator(),
+            &MutationOperators::ResetToRandom
+        );
+        assert_eq!(
+            evolution_config.get_crossover_operator(),
+            &CrossoverOperators::GeneSwap
+        );
+    }
+
+    #[test]
+    fn test_rates_outside_of_zero_and_one_are_rejected() {
+        let mut evolution_config = EvolutionConfig::new();
+
+        assert!(evolution_config.set_mutation_rate(1.5).is_err());
+        assert!(evolution_config.set_crossover_rate(-0.1).is_err());
+        assert!(evolution_config.set_mutation_rate(0.2).is_ok());
+        assert_eq!(evolution_config.get_mutation_rate(), 0.2);
+    }
+
+    #[test]
+    fn test_can_serialize_and_deserialize_the_config() -> Result<(), Box<dyn Error>> {
+        let mut evolution_config = EvolutionConfig::new();
+        evolution_config.set_crossover_rate(0.25)?;
+        evolution_config.set_number_of_elites(3);
+        evolution_config
+            .set_mutation_operator(MutationOperators::GaussianPerturbation { sigma: 0.1 });
+
+        let serialized = serde_json::to_string(&evolution_config)?;
+        let deserialized: EvolutionConfig = serde_json::from_str(&serialized)?;
+
+        assert_eq!(deserialized, evolution_config);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_deserializing_a_config_with_rates_outside_of_zero_and_one_fails() -> Result<(), String>
+    {
+        let mut serialized =
+            serde_json::to_value(EvolutionConfig::new()).map_err(|e| e.to_string())?;
+        serialized["mutation_rate"] = serde_json::json!(2.5);
+
+        assert!(serde_json::from_value::<EvolutionConfig>(serialized).is_err());
+
+        Ok(())
+    }
+}
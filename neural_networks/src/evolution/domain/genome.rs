@@ -80,16 +80,20 @@ mod tests {
     use self::file_system::read_file_to_string::read_file_to_string;
     use self::file_system::save_json::save_json;
 
+    use neural_network::LayeredNeuralNetworkTrait;
     use neural_network::NeuralNetwork;
     extern crate randomization;
     use self::randomization::randomizer::Randomizer;
+    use neural_networks_error::NeuralNetworksError;
     use neuron::Neuron;
     use neuron_activation::activation_functions::ActivationFunctions;
+    use std::error::Error;
 
     use super::*;
 
     #[test]
-    fn test_after_creating_a_genome_it_has_expected_properties() -> Result<(), String> {
+    fn test_after_creating_a_genome_it_has_expected_properties() -> Result<(), NeuralNetworksError>
+    {
         let mut randomizer = Randomizer::new();
 
         let neural_network = NeuralNetwork::new_with_specified_layers(
@@ -98,7 +102,7 @@ mod tests {
             |number_of_inputs, randomizer| {
                 Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
             },
-        );
+        )?;
 
         let genome = Genome::new(1, neural_network);
 
@@ -110,7 +114,7 @@ mod tests {
     }
 
     #[test]
-    fn test_can_serialize_and_deserialize_genomes() -> Result<(), String> {
+    fn test_can_serialize_and_deserialize_genomes() -> Result<(), Box<dyn Error>> {
         let mut randomizer = Randomizer::new();
 
         let neural_network = NeuralNetwork::<Neuron>::new_with_specified_layers(
@@ -119,7 +123,7 @@ mod tests {
             |number_of_inputs, randomizer| {
                 Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
             },
-        );
+        )?;
 
         let genome = Genome::new(1, neural_network);
 
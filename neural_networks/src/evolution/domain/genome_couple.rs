@@ -1,5 +1,6 @@
 use evolution::domain::genome::GenomeTrait;
 use neural_network::NeuralNetworkTrait;
+use neural_networks_error::NeuralNetworksError;
 use neuron::NeuronTrait;
 use std::marker::PhantomData;
 
@@ -25,7 +26,7 @@ impl<
     pub fn new(
         first_parent: &'a T,
         second_parent: &'a T,
-    ) -> Result<GenomeCouple<'a, T, U, V>, String> {
+    ) -> Result<GenomeCouple<'a, T, U, V>, NeuralNetworksError> {
         Ok(GenomeCouple {
             first_parent,
             second_parent,
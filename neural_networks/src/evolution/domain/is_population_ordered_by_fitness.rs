@@ -2,6 +2,7 @@ use evolution::domain::genome::GenomeTrait;
 use evolution::domain::population::Population;
 use evolution::domain::population::PopulationTrait;
 use neural_network::NeuralNetworkTrait;
+use neural_networks_error::NeuralNetworksError;
 use neuron::NeuronTrait;
 
 pub fn is_population_ordered_by_fitness<
@@ -10,7 +11,7 @@ pub fn is_population_ordered_by_fitness<
     V: NeuronTrait + Clone,
 >(
     population: &Population<T, U, V>,
-) -> Result<bool, String> {
+) -> Result<bool, NeuralNetworksError> {
     let highest_fitness = population.get_genomes()?[0].get_fitness();
 
     for genome in population.get_genomes()?.iter() {
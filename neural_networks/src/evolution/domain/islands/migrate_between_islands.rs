@@ -0,0 +1,161 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use evolution::domain::genome::GenomeTrait;
+use evolution::domain::islands::migration_policy::MigrationPolicy;
+use evolution::domain::islands::migration_topologies::MigrationTopologies;
+use evolution::domain::population::Population;
+use evolution::domain::population::PopulationTrait;
+use evolution::domain::selection::sort_indexes_by_fitness::sort_indexes_by_fitness;
+use neural_network::NeuralNetworkTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::NeuronTrait;
+
+// Copies the best genomes of each island over the worst ones of its destination. All the migrants
+// get picked before any island changes, so a genome can't travel twice in the same migration.
+pub fn migrate_between_islands<
+    T: GenomeTrait<U, V> + Clone,
+    U: NeuralNetworkTrait<V> + Clone,
+    V: NeuronTrait + Clone,
+    W: RandomizerTrait,
+>(
+    islands: &mut [Population<T, U, V>],
+    migration_policy: &MigrationPolicy,
+    randomizer: &mut W,
+) -> Result<(), NeuralNetworksError> {
+    if islands.len() < 2 {
+        return Ok(());
+    }
+
+    let number_of_migrants = migration_policy.get_number_of_migrants() as usize;
+
+    if islands
+        .iter()
+        .any(|island| island.get_size() as usize <= number_of_migrants)
+    {
+        return Err(NeuralNetworksError::NotEnoughGenomesToMigrate { number_of_migrants });
+    }
+
+    let mut migrations = Vec::new();
+
+    for (origin, island) in islands.iter().enumerate() {
+        let destination = match migration_policy.get_topology() {
+            MigrationTopologies::Ring => (origin + 1) % islands.len(),
+            MigrationTopologies::Random => {
+                let other_islands: Vec<usize> = (0..islands.len())
+                    .filter(|index| *index != origin)
+                    .collect();
+
+                randomizer.choose_random_from_vec(&other_islands)
+            }
+        };
+
+        let genomes = island.get_genomes()?;
+
+        let migrants: Vec<T> = sort_indexes_by_fitness(genomes)
+            .iter()
+            .take(number_of_migrants)
+            .map(|index| genomes[*index].clone())
+            .collect();
+
+        migrations.push((destination, migrants));
+    }
+
+    for (destination, migrants) in migrations {
+        let genomes = islands[destination].get_genomes_mut()?;
+
+        let worst_indexes: Vec<usize> = sort_indexes_by_fitness(genomes)
+            .into_iter()
+            .rev()
+            .take(migrants.len())
+            .collect();
+
+        for (index, migrant) in worst_indexes.into_iter().zip(migrants) {
+            genomes[index] = migrant;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use evolution::domain::create_genome::create_genome;
+    use evolution::domain::genome::Genome;
+    use neural_network::NeuralNetwork;
+    use neuron::Neuron;
+
+    use self::randomization::randomizer::Randomizer;
+
+    #[test]
+    fn test_the_best_genomes_replace_the_worst_ones_of_the_next_island(
+    ) -> Result<(), NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+
+        let mut islands: Vec<Population<Genome<NeuralNetwork<Neuron>, Neuron>, _, _>> = (0..3)
+            .map(|island_index| {
+                let mut island = Population::new_with_specified_layers(
+                    4,
+                    &[[2, 1]],
+                    create_genome,
+                    &mut randomizer,
+                )
+                .unwrap();
+
+                // Fitnesses go from 'island_index * 10' to 'island_index * 10 + 3'.
+                for (index, genome) in island.get_genomes_mut().unwrap().iter_mut().enumerate() {
+                    genome.set_fitness((island_index * 10 + index) as f64);
+                }
+
+                island
+            })
+            .collect();
+
+        migrate_between_islands(
+            &mut islands,
+            &MigrationPolicy::new(MigrationTopologies::Ring, 1, 2),
+            &mut randomizer,
+        )?;
+
+        let fitnesses: Vec<Vec<f64>> = islands
+            .iter()
+            .map(|island| {
+                island
+                    .get_genomes()
+                    .unwrap()
+                    .iter()
+                    .map(|genome| genome.get_fitness())
+                    .collect()
+            })
+            .collect();
+
+        assert_eq!(fitnesses[0], vec![23.0, 22.0, 2.0, 3.0]);
+        assert_eq!(fitnesses[1], vec![3.0, 2.0, 12.0, 13.0]);
+        assert_eq!(fitnesses[2], vec![13.0, 12.0, 22.0, 23.0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_islands_too_small_for_the_migrants_are_rejected() -> Result<(), NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+
+        let mut islands: Vec<Population<Genome<NeuralNetwork<Neuron>, Neuron>, _, _>> = (0..2)
+            .map(|_| {
+                Population::new_with_specified_layers(2, &[[2, 1]], create_genome, &mut randomizer)
+                    .unwrap()
+            })
+            .collect();
+
+        assert!(migrate_between_islands(
+            &mut islands,
+            &MigrationPolicy::new(MigrationTopologies::Random, 1, 2),
+            &mut randomizer,
+        )
+        .is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+extern crate serde;
+
+use self::serde::{Deserialize, Serialize};
+use evolution::domain::islands::migration_topologies::MigrationTopologies;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MigrationPolicy {
+    topology: MigrationTopologies,
+    interval: u32,
+    number_of_migrants: u32,
+}
+
+impl Default for MigrationPolicy {
+    fn default() -> Self {
+        MigrationPolicy {
+            topology: MigrationTopologies::Ring,
+            interval: 5,
+            number_of_migrants: 1,
+        }
+    }
+}
+
+impl MigrationPolicy {
+    // Every 'interval' generations, the best 'number_of_migrants' genomes of each island get
+    // copied into another island, following the topology.
+    pub fn new(topology: MigrationTopologies, interval: u32, number_of_migrants: u32) -> Self {
+        MigrationPolicy {
+            topology,
+            interval: interval.max(1),
+            number_of_migrants,
+        }
+    }
+
+    pub fn get_topology(&self) -> &MigrationTopologies {
+        &self.topology
+    }
+
+    pub fn get_interval(&self) -> u32 {
+        self.interval
+    }
+
+    pub fn get_number_of_migrants(&self) -> u32 {
+        self.number_of_migrants
+    }
+
+    // Generations are counted from zero, so the first migration happens once 'interval'
+    // generations have been trained.
+    pub fn is_migration_due(&self, generation: u32) -> bool {
+        (generation + 1).is_multiple_of(self.interval)
+    }
+}
@@ -0,0 +1,11 @@
+extern crate serde;
+
+use self::serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum MigrationTopologies {
+    // Each island sends its migrants to the next one, and the last island to the first.
+    Ring,
+    // Each island sends its migrants to another island picked at random.
+    Random,
+}
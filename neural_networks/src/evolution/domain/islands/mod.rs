@@ -0,0 +1,3 @@
+pub mod migrate_between_islands;
+pub mod migration_policy;
+pub mod migration_topologies;
@@ -1,4 +1,5 @@
 use layer::Layer;
+use neural_networks_error::NeuralNetworksError;
 use neuron::NeuronTrait;
 
 pub struct LayerCouple<'a, T: NeuronTrait> {
@@ -10,7 +11,7 @@ impl<T: NeuronTrait> LayerCouple<'_, T> {
     pub fn new<'a>(
         first_parent: &'a Layer<T>,
         second_parent: &'a Layer<T>,
-    ) -> Result<LayerCouple<'a, T>, String> {
+    ) -> Result<LayerCouple<'a, T>, NeuralNetworksError> {
         Ok(LayerCouple {
             first_parent,
             second_parent,
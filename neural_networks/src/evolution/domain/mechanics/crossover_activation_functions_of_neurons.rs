@@ -4,6 +4,8 @@ use evolution::domain::layer_couple::LayerCouple;
 use layer::Layer;
 use layer::LayerTrait;
 
+use neural_networks_error::NeuralNetworksError;
+use neuron::MutableNeuronTrait;
 use neuron::NeuronTrait;
 
 pub fn crossover_activation_functions_of_neurons<T: NeuronTrait, U: RandomizerTrait>(
@@ -11,9 +13,10 @@ pub fn crossover_activation_functions_of_neurons<T: NeuronTrait, U: RandomizerTr
     first_child: &mut Layer<T>,
     second_child: &mut Layer<T>,
     index: usize,
+    crossover_rate: f64,
     randomizer: &mut U,
-) -> Result<(), String> {
-    if Layer::<T>::should_crossover(randomizer)? {
+) -> Result<(), NeuralNetworksError> {
+    if Layer::<T>::should_crossover(crossover_rate, randomizer)? {
         first_child.get_neuron_mut(index)?.set_activation_function(
             *layer_couple
                 .get_second_parent()
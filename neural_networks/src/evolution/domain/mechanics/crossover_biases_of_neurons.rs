@@ -4,6 +4,7 @@ use evolution::domain::layer_couple::LayerCouple;
 use layer::Layer;
 use layer::LayerTrait;
 
+use neural_networks_error::NeuralNetworksError;
 use neuron::NeuronTrait;
 
 pub fn crossover_biases_of_neurons<T: NeuronTrait, U: RandomizerTrait>(
@@ -11,34 +12,39 @@ pub fn crossover_biases_of_neurons<T: NeuronTrait, U: RandomizerTrait>(
     first_child: &mut Layer<T>,
     second_child: &mut Layer<T>,
     index: usize,
+    crossover_rate: f64,
     randomizer: &mut U,
-) -> Result<(), String> {
-    if Layer::<T>::should_crossover(randomizer)? {
-        first_child.get_neuron_mut(index)?.set_bias(
+) -> Result<(), NeuralNetworksError> {
+    if Layer::<T>::should_crossover(crossover_rate, randomizer)? {
+        first_child.set_neuron_bias(
+            index,
             layer_couple
                 .get_second_parent()
                 .get_neuron(index)?
                 .get_bias(),
-        );
-        second_child.get_neuron_mut(index)?.set_bias(
+        )?;
+        second_child.set_neuron_bias(
+            index,
             layer_couple
                 .get_first_parent()
                 .get_neuron(index)?
                 .get_bias(),
-        );
+        )?;
     } else {
-        first_child.get_neuron_mut(index)?.set_bias(
+        first_child.set_neuron_bias(
+            index,
             layer_couple
                 .get_first_parent()
                 .get_neuron(index)?
                 .get_bias(),
-        );
-        second_child.get_neuron_mut(index)?.set_bias(
+        )?;
+        second_child.set_neuron_bias(
+            index,
             layer_couple
                 .get_second_parent()
                 .get_neuron(index)?
                 .get_bias(),
-        );
+        )?;
     }
 
     Ok(())
@@ -1,18 +1,20 @@
 extern crate randomization;
 
 use self::randomization::randomizer::RandomizerTrait;
+use evolution::domain::evolution_config::EvolutionConfig;
 use evolution::domain::genome::GenomeTrait;
 use evolution::domain::genome_couple::GenomeCouple;
 use evolution::domain::layer_couple::LayerCouple;
 use evolution::domain::mechanics::crossover_layers::crossover_layers;
-use neural_network::NeuralNetworkTrait;
+use neural_network::LayeredNeuralNetworkTrait;
+use neural_networks_error::NeuralNetworksError;
 use neuron::NeuronTrait;
 
-type ResultCrossoverGenomes<T> = Result<(T, T), String>;
+type ResultCrossoverGenomes<T> = Result<(T, T), NeuralNetworksError>;
 
 pub fn crossover_genomes<
     T: GenomeTrait<U, V> + Clone,
-    U: NeuralNetworkTrait<V> + Clone,
+    U: LayeredNeuralNetworkTrait<V> + Clone,
     V: NeuronTrait + Clone,
     W: RandomizerTrait,
     X: Fn(u32, U) -> T,
@@ -23,6 +25,7 @@ pub fn crossover_genomes<
     genome_creator: &X,
     neural_network_creator: &Y,
     neuron_creator: Z,
+    evolution_config: &EvolutionConfig,
     randomizer: &mut W,
 ) -> ResultCrossoverGenomes<T> {
     let mut first_child = neural_network_creator();
@@ -43,6 +46,7 @@ pub fn crossover_genomes<
     {
         let (c1, c2) = crossover_layers(
             LayerCouple::new(first_layer, second_layer)?,
+            evolution_config,
             randomizer,
             &neuron_creator,
         )?;
@@ -50,6 +54,9 @@ pub fn crossover_genomes<
         second_child.add(c2)?;
     }
 
+    first_child.inherit_from(couple.get_first_parent().get_neural_network())?;
+    second_child.inherit_from(couple.get_second_parent().get_neural_network())?;
+
     // Note: the 0 as an identifier is because the identifiers can't be set here.
     // A later process should set the identifiers for the whole population.
     Ok((
@@ -57,3 +64,66 @@ pub fn crossover_genomes<
         genome_creator(0, second_child),
     ))
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use evolution::domain::genome::Genome;
+    use named_io::io_schema::IoSchema;
+    use named_io::named_value::NamedValue;
+    use neural_network::NeuralNetwork;
+    use neuron::Neuron;
+    use neuron_activation::activation_functions::ActivationFunctions;
+
+    use self::randomization::randomizer::Randomizer;
+
+    #[test]
+    fn test_children_keep_the_io_schema_of_their_parents() -> Result<(), NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+        let neuron_creator = |number_of_inputs, randomizer: &mut Randomizer| {
+            Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+        };
+
+        let io_schema = IoSchema::new(
+            vec![
+                NamedValue::new("Hunger", 0.0, 1.0),
+                NamedValue::new("FoodNearby", 0.0, 1.0),
+            ],
+            vec![NamedValue::new_unbounded("Eat")],
+        )?;
+
+        let mut parents = Vec::new();
+
+        for identifier in 1..=2 {
+            let mut neural_network = NeuralNetwork::new_with_specified_layers(
+                &[[2, 3], [3, 1]],
+                &mut randomizer,
+                neuron_creator,
+            )?;
+            neural_network.set_io_schema(io_schema.clone())?;
+
+            parents.push(Genome::new(identifier, neural_network));
+        }
+
+        let (first_child, second_child) = crossover_genomes(
+            GenomeCouple::new(&parents[0], &parents[1])?,
+            &Genome::new,
+            &NeuralNetwork::new,
+            neuron_creator,
+            &EvolutionConfig::new(),
+            &mut randomizer,
+        )?;
+
+        assert_eq!(
+            first_child.get_neural_network().get_io_schema(),
+            Some(&io_schema)
+        );
+        assert_eq!(
+            second_child.get_neural_network().get_io_schema(),
+            Some(&io_schema)
+        );
+
+        Ok(())
+    }
+}
@@ -1,52 +1,179 @@
 extern crate randomization;
 use self::randomization::randomizer::RandomizerTrait;
+use evolution::domain::crossover::arithmetic_blend_crossover::ArithmeticBlendCrossover;
+use evolution::domain::crossover::crossover_operator_trait::CrossoverOperatorTrait;
+use evolution::domain::crossover::gene_swap_crossover::GeneSwapCrossover;
+use evolution::domain::crossover::layer_swap_crossover::LayerSwapCrossover;
+use evolution::domain::crossover::neuron_swap_crossover::NeuronSwapCrossover;
+use evolution::domain::crossover::single_point_crossover::SinglePointCrossover;
+use evolution::domain::crossover::two_point_crossover::TwoPointCrossover;
+use evolution::domain::crossover::uniform_crossover::UniformCrossover;
+use evolution::domain::crossover_operators::CrossoverOperators;
+use evolution::domain::evolution_config::EvolutionConfig;
 use evolution::domain::layer_couple::LayerCouple;
-use evolution::domain::mechanics::crossover_activation_functions_of_neurons::crossover_activation_functions_of_neurons;
-use evolution::domain::mechanics::crossover_biases_of_neurons::crossover_biases_of_neurons;
-use evolution::domain::mechanics::crossover_weights_of_neurons::crossover_weights_of_neurons;
 use evolution::domain::mechanics::produce_child_for_crossover::produce_child_for_crossover;
 use layer::Layer;
-use layer::LayerTrait;
+use neural_networks_error::NeuralNetworksError;
 use neuron::NeuronTrait;
 
 pub fn crossover_layers<T: NeuronTrait, U: RandomizerTrait, V: Fn(u32, &mut U) -> T>(
     layer_couple: LayerCouple<T>,
+    evolution_config: &EvolutionConfig,
     randomizer: &mut U,
     neuron_creator: &V,
-) -> Result<(Layer<T>, Layer<T>), String> {
+) -> Result<(Layer<T>, Layer<T>), NeuralNetworksError> {
     let mut first_child =
         produce_child_for_crossover(layer_couple.get_first_parent(), randomizer, neuron_creator)?;
     let mut second_child =
         produce_child_for_crossover(layer_couple.get_first_parent(), randomizer, neuron_creator)?;
 
-    // Cannot iter() over here since destructuring assignments are not allowed
-    // https://github.com/rust-lang/rfcs/issues/372
+    let crossover_rate = evolution_config.get_crossover_rate();
 
-    for index in 0..layer_couple.get_first_parent().get_number_of_neurons() as usize {
-        crossover_biases_of_neurons(
+    match *evolution_config.get_crossover_operator() {
+        CrossoverOperators::GeneSwap => GeneSwapCrossover::new().crossover(
             &layer_couple,
             &mut first_child,
             &mut second_child,
-            index,
+            crossover_rate,
             randomizer,
-        )?;
-
-        crossover_weights_of_neurons(
+        )?,
+        CrossoverOperators::Uniform => UniformCrossover::new().crossover(
             &layer_couple,
             &mut first_child,
             &mut second_child,
-            index,
+            crossover_rate,
             randomizer,
-        )?;
-
-        crossover_activation_functions_of_neurons(
+        )?,
+        CrossoverOperators::SinglePoint => SinglePointCrossover::new().crossover(
             &layer_couple,
             &mut first_child,
             &mut second_child,
-            index,
+            crossover_rate,
             randomizer,
-        )?;
+        )?,
+        CrossoverOperators::TwoPoint => TwoPointCrossover::new().crossover(
+            &layer_couple,
+            &mut first_child,
+            &mut second_child,
+            crossover_rate,
+            randomizer,
+        )?,
+        CrossoverOperators::NeuronSwap => NeuronSwapCrossover::new().crossover(
+            &layer_couple,
+            &mut first_child,
+            &mut second_child,
+            crossover_rate,
+            randomizer,
+        )?,
+        CrossoverOperators::LayerSwap => LayerSwapCrossover::new().crossover(
+            &layer_couple,
+            &mut first_child,
+            &mut second_child,
+            crossover_rate,
+            randomizer,
+        )?,
+        CrossoverOperators::ArithmeticBlend { alpha } => ArithmeticBlendCrossover::new(alpha)
+            .crossover(
+                &layer_couple,
+                &mut first_child,
+                &mut second_child,
+                crossover_rate,
+                randomizer,
+            )?,
     }
 
     Ok((first_child, second_child))
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use layer::LayerTrait;
+    use neuron::Neuron;
+    use neuron_activation::activation_functions::ActivationFunctions;
+    use output_transformation::output_transforms::OutputTransforms;
+    use recurrence::recurrences::Recurrences;
+
+    use self::randomization::randomizer::Randomizer;
+
+    #[test]
+    fn test_children_of_recurrent_layers_inherit_the_recurrent_weights(
+    ) -> Result<(), NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+        let neuron_creator = |number_of_inputs, randomizer: &mut Randomizer| {
+            Neuron::new(number_of_inputs, ActivationFunctions::Tanh, randomizer)
+        };
+
+        let first_parent = Layer::create_recurrent_layer(
+            3,
+            2,
+            Recurrences::Lstm,
+            &mut randomizer,
+            neuron_creator,
+        )?;
+        let second_parent = Layer::create_recurrent_layer(
+            3,
+            2,
+            Recurrences::Lstm,
+            &mut randomizer,
+            neuron_creator,
+        )?;
+
+        let mut evolution_config = EvolutionConfig::new();
+        evolution_config.set_crossover_operator(CrossoverOperators::GeneSwap);
+
+        let (first_child, second_child) = crossover_layers(
+            LayerCouple::new(&first_parent, &second_parent)?,
+            &evolution_config,
+            &mut randomizer,
+            &neuron_creator,
+        )?;
+
+        for child in &[&first_child, &second_child] {
+            assert_eq!(child.get_recurrence(), &Recurrences::Lstm);
+            assert_eq!(child.get_number_of_outputs(), 2);
+            assert_eq!(child.get_weights().len(), first_parent.get_weights().len());
+        }
+
+        // Every recurrent weight came from one of the parents.
+        for (index, weight) in first_child.get_weights().iter().enumerate() {
+            assert!(
+                *weight == first_parent.get_weights()[index]
+                    || *weight == second_parent.get_weights()[index]
+            );
+            assert_eq!(
+                *weight + second_child.get_weights()[index],
+                first_parent.get_weights()[index] + second_parent.get_weights()[index]
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_children_of_softmax_layers_keep_the_softmax() -> Result<(), NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+        let neuron_creator = |number_of_inputs, randomizer: &mut Randomizer| {
+            Neuron::new(number_of_inputs, ActivationFunctions::Identity, randomizer)
+        };
+
+        let mut first_parent = Layer::create_layer(3, 4, &mut randomizer, neuron_creator)?;
+        let mut second_parent = Layer::create_layer(3, 4, &mut randomizer, neuron_creator)?;
+        first_parent.set_output_transform(OutputTransforms::Softmax);
+        second_parent.set_output_transform(OutputTransforms::Softmax);
+
+        let (first_child, second_child) = crossover_layers(
+            LayerCouple::new(&first_parent, &second_parent)?,
+            &EvolutionConfig::new(),
+            &mut randomizer,
+            &neuron_creator,
+        )?;
+
+        for child in &[&first_child, &second_child] {
+            assert_eq!(child.get_output_transform(), &OutputTransforms::Softmax);
+        }
+
+        Ok(())
+    }
+}
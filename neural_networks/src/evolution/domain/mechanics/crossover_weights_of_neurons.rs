@@ -3,6 +3,7 @@ use self::randomization::randomizer::RandomizerTrait;
 use evolution::domain::layer_couple::LayerCouple;
 use layer::Layer;
 use layer::LayerTrait;
+use neural_networks_error::NeuralNetworksError;
 use neuron::NeuronTrait;
 
 pub fn crossover_weights_of_neurons<T: NeuronTrait, U: RandomizerTrait>(
@@ -10,18 +11,24 @@ pub fn crossover_weights_of_neurons<T: NeuronTrait, U: RandomizerTrait>(
     first_child: &mut Layer<T>,
     second_child: &mut Layer<T>,
     index: usize,
+    crossover_rate: f64,
     randomizer: &mut U,
-) -> Result<(), String> {
-    for j in 0..layer_couple.get_first_parent().get_number_of_inputs() as usize {
-        if Layer::<T>::should_crossover(randomizer)? {
-            first_child.get_neuron_mut(index)?.set_weight(
+) -> Result<(), NeuralNetworksError> {
+    for j in 0..layer_couple
+        .get_first_parent()
+        .get_number_of_weights_per_neuron() as usize
+    {
+        if Layer::<T>::should_crossover(crossover_rate, randomizer)? {
+            first_child.set_neuron_weight(
+                index,
                 j,
                 layer_couple
                     .get_second_parent()
                     .get_neuron(index)?
                     .get_weight(j)?,
             )?;
-            second_child.get_neuron_mut(index)?.set_weight(
+            second_child.set_neuron_weight(
+                index,
                 j,
                 layer_couple
                     .get_first_parent()
@@ -29,14 +36,16 @@ pub fn crossover_weights_of_neurons<T: NeuronTrait, U: RandomizerTrait>(
                     .get_weight(j)?,
             )?;
         } else {
-            first_child.get_neuron_mut(index)?.set_weight(
+            first_child.set_neuron_weight(
+                index,
                 j,
                 layer_couple
                     .get_first_parent()
                     .get_neuron(index)?
                     .get_weight(j)?,
             )?;
-            second_child.get_neuron_mut(index)?.set_weight(
+            second_child.set_neuron_weight(
+                index,
                 j,
                 layer_couple
                     .get_second_parent()
@@ -1,24 +1,69 @@
 extern crate randomization;
 
 use self::randomization::randomizer::RandomizerTrait;
+use evolution::domain::evolution_config::EvolutionConfig;
 use evolution::domain::genome::GenomeTrait;
+use evolution::domain::mutation::gaussian_perturbation::GaussianPerturbation;
+use evolution::domain::mutation::mutation_operator_trait::MutationOperatorTrait;
+use evolution::domain::mutation::reset_to_random::ResetToRandom;
+use evolution::domain::mutation::uniform_perturbation::UniformPerturbation;
+use evolution::domain::mutation::weight_swap::WeightSwap;
+use evolution::domain::mutation_operators::MutationOperators;
 use layer::LayerTrait;
-use neural_network::NeuralNetworkTrait;
+use neural_network::LayeredNeuralNetworkTrait;
+use neural_networks_error::NeuralNetworksError;
 use neuron::NeuronTrait;
 
 pub fn mutate_genome<
     T: GenomeTrait<U, V>,
-    U: NeuralNetworkTrait<V> + Clone,
+    U: LayeredNeuralNetworkTrait<V> + Clone,
     V: NeuronTrait + Clone,
     W: RandomizerTrait,
 >(
     genome: &mut T,
+    evolution_config: &EvolutionConfig,
     randomizer: &mut W,
-) -> Result<(), String> {
+) -> Result<(), NeuralNetworksError> {
+    let mutation_rate = evolution_config.get_mutation_rate();
+
+    match *evolution_config.get_mutation_operator() {
+        MutationOperators::ResetToRandom => {
+            mutate_neurons(genome, &ResetToRandom::new(), mutation_rate, randomizer)
+        }
+        MutationOperators::GaussianPerturbation { sigma } => mutate_neurons(
+            genome,
+            &GaussianPerturbation::new(sigma),
+            mutation_rate,
+            randomizer,
+        ),
+        MutationOperators::UniformPerturbation { range } => mutate_neurons(
+            genome,
+            &UniformPerturbation::new(range),
+            mutation_rate,
+            randomizer,
+        ),
+        MutationOperators::WeightSwap => {
+            mutate_neurons(genome, &WeightSwap::new(), mutation_rate, randomizer)
+        }
+    }
+}
+
+fn mutate_neurons<
+    T: GenomeTrait<U, V>,
+    U: LayeredNeuralNetworkTrait<V> + Clone,
+    V: NeuronTrait + Clone,
+    W: RandomizerTrait,
+    X: MutationOperatorTrait,
+>(
+    genome: &mut T,
+    mutation_operator: &X,
+    mutation_rate: f64,
+    randomizer: &mut W,
+) -> Result<(), NeuralNetworksError> {
     // This only delegates to the neural network.
     for layer in genome.get_neural_network_mut().get_layers_mut().iter_mut() {
-        for neuron in layer.get_neurons_mut() {
-            neuron.mutate(randomizer)?;
+        for neuron in layer.get_neurons_mut().iter_mut() {
+            mutation_operator.mutate(neuron, mutation_rate, randomizer)?;
         }
     }
 
@@ -3,17 +3,23 @@ use self::randomization::randomizer::RandomizerTrait;
 use layer::LayerTrait;
 
 use layer::Layer;
+use neural_networks_error::NeuralNetworksError;
 use neuron::NeuronTrait;
 
 pub fn produce_child_for_crossover<T: NeuronTrait, U: RandomizerTrait, V: Fn(u32, &mut U) -> T>(
     parent: &Layer<T>,
     randomizer: &mut U,
     neuron_creator: &V,
-) -> Result<Layer<T>, String> {
-    Ok(Layer::<T>::create_layer(
+) -> Result<Layer<T>, NeuralNetworksError> {
+    let mut child = Layer::<T>::create_recurrent_layer(
         parent.get_number_of_inputs(),
-        parent.get_number_of_neurons() as u32,
+        parent.get_number_of_outputs(),
+        *parent.get_recurrence(),
         randomizer,
         neuron_creator,
-    ))
+    )?;
+
+    child.set_output_transform(*parent.get_output_transform());
+
+    Ok(child)
 }
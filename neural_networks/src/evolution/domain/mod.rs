@@ -1,8 +1,19 @@
 pub mod constants;
 pub mod create_genome;
+#[cfg(test)]
+pub mod create_genomes_with_fitnesses;
+pub mod crossover;
+pub mod crossover_operators;
+pub mod evolution_config;
 pub mod genome;
 pub mod genome_couple;
 pub mod is_population_ordered_by_fitness;
+pub mod islands;
 pub mod layer_couple;
 pub mod mechanics;
+pub mod multi_objective;
+pub mod mutation;
+pub mod mutation_operators;
+pub mod novelty;
 pub mod population;
+pub mod selection;
@@ -0,0 +1,46 @@
+use evolution::domain::genome::GenomeTrait;
+use evolution::domain::multi_objective::compute_crowding_distances::compute_crowding_distances;
+use evolution::domain::multi_objective::sort_into_non_dominated_fronts::sort_into_non_dominated_fronts;
+use neural_network::NeuralNetworkTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::NeuronTrait;
+
+// Encodes the front of each genome and its crowding distance into its fitness, so the selection
+// strategies (a tournament of two, in NSGA-II) prefer better fronts first and less crowded
+// genomes second. The crowding distance only ever adds up to half a point, so it can't make up
+// for a worse front.
+pub fn assign_rank_and_crowding_as_fitness<
+    T: GenomeTrait<U, V>,
+    U: NeuralNetworkTrait<V>,
+    V: NeuronTrait,
+>(
+    genomes: &mut [T],
+    objectives: &[Vec<f64>],
+) -> Result<(), NeuralNetworksError> {
+    if genomes.len() != objectives.len() {
+        return Err(NeuralNetworksError::ObjectivesDontMatchGenomes {
+            number_of_objectives: objectives.len(),
+            number_of_genomes: genomes.len(),
+        });
+    }
+
+    for (rank, front) in sort_into_non_dominated_fronts(objectives)
+        .iter()
+        .enumerate()
+    {
+        for (index, crowding_distance) in front
+            .iter()
+            .zip(compute_crowding_distances(objectives, front))
+        {
+            let crowding_bonus = if crowding_distance.is_infinite() {
+                0.5
+            } else {
+                0.5 * crowding_distance / (1.0 + crowding_distance)
+            };
+
+            genomes[*index].set_fitness(-(rank as f64) + crowding_bonus);
+        }
+    }
+
+    Ok(())
+}
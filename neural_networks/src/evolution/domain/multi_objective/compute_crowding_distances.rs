@@ -0,0 +1,65 @@
+extern crate float_ord;
+
+use self::float_ord::FloatOrd;
+
+// How far each solution of a front lies from its neighbours along every objective, so that
+// solutions in less crowded regions can be preferred. The solutions at the extremes of any
+// objective get an infinite distance. The distances come in the same order as the front.
+pub fn compute_crowding_distances(objectives: &[Vec<f64>], front: &[usize]) -> Vec<f64> {
+    let mut distances = vec![0.0; front.len()];
+
+    if front.is_empty() {
+        return distances;
+    }
+
+    for objective in 0..objectives[front[0]].len() {
+        let values: Vec<f64> = front
+            .iter()
+            .map(|index| objectives[*index].get(objective).copied().unwrap_or(0.0))
+            .collect();
+
+        let mut positions: Vec<usize> = (0..front.len()).collect();
+        positions.sort_by_key(|position| FloatOrd(values[*position]));
+
+        let lowest = values[positions[0]];
+        let highest = values[positions[positions.len() - 1]];
+
+        distances[positions[0]] = f64::INFINITY;
+        distances[positions[positions.len() - 1]] = f64::INFINITY;
+
+        if highest == lowest {
+            continue;
+        }
+
+        for window in positions.windows(3) {
+            distances[window[1]] += (values[window[2]] - values[window[0]]) / (highest - lowest);
+        }
+    }
+
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_the_extremes_are_infinitely_far_and_the_rest_by_their_neighbours() {
+        let objectives = vec![
+            vec![0.0, 4.0],
+            vec![9.0, 9.0],
+            vec![1.0, 3.0],
+            vec![4.0, 0.0],
+            vec![3.0, 1.0],
+        ];
+
+        let distances = compute_crowding_distances(&objectives, &[0, 2, 4, 3]);
+
+        assert_eq!(distances[0], f64::INFINITY);
+        assert_eq!(distances[3], f64::INFINITY);
+        // Neighbours of [1, 3] are [0, 4] and [3, 1]: 3 / 4 for each objective.
+        assert_eq!(distances[1], 1.5);
+        assert_eq!(distances[2], 1.5);
+    }
+}
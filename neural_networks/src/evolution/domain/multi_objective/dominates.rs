@@ -0,0 +1,19 @@
+// Every objective is maximized. The first solution dominates the second one when it is at least as
+// good in every objective, and strictly better in at least one.
+pub fn dominates(first: &[f64], second: &[f64]) -> bool {
+    first.iter().zip(second.iter()).all(|(a, b)| a >= b)
+        && first.iter().zip(second.iter()).any(|(a, b)| a > b)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_dominance_requires_being_strictly_better_somewhere() {
+        assert!(dominates(&[2.0, 1.0], &[1.0, 1.0]));
+        assert!(!dominates(&[1.0, 1.0], &[1.0, 1.0]));
+        assert!(!dominates(&[2.0, 0.0], &[1.0, 1.0]));
+    }
+}
@@ -0,0 +1,6 @@
+pub mod assign_rank_and_crowding_as_fitness;
+pub mod compute_crowding_distances;
+pub mod dominates;
+pub mod pareto_front;
+pub mod select_survivors_by_rank_and_crowding;
+pub mod sort_into_non_dominated_fronts;
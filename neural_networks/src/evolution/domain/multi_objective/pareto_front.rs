@@ -0,0 +1,78 @@
+extern crate serde;
+
+use self::serde::{Deserialize, Serialize};
+use neural_networks_error::NeuralNetworksError;
+
+// The genomes no other genome dominates, along with their objectives. Multi-objective training
+// returns this instead of a single winner.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParetoFront<T> {
+    objective_names: Vec<String>,
+    genomes: Vec<T>,
+    objectives: Vec<Vec<f64>>,
+}
+
+impl<T> ParetoFront<T> {
+    pub fn new(
+        objective_names: Vec<String>,
+        genomes: Vec<T>,
+        objectives: Vec<Vec<f64>>,
+    ) -> Result<Self, NeuralNetworksError> {
+        if genomes.len() != objectives.len()
+            || objectives
+                .iter()
+                .any(|genome_objectives| genome_objectives.len() != objective_names.len())
+        {
+            return Err(NeuralNetworksError::MissingObjectiveValues {
+                number_of_genomes: genomes.len(),
+                objective_names,
+            });
+        }
+
+        Ok(ParetoFront {
+            objective_names,
+            genomes,
+            objectives,
+        })
+    }
+
+    pub fn get_objective_names(&self) -> &Vec<String> {
+        &self.objective_names
+    }
+
+    pub fn get_genomes(&self) -> &Vec<T> {
+        &self.genomes
+    }
+
+    pub fn get_objectives(&self, index: usize) -> Result<&Vec<f64>, NeuralNetworksError> {
+        match self.objectives.get(index) {
+            Some(objectives) => Ok(objectives),
+            None => Err(NeuralNetworksError::GenomeOutOfRange {
+                index,
+                number_of_genomes: self.genomes.len(),
+            }),
+        }
+    }
+
+    pub fn get_objective(
+        &self,
+        index: usize,
+        objective_name: &str,
+    ) -> Result<f64, NeuralNetworksError> {
+        match self
+            .objective_names
+            .iter()
+            .position(|name| name == objective_name)
+        {
+            Some(position) => Ok(self.get_objectives(index)?[position]),
+            None => Err(NeuralNetworksError::UnknownObjective {
+                name: objective_name.to_string(),
+                objective_names: self.objective_names.clone(),
+            }),
+        }
+    }
+
+    pub fn get_size(&self) -> usize {
+        self.genomes.len()
+    }
+}
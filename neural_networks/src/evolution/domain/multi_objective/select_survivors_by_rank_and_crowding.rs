@@ -0,0 +1,70 @@
+extern crate float_ord;
+
+use self::float_ord::FloatOrd;
+use evolution::domain::multi_objective::compute_crowding_distances::compute_crowding_distances;
+use evolution::domain::multi_objective::sort_into_non_dominated_fronts::sort_into_non_dominated_fronts;
+use std::cmp::Reverse;
+
+// NSGA-II's environmental selection: whole fronts get in while they fit, and the front that
+// doesn't fit gets cut down keeping its least crowded solutions.
+pub fn select_survivors_by_rank_and_crowding(
+    objectives: &[Vec<f64>],
+    number_of_survivors: usize,
+) -> Vec<usize> {
+    let mut survivors = Vec::with_capacity(number_of_survivors);
+
+    for front in sort_into_non_dominated_fronts(objectives) {
+        if survivors.len() + front.len() <= number_of_survivors {
+            survivors.extend(front);
+        } else {
+            let crowding_distances = compute_crowding_distances(objectives, &front);
+
+            let mut positions: Vec<usize> = (0..front.len()).collect();
+            positions.sort_by_key(|position| Reverse(FloatOrd(crowding_distances[*position])));
+
+            survivors.extend(
+                positions
+                    .iter()
+                    .take(number_of_survivors - survivors.len())
+                    .map(|position| front[*position]),
+            );
+        }
+
+        if survivors.len() == number_of_survivors {
+            break;
+        }
+    }
+
+    survivors
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_better_fronts_and_less_crowded_solutions_survive() {
+        let objectives = vec![
+            vec![0.0, 0.0],
+            vec![4.0, 0.0],
+            vec![0.0, 4.0],
+            vec![2.0, 2.0],
+            vec![2.1, 1.9],
+            vec![1.0, 1.0],
+        ];
+
+        // The first front is {1, 2, 3, 4}, the second {5} and the last {0}.
+        let mut survivors = select_survivors_by_rank_and_crowding(&objectives, 5);
+        survivors.sort();
+
+        assert_eq!(survivors, vec![1, 2, 3, 4, 5]);
+
+        // The first front doesn't fit anymore. Its extremes are kept, and [2.1, 1.9] is more
+        // crowded than [2, 2].
+        let mut survivors = select_survivors_by_rank_and_crowding(&objectives, 3);
+        survivors.sort();
+
+        assert_eq!(survivors, vec![1, 2, 3]);
+    }
+}
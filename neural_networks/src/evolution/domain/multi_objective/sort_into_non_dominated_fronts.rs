@@ -0,0 +1,67 @@
+use evolution::domain::multi_objective::dominates::dominates;
+
+// Fast non-dominated sorting (Deb et al., 2002). The first front holds the indexes of the
+// solutions no other one dominates, the second front those only dominated by the first front,
+// and so on.
+pub fn sort_into_non_dominated_fronts(objectives: &[Vec<f64>]) -> Vec<Vec<usize>> {
+    let mut dominated_solutions: Vec<Vec<usize>> = vec![Vec::new(); objectives.len()];
+    let mut domination_counts = vec![0; objectives.len()];
+    let mut fronts: Vec<Vec<usize>> = vec![Vec::new()];
+
+    for first in 0..objectives.len() {
+        for second in 0..objectives.len() {
+            if dominates(&objectives[first], &objectives[second]) {
+                dominated_solutions[first].push(second);
+            } else if dominates(&objectives[second], &objectives[first]) {
+                domination_counts[first] += 1;
+            }
+        }
+
+        if domination_counts[first] == 0 {
+            fronts[0].push(first);
+        }
+    }
+
+    while !fronts[fronts.len() - 1].is_empty() {
+        let mut next_front = Vec::new();
+
+        for solution in fronts[fronts.len() - 1].iter() {
+            for dominated in dominated_solutions[*solution].iter() {
+                domination_counts[*dominated] -= 1;
+
+                if domination_counts[*dominated] == 0 {
+                    next_front.push(*dominated);
+                }
+            }
+        }
+
+        fronts.push(next_front);
+    }
+
+    // The last front is always empty.
+    fronts.pop();
+
+    fronts
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_solutions_get_sorted_into_fronts() {
+        let objectives = vec![
+            vec![1.0, 1.0],
+            vec![3.0, 1.0],
+            vec![1.0, 3.0],
+            vec![2.0, 2.0],
+            vec![0.0, 0.0],
+        ];
+
+        let fronts = sort_into_non_dominated_fronts(&objectives);
+
+        assert_eq!(fronts, vec![vec![1, 2, 3], vec![0], vec![4]]);
+        assert!(sort_into_non_dominated_fronts(&[]).is_empty());
+    }
+}
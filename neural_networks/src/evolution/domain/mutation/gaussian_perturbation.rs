@@ -0,0 +1,88 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use evolution::domain::mutation::mutation_operator_trait::MutationOperatorTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::MutableNeuronTrait;
+
+// Nudges the mutated weights and biases by normally distributed noise, so what was learned
+// isn't thrown away.
+#[derive(Debug, Clone)]
+pub struct GaussianPerturbation {
+    sigma: f64,
+}
+
+impl GaussianPerturbation {
+    pub fn new(sigma: f64) -> Self {
+        GaussianPerturbation { sigma }
+    }
+}
+
+impl MutationOperatorTrait for GaussianPerturbation {
+    fn mutate<T: MutableNeuronTrait, U: RandomizerTrait>(
+        &self,
+        neuron: &mut T,
+        mutation_rate: f64,
+        randomizer: &mut U,
+    ) -> Result<(), NeuralNetworksError> {
+        if T::should_mutate(mutation_rate, randomizer)? {
+            let bias = neuron.get_bias() + self.sigma * randomizer.get_normal();
+            neuron.set_bias(bias);
+        }
+
+        for index in 0..neuron.get_number_of_weights() as usize {
+            if T::should_mutate(mutation_rate, randomizer)? {
+                let weight = neuron.get_weight(index)? + self.sigma * randomizer.get_normal();
+                neuron.set_weight(index, weight)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use neuron::Neuron;
+    use neuron::NeuronTrait;
+    use neuron_activation::activation_functions::ActivationFunctions;
+
+    struct FakeRandomizer {}
+
+    impl RandomizerTrait for FakeRandomizer {
+        fn get_normal(&mut self) -> f64 {
+            0.5_f64
+        }
+        fn generate_float_from_0_to_1(&mut self) -> f64 {
+            0.75_f64
+        }
+        fn choose_random_from_vec<T>(&mut self, _: &[T]) -> T
+        where
+            T: std::clone::Clone,
+        {
+            todo!()
+        }
+    }
+
+    #[test]
+    fn test_weights_and_biases_get_nudged_by_scaled_noise() -> Result<(), NeuralNetworksError> {
+        let mut randomizer = FakeRandomizer {};
+
+        let mut neuron = Neuron::new(2, ActivationFunctions::Sigmoid, &mut randomizer);
+
+        GaussianPerturbation::new(0.2).mutate(&mut neuron, 1.0, &mut randomizer)?;
+
+        assert!((neuron.get_bias() - 0.6).abs() < 1e-12);
+        assert!((neuron.get_weight(0)? - 0.6).abs() < 1e-12);
+        assert!((neuron.get_weight(1)? - 0.6).abs() < 1e-12);
+
+        // Nothing should change when the mutation rate is zero.
+        GaussianPerturbation::new(0.2).mutate(&mut neuron, 0.0, &mut randomizer)?;
+
+        assert!((neuron.get_bias() - 0.6).abs() < 1e-12);
+
+        Ok(())
+    }
+}
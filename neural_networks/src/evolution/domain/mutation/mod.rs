@@ -0,0 +1,5 @@
+pub mod gaussian_perturbation;
+pub mod mutation_operator_trait;
+pub mod reset_to_random;
+pub mod uniform_perturbation;
+pub mod weight_swap;
@@ -0,0 +1,16 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::MutableNeuronTrait;
+
+pub trait MutationOperatorTrait {
+    // Every weight and bias of the neuron gets the chance to mutate, with the mutation rate as
+    // its probability.
+    fn mutate<T: MutableNeuronTrait, U: RandomizerTrait>(
+        &self,
+        neuron: &mut T,
+        mutation_rate: f64,
+        randomizer: &mut U,
+    ) -> Result<(), NeuralNetworksError>;
+}
@@ -0,0 +1,28 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use evolution::domain::mutation::mutation_operator_trait::MutationOperatorTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::MutableNeuronTrait;
+
+// Replaces the mutated weights and biases with brand-new values, and may also pick a new
+// activation function. This is what the neurons do on their own.
+#[derive(Debug, Clone, Default)]
+pub struct ResetToRandom {}
+
+impl ResetToRandom {
+    pub fn new() -> Self {
+        ResetToRandom {}
+    }
+}
+
+impl MutationOperatorTrait for ResetToRandom {
+    fn mutate<T: MutableNeuronTrait, U: RandomizerTrait>(
+        &self,
+        neuron: &mut T,
+        mutation_rate: f64,
+        randomizer: &mut U,
+    ) -> Result<(), NeuralNetworksError> {
+        neuron.mutate(mutation_rate, randomizer)
+    }
+}
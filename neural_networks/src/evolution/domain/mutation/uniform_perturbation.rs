@@ -0,0 +1,78 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use evolution::domain::mutation::mutation_operator_trait::MutationOperatorTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::MutableNeuronTrait;
+
+// Nudges the mutated weights and biases by a value taken uniformly from [-range, range).
+#[derive(Debug, Clone)]
+pub struct UniformPerturbation {
+    range: f64,
+}
+
+impl UniformPerturbation {
+    pub fn new(range: f64) -> Self {
+        UniformPerturbation { range }
+    }
+
+    fn generate_perturbation<U: RandomizerTrait>(&self, randomizer: &mut U) -> f64 {
+        (randomizer.generate_float_from_0_to_1() * 2.0 - 1.0) * self.range
+    }
+}
+
+impl MutationOperatorTrait for UniformPerturbation {
+    fn mutate<T: MutableNeuronTrait, U: RandomizerTrait>(
+        &self,
+        neuron: &mut T,
+        mutation_rate: f64,
+        randomizer: &mut U,
+    ) -> Result<(), NeuralNetworksError> {
+        if T::should_mutate(mutation_rate, randomizer)? {
+            let bias = neuron.get_bias() + self.generate_perturbation(randomizer);
+            neuron.set_bias(bias);
+        }
+
+        for index in 0..neuron.get_number_of_weights() as usize {
+            if T::should_mutate(mutation_rate, randomizer)? {
+                let weight = neuron.get_weight(index)? + self.generate_perturbation(randomizer);
+                neuron.set_weight(index, weight)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use neuron::Neuron;
+    use neuron::NeuronTrait;
+    use neuron_activation::activation_functions::ActivationFunctions;
+
+    use self::randomization::randomizer::Randomizer;
+
+    #[test]
+    fn test_weights_and_biases_stay_within_the_range() -> Result<(), NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+
+        let mut neuron = Neuron::new(20, ActivationFunctions::Sigmoid, &mut randomizer);
+
+        let original = neuron.clone();
+
+        UniformPerturbation::new(0.1).mutate(&mut neuron, 1.0, &mut randomizer)?;
+
+        assert!((neuron.get_bias() - original.get_bias()).abs() <= 0.1);
+
+        for index in 0..20 {
+            let difference = neuron.get_weight(index)? - original.get_weight(index)?;
+
+            assert!(difference.abs() <= 0.1);
+            assert_ne!(difference, 0.0);
+        }
+
+        Ok(())
+    }
+}
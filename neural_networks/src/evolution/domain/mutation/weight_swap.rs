@@ -0,0 +1,88 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use evolution::domain::mutation::mutation_operator_trait::MutationOperatorTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::MutableNeuronTrait;
+
+// Swaps each mutated weight with another one of the same neuron. The values themselves are
+// kept, only the inputs they apply to change. The bias is left alone.
+#[derive(Debug, Clone, Default)]
+pub struct WeightSwap {}
+
+impl WeightSwap {
+    pub fn new() -> Self {
+        WeightSwap {}
+    }
+}
+
+impl MutationOperatorTrait for WeightSwap {
+    fn mutate<T: MutableNeuronTrait, U: RandomizerTrait>(
+        &self,
+        neuron: &mut T,
+        mutation_rate: f64,
+        randomizer: &mut U,
+    ) -> Result<(), NeuralNetworksError> {
+        let indexes: Vec<usize> = (0..neuron.get_number_of_weights() as usize).collect();
+
+        for index in 0..indexes.len() {
+            if T::should_mutate(mutation_rate, randomizer)? {
+                let other_index = randomizer.choose_random_from_vec(&indexes);
+
+                let weight = neuron.get_weight(index)?;
+                let other_weight = neuron.get_weight(other_index)?;
+
+                neuron.set_weight(index, other_weight)?;
+                neuron.set_weight(other_index, weight)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use neuron::Neuron;
+    use neuron::NeuronTrait;
+    use neuron_activation::activation_functions::ActivationFunctions;
+
+    struct FakeRandomizer {}
+
+    impl RandomizerTrait for FakeRandomizer {
+        fn get_normal(&mut self) -> f64 {
+            0.5_f64
+        }
+        fn generate_float_from_0_to_1(&mut self) -> f64 {
+            0.75_f64
+        }
+        fn choose_random_from_vec<T>(&mut self, vector: &[T]) -> T
+        where
+            T: std::clone::Clone,
+        {
+            vector[0].clone()
+        }
+    }
+
+    #[test]
+    fn test_weights_get_swapped_without_changing_their_values() -> Result<(), NeuralNetworksError> {
+        let mut randomizer = FakeRandomizer {};
+
+        let mut neuron = Neuron::new(3, ActivationFunctions::Sigmoid, &mut randomizer);
+        neuron.set_weight(0, 1.0)?;
+        neuron.set_weight(1, 2.0)?;
+        neuron.set_weight(2, 3.0)?;
+
+        WeightSwap::new().mutate(&mut neuron, 1.0, &mut randomizer)?;
+
+        // Every weight got swapped with the first one, in order.
+        assert_eq!(neuron.get_weight(0)?, 3.0);
+        assert_eq!(neuron.get_weight(1)?, 1.0);
+        assert_eq!(neuron.get_weight(2)?, 2.0);
+        assert_eq!(neuron.get_bias(), 0.5);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,13 @@
+extern crate serde;
+
+use self::serde::{Deserialize, Serialize};
+
+// Which mutation operator a run uses (see the 'mutation' module), along with its settings.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum MutationOperators {
+    // Replaces the mutated weight or bias with a brand-new normally distributed value.
+    ResetToRandom,
+    GaussianPerturbation { sigma: f64 },
+    UniformPerturbation { range: f64 },
+    WeightSwap,
+}
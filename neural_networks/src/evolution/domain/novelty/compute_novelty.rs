@@ -0,0 +1,61 @@
+extern crate float_ord;
+
+use self::float_ord::FloatOrd;
+use neural_networks_error::NeuralNetworksError;
+
+// The mean distance between a behaviour and its 'number_of_neighbours' nearest neighbours amongst
+// the given ones. The behaviour being scored shouldn't be amongst them.
+pub fn compute_novelty(
+    behaviour: &[f64],
+    neighbours: &[&Vec<f64>],
+    number_of_neighbours: usize,
+) -> Result<f64, NeuralNetworksError> {
+    let mut distances = Vec::with_capacity(neighbours.len());
+
+    for neighbour in neighbours.iter() {
+        if neighbour.len() != behaviour.len() {
+            return Err(NeuralNetworksError::BehavioursOfDifferentLengths {
+                behaviour: behaviour.len(),
+                neighbour: neighbour.len(),
+            });
+        }
+
+        distances.push(
+            behaviour
+                .iter()
+                .zip(neighbour.iter())
+                .map(|(a, b)| (a - b).powi(2))
+                .sum::<f64>()
+                .sqrt(),
+        );
+    }
+
+    distances.sort_by_key(|distance| FloatOrd(*distance));
+
+    let nearest = &distances[..number_of_neighbours.min(distances.len())];
+
+    if nearest.is_empty() {
+        return Ok(0.0);
+    }
+
+    Ok(nearest.iter().sum::<f64>() / nearest.len() as f64)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_novelty_is_the_mean_distance_to_the_nearest_neighbours(
+    ) -> Result<(), NeuralNetworksError> {
+        let neighbours = [vec![1.0, 0.0], vec![0.0, 2.0], vec![10.0, 10.0]];
+        let neighbours: Vec<&Vec<f64>> = neighbours.iter().collect();
+
+        assert_eq!(compute_novelty(&[0.0, 0.0], &neighbours, 2)?, 1.5);
+        assert_eq!(compute_novelty(&[0.0, 0.0], &[], 2)?, 0.0);
+        assert!(compute_novelty(&[0.0], &neighbours, 2).is_err());
+
+        Ok(())
+    }
+}
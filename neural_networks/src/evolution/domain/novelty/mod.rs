@@ -0,0 +1,3 @@
+pub mod compute_novelty;
+pub mod novelty_archive;
+pub mod novelty_search;
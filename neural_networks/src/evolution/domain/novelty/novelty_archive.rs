@@ -0,0 +1,44 @@
+extern crate serde;
+
+use self::serde::{Deserialize, Serialize};
+
+// Behaviours found novel enough in past generations. Once full, the oldest ones make room for the
+// new ones.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NoveltyArchive {
+    behaviours: Vec<Vec<f64>>,
+    maximum_size: usize,
+}
+
+impl NoveltyArchive {
+    pub fn new(maximum_size: usize) -> Self {
+        NoveltyArchive {
+            behaviours: Vec::new(),
+            maximum_size,
+        }
+    }
+
+    pub fn add(&mut self, behaviour: Vec<f64>) {
+        if self.maximum_size == 0 {
+            return;
+        }
+
+        if self.behaviours.len() == self.maximum_size {
+            self.behaviours.remove(0);
+        }
+
+        self.behaviours.push(behaviour);
+    }
+
+    pub fn get_behaviours(&self) -> &Vec<Vec<f64>> {
+        &self.behaviours
+    }
+
+    pub fn get_size(&self) -> usize {
+        self.behaviours.len()
+    }
+
+    pub fn get_maximum_size(&self) -> usize {
+        self.maximum_size
+    }
+}
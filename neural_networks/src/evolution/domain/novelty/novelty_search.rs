@@ -0,0 +1,201 @@
+extern crate serde;
+
+use self::serde::{Deserialize, Serialize};
+use evolution::domain::genome::GenomeTrait;
+use evolution::domain::novelty::compute_novelty::compute_novelty;
+use evolution::domain::novelty::novelty_archive::NoveltyArchive;
+use evolution_strategies::domain::compute_centered_ranks::compute_centered_ranks;
+use neural_network::NeuralNetworkTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::NeuronTrait;
+
+const DEFAULT_MAXIMUM_ARCHIVE_SIZE: usize = 1000;
+
+// Rewards genomes for behaving differently from the rest of their generation and from what the
+// archive holds, instead of (or on top of) reaching the objective. The behaviour of a genome is
+// whatever the descriptor function returns for it. Holds no closure, so it can be checkpointed
+// along with its archive.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NoveltySearch {
+    number_of_neighbours: usize,
+    archive_threshold: f64,
+    objective_weight: f64,
+    archive: NoveltyArchive,
+}
+
+impl NoveltySearch {
+    // Behaviours whose novelty reaches the threshold get stored in the archive.
+    pub fn new(number_of_neighbours: usize, archive_threshold: f64) -> Self {
+        NoveltySearch {
+            number_of_neighbours,
+            archive_threshold,
+            objective_weight: 0.0,
+            archive: NoveltyArchive::new(DEFAULT_MAXIMUM_ARCHIVE_SIZE),
+        }
+    }
+
+    pub fn get_objective_weight(&self) -> f64 {
+        self.objective_weight
+    }
+
+    // With a weight of 0 the fitness is the novelty alone, and with a weight of 1 it is the
+    // objective fitness alone. Anything in between blends their ranks linearly.
+    pub fn set_objective_weight(
+        &mut self,
+        objective_weight: f64,
+    ) -> Result<(), NeuralNetworksError> {
+        if !(0.0..=1.0).contains(&objective_weight) {
+            return Err(NeuralNetworksError::ObjectiveWeightOutOfRange(
+                objective_weight,
+            ));
+        }
+
+        self.objective_weight = objective_weight;
+
+        Ok(())
+    }
+
+    pub fn set_maximum_archive_size(&mut self, maximum_archive_size: usize) {
+        let mut archive = NoveltyArchive::new(maximum_archive_size);
+
+        for behaviour in self.archive.get_behaviours().iter() {
+            archive.add(behaviour.clone());
+        }
+
+        self.archive = archive;
+    }
+
+    pub fn get_archive(&self) -> &NoveltyArchive {
+        &self.archive
+    }
+
+    // Replaces the fitness of every genome (expected to hold its objective fitness already) with
+    // its novelty, blended with the objective fitness according to the objective weight.
+    //
+    // Novelty and objective fitness have unrelated scales, so both get replaced by their rank
+    // within the generation (from 0 for the lowest to 1 for the highest) before being blended.
+    pub fn assign_novelty_scores<
+        T: GenomeTrait<U, V>,
+        U: NeuralNetworkTrait<V>,
+        V: NeuronTrait,
+        D: Fn(&T) -> Result<Vec<f64>, NeuralNetworksError>,
+    >(
+        &mut self,
+        genomes: &mut [T],
+        behaviour_descriptor: &D,
+    ) -> Result<(), NeuralNetworksError> {
+        let behaviours = genomes
+            .iter()
+            .map(behaviour_descriptor)
+            .collect::<Result<Vec<Vec<f64>>, NeuralNetworksError>>()?;
+
+        let mut novelties = Vec::with_capacity(genomes.len());
+        let mut novel_behaviours = Vec::new();
+
+        for index in 0..genomes.len() {
+            let neighbours: Vec<&Vec<f64>> = behaviours
+                .iter()
+                .enumerate()
+                .filter(|(other_index, _)| *other_index != index)
+                .map(|(_, behaviour)| behaviour)
+                .chain(self.archive.get_behaviours().iter())
+                .collect();
+
+            let novelty =
+                compute_novelty(&behaviours[index], &neighbours, self.number_of_neighbours)?;
+
+            if novelty >= self.archive_threshold {
+                novel_behaviours.push(behaviours[index].clone());
+            }
+
+            novelties.push(novelty);
+        }
+
+        let objective_fitnesses: Vec<f64> =
+            genomes.iter().map(|genome| genome.get_fitness()).collect();
+
+        let novelty_ranks = compute_centered_ranks(&novelties);
+        let objective_ranks = compute_centered_ranks(&objective_fitnesses);
+
+        for (index, genome) in genomes.iter_mut().enumerate() {
+            genome.set_fitness(
+                (1.0 - self.objective_weight) * (novelty_ranks[index] + 0.5)
+                    + self.objective_weight * (objective_ranks[index] + 0.5),
+            );
+        }
+
+        // Only added at the end, so every genome of the generation gets compared against the same
+        // archive.
+        for behaviour in novel_behaviours {
+            self.archive.add(behaviour);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use evolution::domain::create_genomes_with_fitnesses::create_genomes_with_fitnesses;
+    use evolution::domain::genome::Genome;
+    use neural_network::NeuralNetwork;
+    use neuron::Neuron;
+
+    // The behaviour of each genome depends on its identifier, so the fourth genome stands out.
+    fn describe_behaviour(
+        genome: &Genome<NeuralNetwork<Neuron>, Neuron>,
+    ) -> Result<Vec<f64>, NeuralNetworksError> {
+        Ok(vec![
+            [0.0, 0.1, 0.2, 5.0][genome.get_identifier() as usize - 1],
+        ])
+    }
+
+    #[test]
+    fn test_genomes_behaving_differently_get_the_highest_fitness() -> Result<(), NeuralNetworksError>
+    {
+        let mut genomes = create_genomes_with_fitnesses(&[1.0, 2.0, 3.0, 4.0])?;
+
+        let mut novelty_search = NoveltySearch::new(2, 1.0);
+        novelty_search.set_objective_weight(0.5)?;
+
+        novelty_search.assign_novelty_scores(&mut genomes, &describe_behaviour)?;
+
+        // Novelties: 0.15, 0.1, 0.15 and 4.85, so the ranks go 1/3, 0, 2/3 and 1 (ties keep their
+        // order), while the objective ranks go 0, 1/3, 2/3 and 1.
+        assert!((genomes[0].get_fitness() - (0.5 / 3.0)).abs() < 1e-9);
+        assert!((genomes[1].get_fitness() - (0.5 / 3.0)).abs() < 1e-9);
+        assert!((genomes[3].get_fitness() - 1.0).abs() < 1e-9);
+        assert_eq!(
+            novelty_search.get_archive().get_behaviours(),
+            &vec![vec![5.0]]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_the_scale_of_the_objective_fitness_doesnt_outweigh_the_novelty(
+    ) -> Result<(), NeuralNetworksError> {
+        let mut genomes = create_genomes_with_fitnesses(&[4000.0, 3000.0, 2000.0, 1000.0])?;
+        let mut scaled_down_genomes = create_genomes_with_fitnesses(&[0.004, 0.003, 0.002, 0.001])?;
+
+        let mut novelty_search = NoveltySearch::new(2, 1.0);
+        novelty_search.set_objective_weight(0.5)?;
+
+        novelty_search
+            .clone()
+            .assign_novelty_scores(&mut genomes, &describe_behaviour)?;
+        novelty_search.assign_novelty_scores(&mut scaled_down_genomes, &describe_behaviour)?;
+
+        for (genome, scaled_down_genome) in genomes.iter().zip(scaled_down_genomes.iter()) {
+            assert!((genome.get_fitness() - scaled_down_genome.get_fitness()).abs() < 1e-9);
+        }
+
+        // Blending the raw values, the objective fitness would drown the novelty of the fourth genome.
+        assert!(genomes[3].get_fitness() > genomes[1].get_fitness());
+
+        Ok(())
+    }
+}
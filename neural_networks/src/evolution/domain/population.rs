@@ -4,18 +4,21 @@ extern crate serde;
 use self::randomization::randomizer::RandomizerTrait;
 use self::serde::{Deserialize, Serialize};
 use evolution::domain::genome::GenomeTrait;
+use neural_network::LayeredNeuralNetworkTrait;
 use neural_network::NeuralNetworkTrait;
+use neural_networks_error::NeuralNetworksError;
 use neuron::NeuronTrait;
+use neuron_initialization::initializers::Initializers;
 use std::fmt;
 use std::marker::PhantomData;
 
 pub trait PopulationTrait<T: GenomeTrait<U, V>, U: NeuralNetworkTrait<V>, V: NeuronTrait> {
     fn get_size(&self) -> u32;
-    fn add(&mut self, genome: T) -> Result<(), String>;
-    fn get_genome(&self, index: usize) -> Result<&T, String>;
-    fn get_genomes(&self) -> Result<&Vec<T>, String>;
-    fn get_genomes_mut(&mut self) -> Result<&mut Vec<T>, String>;
-    fn get_genome_mut(&mut self, index: usize) -> Result<&mut T, String>;
+    fn add(&mut self, genome: T) -> Result<(), NeuralNetworksError>;
+    fn get_genome(&self, index: usize) -> Result<&T, NeuralNetworksError>;
+    fn get_genomes(&self) -> Result<&Vec<T>, NeuralNetworksError>;
+    fn get_genomes_mut(&mut self) -> Result<&mut Vec<T>, NeuralNetworksError>;
+    fn get_genome_mut(&mut self, index: usize) -> Result<&mut T, NeuralNetworksError>;
     fn get_midpoint(&self) -> u32;
 }
 
@@ -68,16 +71,47 @@ impl<T: GenomeTrait<U, V> + Clone, U: NeuralNetworkTrait<V> + Clone, V: NeuronTr
         }
     }
 
-    pub fn new_with_specified_layers<W: RandomizerTrait, X: Fn(u32, &[[usize; 2]], &mut W) -> T>(
+    pub fn new_with_specified_layers<
+        W: RandomizerTrait,
+        X: Fn(u32, &[[usize; 2]], &mut W) -> Result<T, NeuralNetworksError>,
+    >(
         number_of_neural_networks: u32,
         layers_definition: &[[usize; 2]],
         genome_creator: X,
         randomizer: &mut W,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, NeuralNetworksError> {
         let mut population = Population::new();
 
         for index in 0..number_of_neural_networks {
-            let genome = genome_creator(index + 1, layers_definition, randomizer);
+            let genome = genome_creator(index + 1, layers_definition, randomizer)?;
+
+            population.add(genome)?;
+        }
+
+        Ok(population)
+    }
+
+    pub fn new_with_specified_layers_and_initializer<
+        W: RandomizerTrait,
+        X: Fn(u32, &[[usize; 2]], &mut W) -> Result<T, NeuralNetworksError>,
+    >(
+        number_of_neural_networks: u32,
+        layers_definition: &[[usize; 2]],
+        initializer: Initializers,
+        genome_creator: X,
+        randomizer: &mut W,
+    ) -> Result<Self, NeuralNetworksError>
+    where
+        U: LayeredNeuralNetworkTrait<V>,
+    {
+        let mut population = Population::new();
+
+        for index in 0..number_of_neural_networks {
+            let mut genome = genome_creator(index + 1, layers_definition, randomizer)?;
+
+            genome
+                .get_neural_network_mut()
+                .initialize_parameters(initializer, randomizer)?;
 
             population.add(genome)?;
         }
@@ -93,27 +127,25 @@ impl<T: GenomeTrait<U, V> + Clone, U: NeuralNetworkTrait<V> + Clone, V: NeuronTr
         self.genomes.len() as u32
     }
 
-    fn add(&mut self, genome: T) -> std::result::Result<(), std::string::String> {
+    fn add(&mut self, genome: T) -> Result<(), NeuralNetworksError> {
         self.genomes.push(genome);
 
         Ok(())
     }
-    fn get_genome(&self, index: usize) -> Result<&T, String> {
+    fn get_genome(&self, index: usize) -> Result<&T, NeuralNetworksError> {
         Ok(&self.genomes[index])
     }
-    fn get_genome_mut(&mut self, index: usize) -> Result<&mut T, String> {
+    fn get_genome_mut(&mut self, index: usize) -> Result<&mut T, NeuralNetworksError> {
         Ok(&mut self.genomes[index])
     }
 
     fn get_midpoint(&self) -> u32 {
         self.get_size() % 2 + self.get_size() / 2
     }
-    fn get_genomes_mut(
-        &mut self,
-    ) -> std::result::Result<&mut std::vec::Vec<T>, std::string::String> {
+    fn get_genomes_mut(&mut self) -> Result<&mut Vec<T>, NeuralNetworksError> {
         Ok(&mut self.genomes)
     }
-    fn get_genomes(&self) -> std::result::Result<&std::vec::Vec<T>, std::string::String> {
+    fn get_genomes(&self) -> Result<&Vec<T>, NeuralNetworksError> {
         Ok(&self.genomes)
     }
 }
@@ -125,7 +157,9 @@ mod tests {
     use super::*;
     use evolution::controllers::create_next_generation::create_next_generation;
     use evolution::domain::create_genome::create_genome;
+    use evolution::domain::evolution_config::EvolutionConfig;
     use evolution::domain::genome::Genome;
+    use evolution::domain::selection::truncation_selection::TruncationSelection;
     use layer::Layer;
     use layer::LayerTrait;
     use neural_network::NeuralNetwork;
@@ -133,7 +167,7 @@ mod tests {
     use neuron_activation::activation_functions::ActivationFunctions;
 
     #[test]
-    fn test_can_create_empty_population_of_neural_networks() -> Result<(), String> {
+    fn test_can_create_empty_population_of_neural_networks() -> Result<(), NeuralNetworksError> {
         let population = Population::<
             Genome<NeuralNetwork<Neuron>, Neuron>,
             NeuralNetwork<Neuron>,
@@ -147,7 +181,7 @@ mod tests {
 
     #[test]
     fn test_can_create_population_of_x_number_of_neural_networks_with_prespecified_layers(
-    ) -> Result<(), String> {
+    ) -> Result<(), NeuralNetworksError> {
         let mut randomizer = Randomizer::new();
 
         let layers_definition = &[[4, 3], [3, 2], [2, 1]];
@@ -176,7 +210,7 @@ mod tests {
 
     #[test]
     fn test_the_first_neural_network_of_the_population_has_expected_properties(
-    ) -> Result<(), String> {
+    ) -> Result<(), NeuralNetworksError> {
         let mut randomizer = Randomizer::new();
 
         let layers_definition = &[[4, 3], [3, 2], [2, 1]];
@@ -217,8 +251,8 @@ mod tests {
     }
 
     #[test]
-    fn test_the_last_neural_network_of_the_population_has_expected_properties() -> Result<(), String>
-    {
+    fn test_the_last_neural_network_of_the_population_has_expected_properties(
+    ) -> Result<(), NeuralNetworksError> {
         let mut randomizer = Randomizer::new();
 
         let layers_definition = &[[4, 3], [3, 2], [2, 1]];
@@ -231,7 +265,7 @@ mod tests {
             10,
             layers_definition,
             |genome_identifier, layers_definition, randomizer| {
-                Genome::new(
+                Ok(Genome::new(
                     genome_identifier,
                     NeuralNetwork::new_with_specified_layers(
                         layers_definition,
@@ -239,8 +273,8 @@ mod tests {
                         |number_of_inputs, randomizer| {
                             Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
                         },
-                    ),
-                )
+                    )?,
+                ))
             },
             &mut randomizer,
         )?;
@@ -274,21 +308,29 @@ mod tests {
 
     fn setup_manual_population() -> Result<
         Population<Genome<NeuralNetwork<Neuron>, Neuron>, NeuralNetwork<Neuron>, Neuron>,
-        String,
+        NeuralNetworksError,
     > {
         let mut population = Population::new();
         let mut neural_network1 = NeuralNetwork::new();
 
         let mut randomizer = Randomizer::new();
 
-        let layer1 =
-            Layer::<Neuron>::create_layer(3, 2, &mut randomizer, |number_of_inputs, randomizer| {
+        let layer1 = Layer::<Neuron>::create_layer(
+            3,
+            2,
+            &mut randomizer,
+            |number_of_inputs, randomizer| {
                 Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
-            });
-        let layer2 =
-            Layer::<Neuron>::create_layer(2, 1, &mut randomizer, |number_of_inputs, randomizer| {
+            },
+        )?;
+        let layer2 = Layer::<Neuron>::create_layer(
+            2,
+            1,
+            &mut randomizer,
+            |number_of_inputs, randomizer| {
                 Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
-            });
+            },
+        )?;
 
         neural_network1.add(layer1)?;
         neural_network1.add(layer2)?;
@@ -299,14 +341,22 @@ mod tests {
 
         let mut neural_network2 = NeuralNetwork::new();
 
-        let layer1 =
-            Layer::<Neuron>::create_layer(3, 2, &mut randomizer, |number_of_inputs, randomizer| {
+        let layer1 = Layer::<Neuron>::create_layer(
+            3,
+            2,
+            &mut randomizer,
+            |number_of_inputs, randomizer| {
                 Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
-            });
-        let layer2 =
-            Layer::<Neuron>::create_layer(2, 1, &mut randomizer, |number_of_inputs, randomizer| {
+            },
+        )?;
+        let layer2 = Layer::<Neuron>::create_layer(
+            2,
+            1,
+            &mut randomizer,
+            |number_of_inputs, randomizer| {
                 Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
-            });
+            },
+        )?;
 
         neural_network2.add(layer1)?;
         neural_network2.add(layer2)?;
@@ -317,7 +367,7 @@ mod tests {
     }
 
     #[test]
-    fn test_can_add_neural_networks_to_population_one_by_one() -> Result<(), String> {
+    fn test_can_add_neural_networks_to_population_one_by_one() -> Result<(), NeuralNetworksError> {
         let population = setup_manual_population()?;
 
         assert_eq!(population.get_size(), 2);
@@ -326,11 +376,10 @@ mod tests {
     }
 
     #[test]
-    fn test_can_create_next_generation_from_existing_population() -> Result<(), String> {
+    fn test_can_create_next_generation_from_existing_population() -> Result<(), NeuralNetworksError>
+    {
         let mut population = setup_manual_population()?;
 
-        // Note that create_next_generation expects the population to have been ordered.
-
         population.get_genome_mut(0)?.set_fitness(10.0_f64);
         population.get_genome_mut(1)?.set_fitness(5.0_f64);
 
@@ -338,6 +387,8 @@ mod tests {
 
         let next_generation = create_next_generation(
             &population,
+            &TruncationSelection::new(0.5),
+            &EvolutionConfig::new(),
             Genome::new,
             || NeuralNetwork::new(),
             |number_of_inputs, randomizer| {
@@ -353,8 +404,10 @@ mod tests {
 
     extern crate file_system;
 
+    use std::error::Error;
+
     #[test]
-    fn test_can_serialize_population_to_file_and_deserialize_it() -> Result<(), String> {
+    fn test_can_serialize_population_to_file_and_deserialize_it() -> Result<(), Box<dyn Error>> {
         use self::file_system::deserialize_json_from_string::deserialize_json_from_string;
         use self::file_system::does_file_exist::does_file_exist;
         use self::file_system::read_file_to_string::read_file_to_string;
@@ -397,4 +450,67 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_the_elites_survive_unmutated_into_the_next_generation(
+    ) -> Result<(), NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+
+        let mut population = Population::<
+            Genome<NeuralNetwork<Neuron>, Neuron>,
+            NeuralNetwork<Neuron>,
+            Neuron,
+        >::new_with_specified_layers(
+            7, &[[3, 2], [2, 1]], create_genome, &mut randomizer
+        )?;
+
+        // The best genome doesn't need to come first.
+        population.get_genome_mut(4)?.set_fitness(10.0_f64);
+        population.get_genome_mut(2)?.set_fitness(5.0_f64);
+
+        let best_weights = population
+            .get_genome(4)?
+            .get_neural_network()
+            .get_layer(0)
+            .get_weights()
+            .to_vec();
+
+        // Every other genome is guaranteed to mutate.
+        let mut evolution_config = EvolutionConfig::new();
+        evolution_config.set_number_of_elites(2);
+        evolution_config.set_mutation_rate(1.0)?;
+
+        let next_generation = create_next_generation(
+            &population,
+            &TruncationSelection::new(0.5),
+            &evolution_config,
+            Genome::new,
+            NeuralNetwork::new,
+            |number_of_inputs, randomizer| {
+                Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+            },
+            &mut randomizer,
+        )?;
+
+        assert_eq!(next_generation.get_size(), 7);
+        assert_eq!(
+            next_generation
+                .get_genome(0)?
+                .get_neural_network()
+                .get_layer(0)
+                .get_weights(),
+            &best_weights[..]
+        );
+        assert_eq!(next_generation.get_genome(0)?.get_fitness(), 0.0_f64);
+        assert_ne!(
+            next_generation
+                .get_genome(2)?
+                .get_neural_network()
+                .get_layer(0)
+                .get_weights(),
+            &best_weights[..]
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,6 @@
+pub mod rank_selection;
+pub mod roulette_wheel_selection;
+pub mod selection_strategy_trait;
+pub mod sort_indexes_by_fitness;
+pub mod tournament_selection;
+pub mod truncation_selection;
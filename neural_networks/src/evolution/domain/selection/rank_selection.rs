@@ -0,0 +1,72 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use evolution::domain::genome::GenomeTrait;
+use evolution::domain::selection::roulette_wheel_selection::spin_roulette_wheel;
+use evolution::domain::selection::selection_strategy_trait::SelectionStrategyTrait;
+use evolution::domain::selection::sort_indexes_by_fitness::sort_indexes_by_fitness;
+use neural_network::NeuralNetworkTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::NeuronTrait;
+
+// Like the roulette wheel, but the slots depend on the position of each genome once ordered by
+// fitness, so that a single outstanding genome doesn't take over the whole next generation.
+#[derive(Debug, Clone, Default)]
+pub struct RankSelection {}
+
+impl RankSelection {
+    pub fn new() -> Self {
+        RankSelection {}
+    }
+}
+
+impl SelectionStrategyTrait for RankSelection {
+    fn select<
+        T: GenomeTrait<U, V>,
+        U: NeuralNetworkTrait<V>,
+        V: NeuronTrait,
+        W: RandomizerTrait,
+    >(
+        &self,
+        genomes: &[T],
+        randomizer: &mut W,
+    ) -> Result<usize, NeuralNetworksError> {
+        let ranking = sort_indexes_by_fitness(genomes);
+
+        let weights: Vec<f64> = (0..ranking.len())
+            .map(|position| (ranking.len() - position) as f64)
+            .collect();
+
+        Ok(ranking[spin_roulette_wheel(&weights, randomizer)?])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use evolution::domain::create_genomes_with_fitnesses::create_genomes_with_fitnesses;
+
+    use self::randomization::randomizer::Randomizer;
+
+    #[test]
+    fn test_better_ranked_genomes_get_selected_more_often() -> Result<(), NeuralNetworksError> {
+        let genomes = create_genomes_with_fitnesses(&[0.5, 1000.0, 0.1])?;
+        let mut randomizer = Randomizer::new();
+
+        let selection_strategy = RankSelection::new();
+
+        let mut times_selected = [0; 3];
+
+        for _ in 0..3000 {
+            times_selected[selection_strategy.select(&genomes, &mut randomizer)?] += 1;
+        }
+
+        // The ranks give weights of 3, 2 and 1, regardless of how far apart the fitnesses are.
+        assert!(times_selected[1] > times_selected[0]);
+        assert!(times_selected[0] > times_selected[2]);
+        assert!(times_selected[1] < 2000);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use evolution::domain::genome::GenomeTrait;
+use evolution::domain::selection::selection_strategy_trait::SelectionStrategyTrait;
+use neural_network::NeuralNetworkTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::NeuronTrait;
+
+#[derive(Debug, Clone, Default)]
+pub struct RouletteWheelSelection {}
+
+impl RouletteWheelSelection {
+    pub fn new() -> Self {
+        RouletteWheelSelection {}
+    }
+}
+
+pub fn spin_roulette_wheel<T: RandomizerTrait>(
+    weights: &[f64],
+    randomizer: &mut T,
+) -> Result<usize, NeuralNetworksError> {
+    if weights.is_empty() {
+        return Err(NeuralNetworksError::NothingToSelectFrom);
+    }
+
+    let total: f64 = weights.iter().sum();
+
+    // With no weight to go by, every slot is as likely as the rest.
+    if total <= 0f64 {
+        let indexes: Vec<usize> = (0..weights.len()).collect();
+        return Ok(randomizer.choose_random_from_vec(&indexes));
+    }
+
+    let mut remaining = randomizer.generate_float_from_0_to_1() * total;
+
+    for (index, weight) in weights.iter().enumerate() {
+        if remaining < *weight {
+            return Ok(index);
+        }
+
+        remaining -= weight;
+    }
+
+    Ok(weights.len() - 1)
+}
+
+impl SelectionStrategyTrait for RouletteWheelSelection {
+    fn select<
+        T: GenomeTrait<U, V>,
+        U: NeuralNetworkTrait<V>,
+        V: NeuronTrait,
+        W: RandomizerTrait,
+    >(
+        &self,
+        genomes: &[T],
+        randomizer: &mut W,
+    ) -> Result<usize, NeuralNetworksError> {
+        // Negative slots make no sense, so when some fitness is negative they all get shifted until
+        // the lowest one is zero. Otherwise they are left alone, keeping the chances proportional.
+        let lowest_fitness = genomes
+            .iter()
+            .map(|genome| genome.get_fitness())
+            .fold(0f64, f64::min);
+
+        let weights: Vec<f64> = genomes
+            .iter()
+            .map(|genome| genome.get_fitness() - lowest_fitness)
+            .collect();
+
+        spin_roulette_wheel(&weights, randomizer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use evolution::domain::create_genomes_with_fitnesses::create_genomes_with_fitnesses;
+
+    use self::randomization::randomizer::Randomizer;
+
+    #[test]
+    fn test_genomes_with_the_lowest_fitness_never_get_selected() -> Result<(), NeuralNetworksError>
+    {
+        let genomes = create_genomes_with_fitnesses(&[-2.0, 4.0, -2.0])?;
+        let mut randomizer = Randomizer::new();
+
+        let selection_strategy = RouletteWheelSelection::new();
+
+        for _ in 0..50 {
+            assert_eq!(selection_strategy.select(&genomes, &mut randomizer)?, 1);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_the_lowest_fitness_still_gets_selected_when_no_fitness_is_negative(
+    ) -> Result<(), NeuralNetworksError> {
+        let genomes = create_genomes_with_fitnesses(&[1.0, 3.0])?;
+        let mut randomizer = Randomizer::new();
+
+        let selection_strategy = RouletteWheelSelection::new();
+        let mut selected = [0; 2];
+
+        for _ in 0..200 {
+            selected[selection_strategy.select(&genomes, &mut randomizer)?] += 1;
+        }
+
+        assert!(selected[0] > 0);
+        assert!(selected[1] > selected[0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_spinning_a_wheel_without_weight_picks_any_slot() -> Result<(), NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+
+        assert!(spin_roulette_wheel(&[0.0, 0.0, 0.0], &mut randomizer)? < 3);
+        assert!(spin_roulette_wheel(&[], &mut randomizer).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,16 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use evolution::domain::genome::GenomeTrait;
+use neural_network::NeuralNetworkTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::NeuronTrait;
+
+pub trait SelectionStrategyTrait {
+    // Returns the index of the genome chosen to become a parent.
+    fn select<T: GenomeTrait<U, V>, U: NeuralNetworkTrait<V>, V: NeuronTrait, W: RandomizerTrait>(
+        &self,
+        genomes: &[T],
+        randomizer: &mut W,
+    ) -> Result<usize, NeuralNetworksError>;
+}
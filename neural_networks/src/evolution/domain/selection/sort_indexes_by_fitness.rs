@@ -0,0 +1,17 @@
+extern crate float_ord;
+
+use self::float_ord::FloatOrd;
+use evolution::domain::genome::GenomeTrait;
+use neural_network::NeuralNetworkTrait;
+use neuron::NeuronTrait;
+
+// Indexes of the genomes, from the highest fitness to the lowest.
+pub fn sort_indexes_by_fitness<T: GenomeTrait<U, V>, U: NeuralNetworkTrait<V>, V: NeuronTrait>(
+    genomes: &[T],
+) -> Vec<usize> {
+    let mut indexes: Vec<usize> = (0..genomes.len()).collect();
+
+    indexes.sort_by_key(|index| std::cmp::Reverse(FloatOrd(genomes[*index].get_fitness())));
+
+    indexes
+}
@@ -0,0 +1,73 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use evolution::domain::genome::GenomeTrait;
+use evolution::domain::selection::selection_strategy_trait::SelectionStrategyTrait;
+use neural_network::NeuralNetworkTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::NeuronTrait;
+
+#[derive(Debug, Clone)]
+pub struct TournamentSelection {
+    tournament_size: u32,
+}
+
+impl TournamentSelection {
+    pub fn new(tournament_size: u32) -> Self {
+        TournamentSelection { tournament_size }
+    }
+}
+
+impl SelectionStrategyTrait for TournamentSelection {
+    fn select<
+        T: GenomeTrait<U, V>,
+        U: NeuralNetworkTrait<V>,
+        V: NeuronTrait,
+        W: RandomizerTrait,
+    >(
+        &self,
+        genomes: &[T],
+        randomizer: &mut W,
+    ) -> Result<usize, NeuralNetworksError> {
+        if genomes.is_empty() {
+            return Err(NeuralNetworksError::NothingToSelectFrom);
+        }
+
+        let indexes: Vec<usize> = (0..genomes.len()).collect();
+
+        let mut winner = randomizer.choose_random_from_vec(&indexes);
+
+        for _ in 1..self.tournament_size {
+            let contender = randomizer.choose_random_from_vec(&indexes);
+
+            if genomes[contender].get_fitness() > genomes[winner].get_fitness() {
+                winner = contender;
+            }
+        }
+
+        Ok(winner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use evolution::domain::create_genomes_with_fitnesses::create_genomes_with_fitnesses;
+
+    use self::randomization::randomizer::Randomizer;
+
+    #[test]
+    fn test_large_tournaments_are_won_by_the_fittest_genome() -> Result<(), NeuralNetworksError> {
+        let genomes = create_genomes_with_fitnesses(&[1.0, 8.0, 3.0])?;
+        let mut randomizer = Randomizer::new();
+
+        let selection_strategy = TournamentSelection::new(60);
+
+        for _ in 0..20 {
+            assert_eq!(selection_strategy.select(&genomes, &mut randomizer)?, 1);
+        }
+
+        Ok(())
+    }
+}
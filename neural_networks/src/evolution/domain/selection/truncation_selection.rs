@@ -0,0 +1,72 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use evolution::domain::genome::GenomeTrait;
+use evolution::domain::selection::selection_strategy_trait::SelectionStrategyTrait;
+use evolution::domain::selection::sort_indexes_by_fitness::sort_indexes_by_fitness;
+use neural_network::NeuralNetworkTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::NeuronTrait;
+
+// Only the given proportion of the best genomes can become parents, all of them equally likely.
+#[derive(Debug, Clone)]
+pub struct TruncationSelection {
+    proportion: f64,
+}
+
+impl TruncationSelection {
+    pub fn new(proportion: f64) -> Self {
+        TruncationSelection { proportion }
+    }
+}
+
+impl SelectionStrategyTrait for TruncationSelection {
+    fn select<
+        T: GenomeTrait<U, V>,
+        U: NeuralNetworkTrait<V>,
+        V: NeuronTrait,
+        W: RandomizerTrait,
+    >(
+        &self,
+        genomes: &[T],
+        randomizer: &mut W,
+    ) -> Result<usize, NeuralNetworksError> {
+        if genomes.is_empty() {
+            return Err(NeuralNetworksError::NothingToSelectFrom);
+        }
+
+        let ranking = sort_indexes_by_fitness(genomes);
+
+        let number_of_candidates = ((genomes.len() as f64 * self.proportion).ceil() as usize)
+            .max(1)
+            .min(genomes.len());
+
+        Ok(randomizer.choose_random_from_vec(&ranking[0..number_of_candidates]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use evolution::domain::create_genomes_with_fitnesses::create_genomes_with_fitnesses;
+
+    use self::randomization::randomizer::Randomizer;
+
+    #[test]
+    fn test_only_the_best_proportion_of_genomes_gets_selected() -> Result<(), NeuralNetworksError> {
+        let genomes =
+            create_genomes_with_fitnesses(&[1.0, 9.0, 3.0, 7.0, 2.0, 0.0, 4.0, 5.0, 6.0, 8.0])?;
+        let mut randomizer = Randomizer::new();
+
+        let selection_strategy = TruncationSelection::new(0.2);
+
+        for _ in 0..50 {
+            let selected = selection_strategy.select(&genomes, &mut randomizer)?;
+
+            assert!(selected == 1 || selected == 9);
+        }
+
+        Ok(())
+    }
+}
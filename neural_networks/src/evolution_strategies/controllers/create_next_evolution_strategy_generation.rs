@@ -0,0 +1,140 @@
+extern crate float_ord;
+extern crate randomization;
+
+use self::float_ord::FloatOrd;
+use self::randomization::randomizer::RandomizerTrait;
+use evolution::domain::genome::GenomeTrait;
+use evolution::domain::population::Population;
+use evolution::domain::population::PopulationTrait;
+use evolution_strategies::domain::evolution_strategy_trait::EvolutionStrategyTrait;
+use neural_network::NeuralNetworkTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::NeuronTrait;
+
+// An alternative to create_next_generation for networks of a fixed topology: instead of crossing
+// over and mutating genomes, the evolution strategy gets told the fitness of the parameters of
+// every genome and samples the parameters of the next generation. The first time around, the
+// strategy starts searching from the fittest genome.
+pub fn create_next_evolution_strategy_generation<
+    T: GenomeTrait<U, V> + Clone,
+    U: NeuralNetworkTrait<V> + Clone,
+    V: NeuronTrait + Clone,
+    W: EvolutionStrategyTrait,
+    X: RandomizerTrait,
+>(
+    population: &Population<T, U, V>,
+    evolution_strategy: &mut W,
+    randomizer: &mut X,
+) -> Result<Population<T, U, V>, NeuralNetworksError> {
+    let genomes = population.get_genomes()?;
+
+    if genomes.is_empty() {
+        return Err(NeuralNetworksError::EmptyPopulation);
+    }
+
+    let fitnesses: Vec<f64> = genomes.iter().map(|genome| genome.get_fitness()).collect();
+
+    if evolution_strategy.is_initialized() {
+        let parameters: Vec<Vec<f64>> = genomes
+            .iter()
+            .map(|genome| genome.get_neural_network().parameters())
+            .collect();
+
+        evolution_strategy.tell(&parameters, &fitnesses)?;
+    } else {
+        let fittest_genome = genomes
+            .iter()
+            .max_by_key(|genome| FloatOrd(genome.get_fitness()))
+            .unwrap();
+
+        evolution_strategy.initialize(fittest_genome.get_neural_network().parameters())?;
+    }
+
+    let candidates = evolution_strategy.ask(genomes.len(), randomizer)?;
+
+    let mut next_generation = Population::new();
+
+    for (genome, candidate) in genomes.iter().zip(candidates.iter()) {
+        let mut child = genome.clone();
+        child.get_neural_network_mut().set_parameters(candidate)?;
+        child.set_fitness(0f64);
+
+        next_generation.add(child)?;
+    }
+
+    Ok(next_generation)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use evolution::domain::genome::Genome;
+    use evolution_strategies::domain::natural_evolution_strategy::NaturalEvolutionStrategy;
+    use neural_network::LayeredNeuralNetworkTrait;
+    use neural_network::NeuralNetwork;
+    use neuron::Neuron;
+    use neuron_activation::activation_functions::ActivationFunctions;
+
+    extern crate randomization;
+    use self::randomization::seeded_randomizer::SeededRandomizer;
+
+    #[test]
+    fn test_the_first_generation_gets_sampled_around_the_fittest_genome(
+    ) -> Result<(), NeuralNetworksError> {
+        let mut randomizer = SeededRandomizer::new(4);
+
+        let mut population = Population::new_with_specified_layers(
+            6,
+            &[[2, 2], [2, 1]],
+            |genome_identifier, layers_definition, randomizer| {
+                Ok(Genome::new(
+                    genome_identifier,
+                    NeuralNetwork::new_with_specified_layers(
+                        layers_definition,
+                        randomizer,
+                        |number_of_inputs, randomizer| {
+                            Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+                        },
+                    )?,
+                ))
+            },
+            &mut randomizer,
+        )?;
+        population.get_genome_mut(3)?.set_fitness(10.0);
+
+        let fittest_parameters = population.get_genome(3)?.get_neural_network().parameters();
+
+        let mut evolution_strategy = NaturalEvolutionStrategy::new(0.01, 0.1)?;
+
+        let next_generation = create_next_evolution_strategy_generation(
+            &population,
+            &mut evolution_strategy,
+            &mut randomizer,
+        )?;
+
+        assert_eq!(next_generation.get_size(), 6);
+        assert_eq!(evolution_strategy.get_mean(), &fittest_parameters);
+
+        for genome in next_generation.get_genomes()?.iter() {
+            assert_eq!(genome.get_fitness(), 0.0);
+
+            for (parameter, fittest_parameter) in genome
+                .get_neural_network()
+                .parameters()
+                .iter()
+                .zip(fittest_parameters.iter())
+            {
+                assert!((parameter - fittest_parameter).abs() < 0.1);
+            }
+        }
+
+        create_next_evolution_strategy_generation(
+            &next_generation,
+            &mut evolution_strategy,
+            &mut randomizer,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+extern crate float_ord;
+
+use self::float_ord::FloatOrd;
+
+// Replaces each fitness by its rank, spread evenly from -0.5 for the worst to 0.5 for the best, so
+// the size of the update doesn't depend on the scale of the fitness, nor get skewed by outliers.
+pub fn compute_centered_ranks(fitnesses: &[f64]) -> Vec<f64> {
+    if fitnesses.len() < 2 {
+        return vec![0.0; fitnesses.len()];
+    }
+
+    let mut indexes: Vec<usize> = (0..fitnesses.len()).collect();
+    indexes.sort_by_key(|index| FloatOrd(fitnesses[*index]));
+
+    let mut centered_ranks = vec![0.0; fitnesses.len()];
+
+    for (rank, index) in indexes.iter().enumerate() {
+        centered_ranks[*index] = rank as f64 / (fitnesses.len() - 1) as f64 - 0.5;
+    }
+
+    centered_ranks
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_ranks_are_spread_evenly_regardless_of_the_fitness_scale() {
+        assert_eq!(
+            compute_centered_ranks(&[3.0, -1000.0, 1e9]),
+            vec![0.0, -0.5, 0.5]
+        );
+        assert_eq!(compute_centered_ranks(&[7.0]), vec![0.0]);
+    }
+}
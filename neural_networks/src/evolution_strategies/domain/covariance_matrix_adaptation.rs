@@ -0,0 +1,390 @@
+extern crate float_ord;
+extern crate randomization;
+extern crate serde;
+
+use self::float_ord::FloatOrd;
+use self::randomization::randomizer::RandomizerTrait;
+use self::serde::{Deserialize, Serialize};
+use evolution_strategies::domain::decompose_symmetric_matrix::decompose_symmetric_matrix;
+use evolution_strategies::domain::evolution_strategy_trait::EvolutionStrategyTrait;
+use neural_networks_error::NeuralNetworksError;
+
+// Two matrices of that size already take 32 MB.
+const DEFAULT_MAXIMUM_NUMBER_OF_PARAMETERS: usize = 1000;
+
+fn default_maximum_number_of_parameters() -> usize {
+    DEFAULT_MAXIMUM_NUMBER_OF_PARAMETERS
+}
+
+// CMA-ES (Hansen, "The CMA Evolution Strategy: A Tutorial"), maximizing the fitness. Besides the
+// mean and the step size, it learns the full covariance of the parameters, so it needs memory
+// quadratic in their number and a cubic eigendecomposition every few generations. Past a few
+// hundred parameters, the natural evolution strategy scales better, so initializing it with more
+// parameters than its maximum gets refused.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CovarianceMatrixAdaptation {
+    step_size: f64,
+    #[serde(default = "default_maximum_number_of_parameters")]
+    maximum_number_of_parameters: usize,
+    mean: Vec<f64>,
+    covariance: Vec<Vec<f64>>,
+    eigenvectors: Vec<Vec<f64>>,
+    axis_lengths: Vec<f64>,
+    evolution_path: Vec<f64>,
+    conjugate_evolution_path: Vec<f64>,
+    generations: u32,
+    generations_since_decomposition: u32,
+}
+
+impl CovarianceMatrixAdaptation {
+    pub fn new(step_size: f64) -> Result<Self, NeuralNetworksError> {
+        if step_size <= 0.0 {
+            return Err(NeuralNetworksError::NonPositiveStepSize(step_size));
+        }
+
+        Ok(CovarianceMatrixAdaptation {
+            step_size,
+            maximum_number_of_parameters: DEFAULT_MAXIMUM_NUMBER_OF_PARAMETERS,
+            mean: Vec::new(),
+            covariance: Vec::new(),
+            eigenvectors: Vec::new(),
+            axis_lengths: Vec::new(),
+            evolution_path: Vec::new(),
+            conjugate_evolution_path: Vec::new(),
+            generations: 0,
+            generations_since_decomposition: 0,
+        })
+    }
+
+    pub fn get_step_size(&self) -> f64 {
+        self.step_size
+    }
+
+    pub fn get_maximum_number_of_parameters(&self) -> usize {
+        self.maximum_number_of_parameters
+    }
+
+    pub fn set_maximum_number_of_parameters(&mut self, maximum_number_of_parameters: usize) {
+        self.maximum_number_of_parameters = maximum_number_of_parameters;
+    }
+
+    pub fn get_covariance(&self) -> &Vec<Vec<f64>> {
+        &self.covariance
+    }
+
+    fn get_number_of_parameters(&self) -> usize {
+        self.mean.len()
+    }
+
+    // B * D * z
+    fn transform_from_standard_normal(&self, standard_normal: &[f64]) -> Vec<f64> {
+        self.eigenvectors
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .zip(self.axis_lengths.iter())
+                    .zip(standard_normal.iter())
+                    .map(|((eigenvector, axis_length), z)| eigenvector * axis_length * z)
+                    .sum()
+            })
+            .collect()
+    }
+
+    // C^(-1/2) * y = B * D^(-1) * B^T * y
+    fn whiten(&self, step: &[f64]) -> Vec<f64> {
+        let size = self.get_number_of_parameters();
+
+        let scaled: Vec<f64> = (0..size)
+            .map(|column| {
+                (0..size)
+                    .map(|row| self.eigenvectors[row][column] * step[row])
+                    .sum::<f64>()
+                    / self.axis_lengths[column]
+            })
+            .collect();
+
+        self.eigenvectors
+            .iter()
+            .map(|row| row.iter().zip(scaled.iter()).map(|(a, b)| a * b).sum())
+            .collect()
+    }
+
+    fn decompose_covariance(&mut self) {
+        let (eigenvalues, eigenvectors) = decompose_symmetric_matrix(&self.covariance);
+
+        self.axis_lengths = eigenvalues
+            .iter()
+            .map(|eigenvalue| eigenvalue.max(1e-20).sqrt())
+            .collect();
+        self.eigenvectors = eigenvectors;
+        self.generations_since_decomposition = 0;
+    }
+}
+
+impl EvolutionStrategyTrait for CovarianceMatrixAdaptation {
+    fn initialize(&mut self, mean: Vec<f64>) -> Result<(), NeuralNetworksError> {
+        if mean.is_empty() {
+            return Err(NeuralNetworksError::NoParameters);
+        }
+
+        if mean.len() > self.maximum_number_of_parameters {
+            return Err(
+                NeuralNetworksError::TooManyParametersForCovarianceMatrixAdaptation {
+                    number_of_parameters: mean.len(),
+                    maximum_number_of_parameters: self.maximum_number_of_parameters,
+                },
+            );
+        }
+
+        let size = mean.len();
+        let identity: Vec<Vec<f64>> = (0..size)
+            .map(|row| {
+                (0..size)
+                    .map(|column| if row == column { 1.0 } else { 0.0 })
+                    .collect()
+            })
+            .collect();
+
+        self.mean = mean;
+        self.covariance = identity.clone();
+        self.eigenvectors = identity;
+        self.axis_lengths = vec![1.0; size];
+        self.evolution_path = vec![0.0; size];
+        self.conjugate_evolution_path = vec![0.0; size];
+        self.generations = 0;
+        self.generations_since_decomposition = 0;
+
+        Ok(())
+    }
+
+    fn is_initialized(&self) -> bool {
+        !self.mean.is_empty()
+    }
+
+    fn get_mean(&self) -> &Vec<f64> {
+        &self.mean
+    }
+
+    fn ask<T: RandomizerTrait>(
+        &self,
+        number_of_candidates: usize,
+        randomizer: &mut T,
+    ) -> Result<Vec<Vec<f64>>, NeuralNetworksError> {
+        if !self.is_initialized() {
+            return Err(NeuralNetworksError::EvolutionStrategyNotInitialized);
+        }
+
+        Ok((0..number_of_candidates)
+            .map(|_| {
+                let standard_normal: Vec<f64> = (0..self.get_number_of_parameters())
+                    .map(|_| randomizer.get_normal())
+                    .collect();
+
+                self.transform_from_standard_normal(&standard_normal)
+                    .iter()
+                    .zip(self.mean.iter())
+                    .map(|(step, mean)| mean + self.step_size * step)
+                    .collect()
+            })
+            .collect())
+    }
+
+    fn tell(
+        &mut self,
+        candidates: &[Vec<f64>],
+        fitnesses: &[f64],
+    ) -> Result<(), NeuralNetworksError> {
+        let size = self.get_number_of_parameters();
+        let number_of_candidates = candidates.len();
+
+        if !self.is_initialized() {
+            return Err(NeuralNetworksError::EvolutionStrategyNotInitialized);
+        }
+        if number_of_candidates < 2 || fitnesses.len() != number_of_candidates {
+            return Err(NeuralNetworksError::NotEnoughFitnesses {
+                number_of_candidates,
+                number_of_fitnesses: fitnesses.len(),
+            });
+        }
+        if let Some(candidate) = candidates.iter().find(|candidate| candidate.len() != size) {
+            return Err(NeuralNetworksError::WrongCandidateSize {
+                expected: size,
+                received: candidate.len(),
+            });
+        }
+
+        let n = size as f64;
+        let lambda = number_of_candidates as f64;
+        let number_of_parents = number_of_candidates / 2;
+
+        let raw_weights: Vec<f64> = (1..=number_of_parents)
+            .map(|rank| ((lambda + 1.0) / 2.0).ln() - (rank as f64).ln())
+            .collect();
+        let sum_of_weights: f64 = raw_weights.iter().sum();
+        let weights: Vec<f64> = raw_weights.iter().map(|w| w / sum_of_weights).collect();
+        let effective_parents = 1.0 / weights.iter().map(|w| w * w).sum::<f64>();
+
+        let cumulation = (4.0 + effective_parents / n) / (n + 4.0 + 2.0 * effective_parents / n);
+        let step_size_cumulation = (effective_parents + 2.0) / (n + effective_parents + 5.0);
+        let rank_one_rate = 2.0 / ((n + 1.3).powi(2) + effective_parents);
+        let rank_parents_rate = (1.0 - rank_one_rate).min(
+            2.0 * (effective_parents - 2.0 + 1.0 / effective_parents)
+                / ((n + 2.0).powi(2) + effective_parents),
+        );
+        let damping = 1.0
+            + 2.0 * (((effective_parents - 1.0) / (n + 1.0)).sqrt() - 1.0).max(0.0)
+            + step_size_cumulation;
+        let expected_norm = n.sqrt() * (1.0 - 1.0 / (4.0 * n) + 1.0 / (21.0 * n * n));
+
+        let mut indexes: Vec<usize> = (0..number_of_candidates).collect();
+        indexes.sort_by_key(|index| std::cmp::Reverse(FloatOrd(fitnesses[*index])));
+
+        let steps: Vec<Vec<f64>> = indexes
+            .iter()
+            .take(number_of_parents)
+            .map(|index| {
+                candidates[*index]
+                    .iter()
+                    .zip(self.mean.iter())
+                    .map(|(candidate, mean)| (candidate - mean) / self.step_size)
+                    .collect()
+            })
+            .collect();
+
+        let weighted_step: Vec<f64> = (0..size)
+            .map(|parameter| {
+                weights
+                    .iter()
+                    .zip(steps.iter())
+                    .map(|(weight, step)| weight * step[parameter])
+                    .sum()
+            })
+            .collect();
+
+        for (mean, step) in self.mean.iter_mut().zip(weighted_step.iter()) {
+            *mean += self.step_size * step;
+        }
+
+        let whitened_step = self.whiten(&weighted_step);
+        let step_size_path_factor =
+            (step_size_cumulation * (2.0 - step_size_cumulation) * effective_parents).sqrt();
+
+        for (path, step) in self
+            .conjugate_evolution_path
+            .iter_mut()
+            .zip(whitened_step.iter())
+        {
+            *path = (1.0 - step_size_cumulation) * *path + step_size_path_factor * step;
+        }
+
+        let conjugate_path_norm = self
+            .conjugate_evolution_path
+            .iter()
+            .map(|value| value * value)
+            .sum::<f64>()
+            .sqrt();
+
+        self.generations += 1;
+
+        // Stalls the update of the evolution path while the step size grows too fast.
+        let is_path_updated = conjugate_path_norm
+            / (1.0 - (1.0 - step_size_cumulation).powi(2 * self.generations as i32)).sqrt()
+            / expected_norm
+            < 1.4 + 2.0 / (n + 1.0);
+        let path_indicator = if is_path_updated { 1.0 } else { 0.0 };
+
+        let path_factor = (cumulation * (2.0 - cumulation) * effective_parents).sqrt();
+
+        for (path, step) in self.evolution_path.iter_mut().zip(weighted_step.iter()) {
+            *path = (1.0 - cumulation) * *path + path_indicator * path_factor * step;
+        }
+
+        let correction = (1.0 - path_indicator) * cumulation * (2.0 - cumulation);
+
+        for row in 0..size {
+            for column in 0..size {
+                let rank_parents_update: f64 = weights
+                    .iter()
+                    .zip(steps.iter())
+                    .map(|(weight, step)| weight * step[row] * step[column])
+                    .sum();
+
+                self.covariance[row][column] = (1.0 - rank_one_rate - rank_parents_rate)
+                    * self.covariance[row][column]
+                    + rank_one_rate
+                        * (self.evolution_path[row] * self.evolution_path[column]
+                            + correction * self.covariance[row][column])
+                    + rank_parents_rate * rank_parents_update;
+            }
+        }
+
+        self.step_size *=
+            ((step_size_cumulation / damping) * (conjugate_path_norm / expected_norm - 1.0)).exp();
+
+        self.generations_since_decomposition += 1;
+
+        if self.generations_since_decomposition as f64
+            > 1.0 / ((rank_one_rate + rank_parents_rate) * n * 10.0)
+        {
+            self.decompose_covariance();
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    extern crate randomization;
+    use self::randomization::seeded_randomizer::SeededRandomizer;
+
+    #[test]
+    fn test_cma_es_converges_on_a_shifted_sphere() -> Result<(), NeuralNetworksError> {
+        let mut randomizer = SeededRandomizer::new(5);
+        let target = [1.0, -2.0, 0.5, 3.0];
+
+        let mut sut = CovarianceMatrixAdaptation::new(0.5)?;
+        sut.initialize(vec![0.0; 4])?;
+
+        for _ in 0..150 {
+            let candidates = sut.ask(10, &mut randomizer)?;
+            let fitnesses: Vec<f64> = candidates
+                .iter()
+                .map(|candidate| {
+                    -candidate
+                        .iter()
+                        .zip(target.iter())
+                        .map(|(a, b)| (a - b).powi(2))
+                        .sum::<f64>()
+                })
+                .collect();
+
+            sut.tell(&candidates, &fitnesses)?;
+        }
+
+        for (mean, expected) in sut.get_mean().iter().zip(target.iter()) {
+            assert!((mean - expected).abs() < 1e-3);
+        }
+        assert!(sut.get_step_size() < 0.5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cma_es_refuses_a_non_positive_step_size_uninitialized_use_and_too_many_parameters(
+    ) -> Result<(), NeuralNetworksError> {
+        assert!(CovarianceMatrixAdaptation::new(0.0).is_err());
+
+        let mut sut = CovarianceMatrixAdaptation::new(1.0)?;
+        assert!(sut.ask(4, &mut SeededRandomizer::new(1)).is_err());
+
+        sut.set_maximum_number_of_parameters(3);
+        assert!(sut.initialize(vec![0.0; 4]).is_err());
+        assert!(sut.initialize(vec![0.0; 3]).is_ok());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+// Eigendecomposition of a symmetric matrix through cyclic Jacobi rotations. Returns the
+// eigenvalues and a matrix whose columns are the matching eigenvectors.
+pub fn decompose_symmetric_matrix(matrix: &[Vec<f64>]) -> (Vec<f64>, Vec<Vec<f64>>) {
+    let size = matrix.len();
+    let mut diagonalized: Vec<Vec<f64>> = matrix.to_vec();
+    let mut eigenvectors: Vec<Vec<f64>> = (0..size)
+        .map(|row| {
+            (0..size)
+                .map(|column| if row == column { 1.0 } else { 0.0 })
+                .collect()
+        })
+        .collect();
+
+    for _ in 0..100 {
+        let off_diagonal_norm: f64 = (0..size)
+            .flat_map(|row| {
+                (0..size)
+                    .filter(move |column| *column != row)
+                    .map(move |column| (row, column))
+            })
+            .map(|(row, column)| diagonalized[row][column].powi(2))
+            .sum();
+
+        if off_diagonal_norm < 1e-22 {
+            break;
+        }
+
+        for p in 0..size {
+            for q in (p + 1)..size {
+                if diagonalized[p][q].abs() < 1e-300 {
+                    continue;
+                }
+
+                let theta = (diagonalized[q][q] - diagonalized[p][p]) / (2.0 * diagonalized[p][q]);
+                let sign = if theta >= 0.0 { 1.0 } else { -1.0 };
+                let t = sign / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let cosine = 1.0 / (t * t + 1.0).sqrt();
+                let sine = t * cosine;
+
+                for row in diagonalized.iter_mut() {
+                    let kp = row[p];
+                    let kq = row[q];
+                    row[p] = cosine * kp - sine * kq;
+                    row[q] = sine * kp + cosine * kq;
+                }
+
+                let (upper_rows, lower_rows) = diagonalized.split_at_mut(q);
+
+                for (pk, qk) in upper_rows[p].iter_mut().zip(lower_rows[0].iter_mut()) {
+                    let (old_pk, old_qk) = (*pk, *qk);
+                    *pk = cosine * old_pk - sine * old_qk;
+                    *qk = sine * old_pk + cosine * old_qk;
+                }
+
+                for row in eigenvectors.iter_mut() {
+                    let kp = row[p];
+                    let kq = row[q];
+                    row[p] = cosine * kp - sine * kq;
+                    row[q] = sine * kp + cosine * kq;
+                }
+            }
+        }
+    }
+
+    (
+        (0..size).map(|index| diagonalized[index][index]).collect(),
+        eigenvectors,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_the_decomposition_rebuilds_the_matrix() {
+        let matrix = vec![
+            vec![4.0, 1.0, 0.5],
+            vec![1.0, 3.0, -0.2],
+            vec![0.5, -0.2, 1.0],
+        ];
+
+        let (eigenvalues, eigenvectors) = decompose_symmetric_matrix(&matrix);
+
+        for row in 0..3 {
+            for column in 0..3 {
+                let rebuilt: f64 = (0..3)
+                    .map(|k| eigenvectors[row][k] * eigenvalues[k] * eigenvectors[column][k])
+                    .sum();
+
+                assert!((rebuilt - matrix[row][column]).abs() < 1e-9);
+            }
+        }
+    }
+}
@@ -0,0 +1,23 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use neural_networks_error::NeuralNetworksError;
+
+// Evolution strategies search over the weights and biases of a network as a single flat vector.
+// They get asked for candidates, which get evaluated elsewhere, and then told how fit each one was
+// so they can move their search distribution towards the best ones.
+pub trait EvolutionStrategyTrait {
+    fn initialize(&mut self, mean: Vec<f64>) -> Result<(), NeuralNetworksError>;
+    fn is_initialized(&self) -> bool;
+    fn get_mean(&self) -> &Vec<f64>;
+    fn ask<T: RandomizerTrait>(
+        &self,
+        number_of_candidates: usize,
+        randomizer: &mut T,
+    ) -> Result<Vec<Vec<f64>>, NeuralNetworksError>;
+    fn tell(
+        &mut self,
+        candidates: &[Vec<f64>],
+        fitnesses: &[f64],
+    ) -> Result<(), NeuralNetworksError>;
+}
@@ -0,0 +1,5 @@
+pub mod compute_centered_ranks;
+pub mod covariance_matrix_adaptation;
+pub mod decompose_symmetric_matrix;
+pub mod evolution_strategy_trait;
+pub mod natural_evolution_strategy;
@@ -0,0 +1,215 @@
+extern crate randomization;
+extern crate serde;
+
+use self::randomization::randomizer::RandomizerTrait;
+use self::serde::{Deserialize, Serialize};
+use evolution_strategies::domain::compute_centered_ranks::compute_centered_ranks;
+use evolution_strategies::domain::evolution_strategy_trait::EvolutionStrategyTrait;
+use neural_networks_error::NeuralNetworksError;
+
+// The evolution strategy of Salimans et al. (2017): candidates get sampled in mirrored pairs around
+// the mean (antithetic sampling), and the mean follows the gradient estimated from the centered
+// ranks of their fitness. Its memory and time are linear in the number of parameters.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NaturalEvolutionStrategy {
+    noise_standard_deviation: f64,
+    learning_rate: f64,
+    weight_decay: f64,
+    mean: Vec<f64>,
+}
+
+impl NaturalEvolutionStrategy {
+    pub fn new(
+        noise_standard_deviation: f64,
+        learning_rate: f64,
+    ) -> Result<Self, NeuralNetworksError> {
+        if noise_standard_deviation <= 0.0 || learning_rate <= 0.0 {
+            return Err(NeuralNetworksError::NonPositiveNoiseOrLearningRate {
+                noise_standard_deviation,
+                learning_rate,
+            });
+        }
+
+        Ok(NaturalEvolutionStrategy {
+            noise_standard_deviation,
+            learning_rate,
+            weight_decay: 0.0,
+            mean: Vec::new(),
+        })
+    }
+
+    pub fn get_noise_standard_deviation(&self) -> f64 {
+        self.noise_standard_deviation
+    }
+
+    pub fn get_learning_rate(&self) -> f64 {
+        self.learning_rate
+    }
+
+    pub fn get_weight_decay(&self) -> f64 {
+        self.weight_decay
+    }
+
+    pub fn set_weight_decay(&mut self, weight_decay: f64) -> Result<(), NeuralNetworksError> {
+        if weight_decay < 0.0 {
+            return Err(NeuralNetworksError::NegativeWeightDecay(weight_decay));
+        }
+
+        self.weight_decay = weight_decay;
+
+        Ok(())
+    }
+}
+
+impl EvolutionStrategyTrait for NaturalEvolutionStrategy {
+    fn initialize(&mut self, mean: Vec<f64>) -> Result<(), NeuralNetworksError> {
+        if mean.is_empty() {
+            return Err(NeuralNetworksError::NoParameters);
+        }
+
+        self.mean = mean;
+
+        Ok(())
+    }
+
+    fn is_initialized(&self) -> bool {
+        !self.mean.is_empty()
+    }
+
+    fn get_mean(&self) -> &Vec<f64> {
+        &self.mean
+    }
+
+    fn ask<T: RandomizerTrait>(
+        &self,
+        number_of_candidates: usize,
+        randomizer: &mut T,
+    ) -> Result<Vec<Vec<f64>>, NeuralNetworksError> {
+        if !self.is_initialized() {
+            return Err(NeuralNetworksError::EvolutionStrategyNotInitialized);
+        }
+        if !number_of_candidates.is_multiple_of(2) {
+            return Err(NeuralNetworksError::OddNumberOfCandidates(
+                number_of_candidates,
+            ));
+        }
+
+        let mut candidates = Vec::with_capacity(number_of_candidates);
+
+        for _ in 0..number_of_candidates / 2 {
+            let noise: Vec<f64> = self.mean.iter().map(|_| randomizer.get_normal()).collect();
+
+            for direction in [1.0, -1.0].iter() {
+                candidates.push(
+                    self.mean
+                        .iter()
+                        .zip(noise.iter())
+                        .map(|(mean, noise)| {
+                            mean + direction * self.noise_standard_deviation * noise
+                        })
+                        .collect(),
+                );
+            }
+        }
+
+        Ok(candidates)
+    }
+
+    fn tell(
+        &mut self,
+        candidates: &[Vec<f64>],
+        fitnesses: &[f64],
+    ) -> Result<(), NeuralNetworksError> {
+        if !self.is_initialized() {
+            return Err(NeuralNetworksError::EvolutionStrategyNotInitialized);
+        }
+        if candidates.is_empty() || fitnesses.len() != candidates.len() {
+            return Err(NeuralNetworksError::NotEnoughFitnesses {
+                number_of_candidates: candidates.len(),
+                number_of_fitnesses: fitnesses.len(),
+            });
+        }
+        if let Some(candidate) = candidates
+            .iter()
+            .find(|candidate| candidate.len() != self.mean.len())
+        {
+            return Err(NeuralNetworksError::WrongCandidateSize {
+                expected: self.mean.len(),
+                received: candidate.len(),
+            });
+        }
+
+        let centered_ranks = compute_centered_ranks(fitnesses);
+        let scale = self.learning_rate / (candidates.len() as f64 * self.noise_standard_deviation);
+
+        for (parameter, mean) in self.mean.clone().iter().enumerate() {
+            let gradient: f64 = candidates
+                .iter()
+                .zip(centered_ranks.iter())
+                .map(|(candidate, rank)| {
+                    rank * (candidate[parameter] - mean) / self.noise_standard_deviation
+                })
+                .sum();
+
+            self.mean[parameter] +=
+                scale * gradient - self.learning_rate * self.weight_decay * mean;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    extern crate randomization;
+    use self::randomization::seeded_randomizer::SeededRandomizer;
+
+    #[test]
+    fn test_candidates_come_in_mirrored_pairs() -> Result<(), NeuralNetworksError> {
+        let mut sut = NaturalEvolutionStrategy::new(0.1, 0.01)?;
+        sut.initialize(vec![1.0, 2.0])?;
+
+        let candidates = sut.ask(4, &mut SeededRandomizer::new(2))?;
+
+        assert_eq!(candidates.len(), 4);
+        assert_eq!(candidates[0][0] + candidates[1][0], 2.0);
+        assert_eq!(candidates[2][1] + candidates[3][1], 4.0);
+        assert!(sut.ask(3, &mut SeededRandomizer::new(2)).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_the_mean_climbs_towards_the_fittest_region() -> Result<(), NeuralNetworksError> {
+        let mut randomizer = SeededRandomizer::new(8);
+        let target = [0.5, -0.5, 1.0];
+
+        let mut sut = NaturalEvolutionStrategy::new(0.1, 0.05)?;
+        sut.initialize(vec![0.0; 3])?;
+
+        for _ in 0..300 {
+            let candidates = sut.ask(20, &mut randomizer)?;
+            let fitnesses: Vec<f64> = candidates
+                .iter()
+                .map(|candidate| {
+                    -candidate
+                        .iter()
+                        .zip(target.iter())
+                        .map(|(a, b)| (a - b).powi(2))
+                        .sum::<f64>()
+                })
+                .collect();
+
+            sut.tell(&candidates, &fitnesses)?;
+        }
+
+        for (mean, expected) in sut.get_mean().iter().zip(target.iter()) {
+            assert!((mean - expected).abs() < 0.1);
+        }
+
+        Ok(())
+    }
+}
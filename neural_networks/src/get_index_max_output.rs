@@ -1,10 +1,13 @@
-pub fn get_index_max_output(outputs: &[f64]) -> usize {
+use neural_networks_error::NeuralNetworksError;
+
+// Ties go to the last of the tied outputs. See action_selection for other ways to decide.
+pub fn get_index_max_output(outputs: &[f64]) -> Result<usize, NeuralNetworksError> {
     outputs
         .iter()
         .enumerate()
         .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
         .map(|(index, _)| index)
-        .unwrap()
+        .ok_or(NeuralNetworksError::NoOutputsToSelectFrom)
 }
 
 #[cfg(test)]
@@ -13,12 +16,16 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_retrieves_the_proper_indexes() -> Result<(), String> {
-        assert_eq!(get_index_max_output(&[0.4, 2.1, 11.0]), 2);
-        assert_eq!(get_index_max_output(&[0.4, 22.1, 10.0]), 1);
-        assert_eq!(get_index_max_output(&[40.4, 22.1, 10.0]), 0);
-        assert_eq!(get_index_max_output(&[0.4, 2.0, -1.0]), 1);
-        assert_eq!(get_index_max_output(&[0.0, 0.0, 0.0]), 2);
+    fn test_retrieves_the_proper_indexes() -> Result<(), NeuralNetworksError> {
+        assert_eq!(get_index_max_output(&[0.4, 2.1, 11.0])?, 2);
+        assert_eq!(get_index_max_output(&[0.4, 22.1, 10.0])?, 1);
+        assert_eq!(get_index_max_output(&[40.4, 22.1, 10.0])?, 0);
+        assert_eq!(get_index_max_output(&[0.4, 2.0, -1.0])?, 1);
+        assert_eq!(get_index_max_output(&[0.0, 0.0, 0.0])?, 2);
+        assert_eq!(
+            get_index_max_output(&[]),
+            Err(NeuralNetworksError::NoOutputsToSelectFrom)
+        );
 
         Ok(())
     }
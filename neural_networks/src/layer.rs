@@ -2,39 +2,291 @@ extern crate randomization;
 extern crate serde;
 
 use self::randomization::randomizer::RandomizerTrait;
-use self::serde::{Deserialize, Serialize};
-use evolution::domain::constants::CROSSOVER_PROBABILITY;
+use self::serde::{Deserialize, Serialize, Serializer};
+use neural_networks_error::NeuralNetworksError;
+use neuron::MutableNeuronTrait;
 use neuron::NeuronTrait;
+use neuron_activation::activate_neuron::activate_neuron;
+use neuron_activation::activation_functions::ActivationFunctions;
+use neuron_view::NeuronView;
+use neuron_view_mut::NeuronViewMut;
+use output_transformation::output_transforms::OutputTransforms;
+use output_transformation::transform_outputs::transform_outputs;
+use recurrence::recurrences::Recurrences;
+use recurrence::step_recurrent_layer::step_recurrent_layer;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
 
 pub trait LayerTrait<T: NeuronTrait> {
     fn get_number_of_inputs(&self) -> u32;
     fn get_number_of_neurons(&self) -> u32;
-    fn get_neurons_mut(&mut self) -> &mut Vec<T>;
-    fn get_neuron(&self, index: usize) -> Result<&T, String>;
-    fn get_neuron_mut(&mut self, index: usize) -> std::result::Result<&mut T, std::string::String>;
-    fn feed_forward(&self, inputs: &[f64]) -> Vec<f64>;
-    fn should_crossover<U: RandomizerTrait>(randomizer: &mut U) -> Result<bool, String>;
+    fn get_number_of_outputs(&self) -> u32;
+    fn get_number_of_weights_per_neuron(&self) -> u32;
+    fn get_neurons(&self) -> Vec<NeuronView<'_>>;
+    fn get_neurons_mut(&mut self) -> Vec<NeuronViewMut<'_>>;
+    fn get_neuron(&self, index: usize) -> Result<NeuronView<'_>, NeuralNetworksError>;
+    fn get_neuron_mut(&mut self, index: usize) -> Result<NeuronViewMut<'_>, NeuralNetworksError>;
+    fn feed_forward(&self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError>;
+    fn feed_forward_batch(
+        &self,
+        inputs: &[f64],
+        outputs: &mut Vec<f64>,
+    ) -> Result<(), NeuralNetworksError>;
+    fn feed_forward_step(&mut self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError>;
+    fn reset_state(&mut self);
+    fn should_crossover<U: RandomizerTrait>(
+        crossover_rate: f64,
+        randomizer: &mut U,
+    ) -> Result<bool, NeuralNetworksError>;
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+// The weights live in a contiguous matrix (one row per neuron), next to the biases and activation
+// functions of the neurons. That is the only place they are stored: the neurons handed out are
+// views into the matrix, and the neurons of the JSON format get taken apart when deserializing and
+// rebuilt out of the views when serializing. Layers without an output transform or recurrence leave
+// them out of the JSON. The state of recurrent layers isn't serialized either; feed_forward and
+// feed_forward_batch start every sample from a blank state, while feed_forward_step carries it on.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(
+    try_from = "SerializedLayer<T>",
+    bound(deserialize = "T: Deserialize<'de>")
+)]
 pub struct Layer<T: NeuronTrait> {
+    number_of_inputs: u32,
+    weights: Vec<f64>,
+    biases: Vec<f64>,
+    activation_functions: Vec<ActivationFunctions>,
+    output_transform: OutputTransforms,
+    recurrence: Recurrences,
+    state: Vec<f64>,
+    phantom: PhantomData<T>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SerializedLayer<T> {
     number_of_inputs: u32,
     neurons: Vec<T>,
+    #[serde(default, skip_serializing_if = "OutputTransforms::is_none")]
+    output_transform: OutputTransforms,
+    #[serde(default, skip_serializing_if = "Recurrences::is_none")]
+    recurrence: Recurrences,
+}
+
+impl<T: NeuronTrait> TryFrom<SerializedLayer<T>> for Layer<T> {
+    type Error = NeuralNetworksError;
+
+    fn try_from(serialized_layer: SerializedLayer<T>) -> Result<Self, NeuralNetworksError> {
+        let mut layer = Layer::new_with_recurrence(
+            serialized_layer.number_of_inputs,
+            serialized_layer.neurons,
+            serialized_layer.recurrence,
+        )?;
+
+        layer.set_output_transform(serialized_layer.output_transform);
+
+        Ok(layer)
+    }
+}
+
+impl<T: NeuronTrait> Serialize for Layer<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerializedLayer {
+            number_of_inputs: self.number_of_inputs,
+            neurons: self.get_neurons(),
+            output_transform: self.output_transform,
+            recurrence: self.recurrence,
+        }
+        .serialize(serializer)
+    }
 }
 
 impl<T: NeuronTrait> Layer<T> {
+    pub fn new(number_of_inputs: u32, neurons: Vec<T>) -> Result<Layer<T>, NeuralNetworksError> {
+        Layer::new_with_recurrence(number_of_inputs, neurons, Recurrences::None)
+    }
+
+    // The neurons of a recurrent layer have to be laid out as described in Recurrences. Every
+    // neuron needs exactly one weight per column of the matrix.
+    pub fn new_with_recurrence(
+        number_of_inputs: u32,
+        neurons: Vec<T>,
+        recurrence: Recurrences,
+    ) -> Result<Layer<T>, NeuralNetworksError> {
+        let expected =
+            recurrence.get_number_of_weights_per_neuron(number_of_inputs as usize, neurons.len());
+
+        if let Some((neuron, wrong_neuron)) = neurons
+            .iter()
+            .enumerate()
+            .find(|(_, neuron)| neuron.get_number_of_weights() as usize != expected)
+        {
+            return Err(NeuralNetworksError::WrongNumberOfWeights {
+                neuron,
+                expected,
+                received: wrong_neuron.get_number_of_weights() as usize,
+            });
+        }
+
+        Ok(Layer::<T> {
+            number_of_inputs,
+            weights: neurons
+                .iter()
+                .flat_map(|neuron| neuron.get_weights().iter().cloned())
+                .collect(),
+            biases: neurons.iter().map(|neuron| neuron.get_bias()).collect(),
+            activation_functions: neurons
+                .iter()
+                .map(|neuron| *neuron.get_activation_function())
+                .collect(),
+            output_transform: OutputTransforms::None,
+            recurrence,
+            state: Vec::new(),
+            phantom: PhantomData,
+        })
+    }
+
     pub fn create_layer<U: RandomizerTrait, V: Fn(u32, &mut U) -> T>(
         number_of_inputs: u32,
         number_of_neurons: u32,
         randomizer: &mut U,
         neuron_creator: V,
-    ) -> Layer<T> {
-        Layer::<T> {
+    ) -> Result<Layer<T>, NeuralNetworksError> {
+        Layer::create_recurrent_layer(
+            number_of_inputs,
+            number_of_neurons,
+            Recurrences::None,
+            randomizer,
+            neuron_creator,
+        )
+    }
+
+    pub fn create_recurrent_layer<U: RandomizerTrait, V: Fn(u32, &mut U) -> T>(
+        number_of_inputs: u32,
+        number_of_units: u32,
+        recurrence: Recurrences,
+        randomizer: &mut U,
+        neuron_creator: V,
+    ) -> Result<Layer<T>, NeuralNetworksError> {
+        let number_of_weights = if recurrence.is_none() {
+            number_of_inputs
+        } else {
+            number_of_inputs + number_of_units
+        };
+
+        Layer::new_with_recurrence(
             number_of_inputs,
-            neurons: (0..number_of_neurons)
-                .map(|_| neuron_creator(number_of_inputs, randomizer))
+            (0..number_of_units as usize * recurrence.get_number_of_gates())
+                .map(|_| neuron_creator(number_of_weights, randomizer))
                 .collect(),
+            recurrence,
+        )
+    }
+
+    pub fn get_weights(&self) -> &[f64] {
+        &self.weights
+    }
+
+    pub fn get_biases(&self) -> &[f64] {
+        &self.biases
+    }
+
+    pub fn get_number_of_parameters(&self) -> usize {
+        self.weights.len() + self.biases.len()
+    }
+
+    // Neuron by neuron, the bias of each neuron followed by its weights.
+    pub fn get_parameters(&self) -> Vec<f64> {
+        let mut parameters = Vec::with_capacity(self.get_number_of_parameters());
+
+        for neuron in self.get_neurons() {
+            parameters.push(neuron.get_bias());
+            parameters.extend_from_slice(neuron.get_weights());
+        }
+
+        parameters
+    }
+
+    pub fn set_neuron_weight(
+        &mut self,
+        neuron: usize,
+        index: usize,
+        weight: f64,
+    ) -> Result<(), NeuralNetworksError> {
+        self.get_neuron_mut(neuron)?.set_weight(index, weight)
+    }
+
+    pub fn set_neuron_bias(&mut self, neuron: usize, bias: f64) -> Result<(), NeuralNetworksError> {
+        self.get_neuron_mut(neuron)?.set_bias(bias);
+
+        Ok(())
+    }
+
+    pub fn set_parameters(&mut self, parameters: &[f64]) -> Result<(), NeuralNetworksError> {
+        if parameters.len() != self.get_number_of_parameters() {
+            return Err(NeuralNetworksError::WrongNumberOfParameters {
+                expected: self.get_number_of_parameters(),
+                received: parameters.len(),
+            });
+        }
+
+        let number_of_weights = self.get_number_of_weights_per_neuron() as usize;
+
+        for (mut neuron, neuron_parameters) in self
+            .get_neurons_mut()
+            .into_iter()
+            .zip(parameters.chunks(number_of_weights + 1))
+        {
+            neuron.set_bias(neuron_parameters[0]);
+
+            for (index, weight) in neuron_parameters[1..].iter().enumerate() {
+                neuron.set_weight(index, *weight)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn get_output_transform(&self) -> &OutputTransforms {
+        &self.output_transform
+    }
+
+    pub fn set_output_transform(&mut self, output_transform: OutputTransforms) {
+        self.output_transform = output_transform;
+    }
+
+    pub fn get_recurrence(&self) -> &Recurrences {
+        &self.recurrence
+    }
+
+    pub fn get_state(&self) -> &[f64] {
+        &self.state
+    }
+
+    pub fn set_state(&mut self, state: Vec<f64>) -> Result<(), NeuralNetworksError> {
+        let expected = self
+            .recurrence
+            .get_state_size(self.get_number_of_outputs() as usize);
+
+        if state.len() != expected {
+            return Err(NeuralNetworksError::WrongStateSize {
+                expected,
+                received: state.len(),
+            });
         }
+
+        self.state = state;
+
+        Ok(())
+    }
+
+    fn view_neuron(&self, index: usize) -> NeuronView<'_> {
+        let number_of_weights = self.get_number_of_weights_per_neuron() as usize;
+
+        NeuronView::new(
+            &self.weights[index * number_of_weights..(index + 1) * number_of_weights],
+            self.biases[index],
+            self.activation_functions[index],
+        )
     }
 }
 
@@ -44,42 +296,175 @@ impl<T: NeuronTrait> LayerTrait<T> for Layer<T> {
     }
 
     fn get_number_of_neurons(&self) -> u32 {
-        self.neurons.len() as u32
+        self.biases.len() as u32
+    }
+
+    fn get_number_of_outputs(&self) -> u32 {
+        (self.biases.len() / self.recurrence.get_number_of_gates()) as u32
+    }
+
+    fn get_number_of_weights_per_neuron(&self) -> u32 {
+        self.recurrence
+            .get_number_of_weights_per_neuron(self.number_of_inputs as usize, self.biases.len())
+            as u32
+    }
+
+    fn get_neurons(&self) -> Vec<NeuronView<'_>> {
+        (0..self.biases.len())
+            .map(|index| self.view_neuron(index))
+            .collect()
     }
 
-    fn get_neurons_mut(&mut self) -> &mut Vec<T> {
-        &mut self.neurons
+    fn get_neurons_mut(&mut self) -> Vec<NeuronViewMut<'_>> {
+        let number_of_weights = self.get_number_of_weights_per_neuron() as usize;
+        let mut rows = &mut self.weights[..];
+        let mut neurons = Vec::with_capacity(self.biases.len());
+
+        for (bias, activation_function) in self
+            .biases
+            .iter_mut()
+            .zip(self.activation_functions.iter_mut())
+        {
+            let (row, remaining_rows) = rows.split_at_mut(number_of_weights);
+            rows = remaining_rows;
+
+            neurons.push(NeuronViewMut::new(row, bias, activation_function));
+        }
+
+        neurons
+    }
+
+    fn get_neuron(&self, index: usize) -> Result<NeuronView<'_>, NeuralNetworksError> {
+        if index >= self.biases.len() {
+            return Err(NeuralNetworksError::NeuronOutOfRange {
+                index,
+                number_of_neurons: self.biases.len(),
+            });
+        }
+
+        Ok(self.view_neuron(index))
+    }
+
+    fn get_neuron_mut(&mut self, index: usize) -> Result<NeuronViewMut<'_>, NeuralNetworksError> {
+        let number_of_weights = self.get_number_of_weights_per_neuron() as usize;
+
+        if index >= self.biases.len() {
+            return Err(NeuralNetworksError::NeuronOutOfRange {
+                index,
+                number_of_neurons: self.biases.len(),
+            });
+        }
+
+        Ok(NeuronViewMut::new(
+            &mut self.weights[index * number_of_weights..(index + 1) * number_of_weights],
+            &mut self.biases[index],
+            &mut self.activation_functions[index],
+        ))
     }
 
-    fn get_neuron(&self, index: usize) -> Result<&T, String> {
-        Ok(&self.neurons[index])
+    fn feed_forward(&self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError> {
+        let mut outputs = Vec::with_capacity(self.biases.len());
+
+        self.feed_forward_batch(inputs, &mut outputs)?;
+
+        Ok(outputs)
     }
 
-    fn get_neuron_mut(&mut self, index: usize) -> std::result::Result<&mut T, std::string::String> {
-        Ok(&mut self.neurons[index])
+    fn feed_forward_batch(
+        &self,
+        inputs: &[f64],
+        outputs: &mut Vec<f64>,
+    ) -> Result<(), NeuralNetworksError> {
+        let number_of_inputs = self.number_of_inputs as usize;
+
+        // Sanity check. The inputs hold the samples one after the other.
+        if number_of_inputs == 0
+            || inputs.is_empty()
+            || !inputs.len().is_multiple_of(number_of_inputs)
+        {
+            return Err(NeuralNetworksError::WrongNumberOfInputs {
+                expected: number_of_inputs,
+                received: inputs.len(),
+            });
+        }
+
+        outputs.clear();
+
+        for sample in inputs.chunks(number_of_inputs) {
+            if self.recurrence.is_none() {
+                for ((row, bias), activation_function) in self
+                    .weights
+                    .chunks(number_of_inputs)
+                    .zip(self.biases.iter())
+                    .zip(self.activation_functions.iter())
+                {
+                    outputs.push(activate_neuron(
+                        row.iter()
+                            .zip(sample.iter())
+                            .map(|(w, x)| w * x)
+                            .sum::<f64>()
+                            + bias,
+                        activation_function,
+                    ));
+                }
+            } else {
+                outputs.extend(step_recurrent_layer(
+                    &self.recurrence,
+                    &self.activation_functions,
+                    &self.weights,
+                    &self.biases,
+                    sample,
+                    &mut Vec::new(),
+                ));
+            }
+
+            let number_of_outputs = outputs.len();
+            transform_outputs(
+                &mut outputs[number_of_outputs - self.get_number_of_outputs() as usize..],
+                &self.output_transform,
+            );
+        }
+
+        Ok(())
     }
 
-    fn feed_forward(&self, inputs: &[f64]) -> std::vec::Vec<f64> {
-        // Sanity check
+    fn feed_forward_step(&mut self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError> {
+        if self.recurrence.is_none() {
+            return self.feed_forward(inputs);
+        }
         if inputs.len() != self.number_of_inputs as usize {
-            panic!(
-                "A layer was sent {:?} inputs when it was set up with {:?}",
-                inputs.len(),
-                self.number_of_inputs
-            )
+            return Err(NeuralNetworksError::WrongNumberOfInputs {
+                expected: self.number_of_inputs as usize,
+                received: inputs.len(),
+            });
         }
 
-        self.neurons
-            .iter()
-            .map(|neuron| neuron.activate(&inputs).unwrap())
-            .collect()
+        let mut outputs = step_recurrent_layer(
+            &self.recurrence,
+            &self.activation_functions,
+            &self.weights,
+            &self.biases,
+            inputs,
+            &mut self.state,
+        );
+
+        transform_outputs(&mut outputs, &self.output_transform);
+
+        Ok(outputs)
+    }
+
+    fn reset_state(&mut self) {
+        self.state.clear();
     }
 
-    fn should_crossover<U>(randomizer: &mut U) -> std::result::Result<bool, std::string::String>
+    fn should_crossover<U>(
+        crossover_rate: f64,
+        randomizer: &mut U,
+    ) -> Result<bool, NeuralNetworksError>
     where
         U: RandomizerTrait,
     {
-        Ok(randomizer.generate_float_from_0_to_1() > 1f64 - CROSSOVER_PROBABILITY)
+        Ok(randomizer.generate_float_from_0_to_1() > 1f64 - crossover_rate)
     }
 }
 
@@ -89,10 +474,11 @@ mod tests {
 
     use super::*;
     use layer::randomization::randomizer::Randomizer;
+    use neuron::MutableNeuronTrait;
     use neuron::Neuron;
     use neuron_activation::activation_functions::ActivationFunctions;
 
-    fn setup_layer() -> Layer<Neuron> {
+    fn setup_layer() -> Result<Layer<Neuron>, NeuralNetworksError> {
         let mut randomizer = Randomizer::new();
 
         Layer::<Neuron>::create_layer(3, 2, &mut randomizer, |number_of_inputs, randomizer| {
@@ -101,8 +487,9 @@ mod tests {
     }
 
     #[test]
-    fn test_when_creating_a_layer_it_has_expected_number_of_inputs() -> Result<(), String> {
-        let layer = setup_layer();
+    fn test_when_creating_a_layer_it_has_expected_number_of_inputs(
+    ) -> Result<(), NeuralNetworksError> {
+        let layer = setup_layer()?;
 
         assert_eq!(layer.get_number_of_inputs(), 3);
 
@@ -110,8 +497,9 @@ mod tests {
     }
 
     #[test]
-    fn test_when_creating_a_layer_it_has_expected_number_of_neurons() -> Result<(), String> {
-        let layer = setup_layer();
+    fn test_when_creating_a_layer_it_has_expected_number_of_neurons(
+    ) -> Result<(), NeuralNetworksError> {
+        let layer = setup_layer()?;
 
         assert_eq!(layer.get_number_of_neurons(), 2);
 
@@ -119,24 +507,217 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
-    fn test_when_feed_forwarding_inputs_through_layer_with_wrong_number_of_inputs_it_should_crash()
-    {
-        let layer = setup_layer();
+    fn test_when_feed_forwarding_inputs_through_layer_with_wrong_number_of_inputs_it_should_fail(
+    ) -> Result<(), NeuralNetworksError> {
+        let layer = setup_layer()?;
+
+        assert_eq!(
+            layer.feed_forward(&[1f64, 2f64]),
+            Err(NeuralNetworksError::WrongNumberOfInputs {
+                expected: 3,
+                received: 2
+            })
+        );
 
-        layer.feed_forward(&vec![1f64, 2f64]);
+        Ok(())
     }
 
     #[test]
     fn test_when_feed_forwarding_inputs_through_layer_with_right_amount_of_inputs_it_should_produce_right_amount_of_outputs(
-    ) -> Result<(), String> {
-        let layer = setup_layer();
+    ) -> Result<(), NeuralNetworksError> {
+        let layer = setup_layer()?;
 
-        let outputs = layer.feed_forward(&vec![0f64, 1f64, 0f64]);
+        let outputs = layer.feed_forward(&vec![0f64, 1f64, 0f64])?;
 
         println!("{:?}", outputs);
         assert_eq!(outputs.len(), 2);
 
         Ok(())
     }
+
+    #[test]
+    fn test_feed_forwarding_a_batch_produces_the_same_outputs_as_one_by_one(
+    ) -> Result<(), NeuralNetworksError> {
+        let layer = setup_layer()?;
+
+        let mut outputs = Vec::new();
+
+        layer.feed_forward_batch(&[0f64, 1f64, 0f64, 0.5f64, -0.5f64, 2f64], &mut outputs)?;
+
+        assert_eq!(outputs.len(), 4);
+        assert_eq!(outputs[0..2], layer.feed_forward(&[0f64, 1f64, 0f64])?[..]);
+        assert_eq!(
+            outputs[2..4],
+            layer.feed_forward(&[0.5f64, -0.5f64, 2f64])?[..]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_changing_a_neuron_through_the_layer_updates_the_weight_matrix(
+    ) -> Result<(), NeuralNetworksError> {
+        let mut layer = setup_layer()?;
+
+        layer.get_neuron_mut(1)?.set_weight(2, 7f64)?;
+        layer.get_neuron_mut(1)?.set_bias(3f64);
+
+        assert_eq!(layer.get_weights()[5], 7f64);
+        assert_eq!(layer.get_biases()[1], 3f64);
+
+        for neuron in layer.get_neurons_mut().iter_mut() {
+            neuron.set_weight(0, -1f64)?;
+        }
+
+        assert_eq!(layer.get_weights()[0], -1f64);
+        assert_eq!(layer.get_weights()[3], -1f64);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_setting_single_weights_and_biases_updates_the_neuron_and_the_matrix(
+    ) -> Result<(), NeuralNetworksError> {
+        let mut layer = setup_layer()?;
+
+        layer.set_neuron_weight(1, 2, 7f64)?;
+        layer.set_neuron_bias(0, -2f64)?;
+
+        assert_eq!(layer.get_weights()[5], 7f64);
+        assert_eq!(layer.get_neuron(1)?.get_weight(2)?, 7f64);
+        assert_eq!(layer.get_biases()[0], -2f64);
+        assert_eq!(layer.get_neuron(0)?.get_bias(), -2f64);
+
+        assert!(layer.set_neuron_weight(1, 3, 0f64).is_err());
+        assert!(layer.set_neuron_bias(2, 0f64).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_a_neuron_with_the_wrong_number_of_weights_is_rejected_when_building_the_layer(
+    ) -> Result<(), NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+        let neurons = vec![
+            Neuron::new(3, ActivationFunctions::Sigmoid, &mut randomizer),
+            Neuron::new(2, ActivationFunctions::Sigmoid, &mut randomizer),
+        ];
+
+        assert_eq!(
+            Layer::new(3, neurons).err(),
+            Some(NeuralNetworksError::WrongNumberOfWeights {
+                neuron: 1,
+                expected: 3,
+                received: 2,
+            })
+        );
+
+        let mut serialized = serde_json::to_value(&setup_layer()?).unwrap();
+        serialized["number_of_inputs"] = serde_json::json!(2);
+
+        assert!(serde_json::from_value::<Layer<Neuron>>(serialized).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_a_softmax_layer_outputs_probabilities_and_keeps_its_transform_when_serialized(
+    ) -> Result<(), NeuralNetworksError> {
+        let mut layer = setup_layer()?;
+
+        assert!(!serde_json::to_string(&layer)
+            .unwrap()
+            .contains("output_transform"));
+
+        layer.set_output_transform(OutputTransforms::Softmax);
+
+        let mut outputs = Vec::new();
+        layer.feed_forward_batch(&[1.0, -2.0, 0.5, 3.0, 0.0, -1.0], &mut outputs)?;
+
+        assert!((outputs[0] + outputs[1] - 1.0).abs() < 1e-12);
+        assert!((outputs[2] + outputs[3] - 1.0).abs() < 1e-12);
+
+        let deserialized: Layer<Neuron> =
+            serde_json::from_str(&serde_json::to_string(&layer).unwrap()).unwrap();
+
+        assert_eq!(
+            deserialized.get_output_transform(),
+            &OutputTransforms::Softmax
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_recurrent_layers_keep_their_state_between_steps_until_reset(
+    ) -> Result<(), NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+
+        for (recurrence, state_size) in &[(Recurrences::Gru, 2), (Recurrences::Lstm, 4)] {
+            let mut layer = Layer::<Neuron>::create_recurrent_layer(
+                3,
+                2,
+                *recurrence,
+                &mut randomizer,
+                |number_of_inputs, randomizer| {
+                    Neuron::new(number_of_inputs, ActivationFunctions::Tanh, randomizer)
+                },
+            )?;
+
+            assert_eq!(layer.get_number_of_outputs(), 2);
+            assert_eq!(
+                layer.get_number_of_neurons() as usize,
+                2 * recurrence.get_number_of_gates()
+            );
+            assert_eq!(layer.get_number_of_weights_per_neuron(), 5);
+
+            let inputs = [0.4, -1.0, 0.8];
+
+            let first = layer.feed_forward_step(&inputs)?;
+            let second = layer.feed_forward_step(&inputs)?;
+
+            assert_eq!(layer.get_state().len(), *state_size);
+            assert_ne!(first, second);
+            assert_eq!(layer.feed_forward(&inputs)?, first);
+
+            let deserialized: Layer<Neuron> =
+                serde_json::from_str(&serde_json::to_string(&layer).unwrap()).unwrap();
+
+            assert_eq!(deserialized.get_recurrence(), recurrence);
+            assert!(deserialized.get_state().is_empty());
+
+            layer.reset_state();
+
+            assert_eq!(layer.feed_forward_step(&inputs)?, first);
+            assert!(layer.set_state(vec![0.0]).is_err());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_deserializing_a_layer_rebuilds_the_weight_matrix() -> Result<(), NeuralNetworksError> {
+        let layer = setup_layer()?;
+
+        let serialized = serde_json::to_string(&layer).unwrap();
+
+        assert!(!serialized.contains("biases"));
+
+        let deserialized: Layer<Neuron> = serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(deserialized.get_weights().len(), 6);
+
+        for (index, neuron) in deserialized.get_neurons().iter().enumerate() {
+            assert_eq!(deserialized.get_biases()[index], neuron.get_bias());
+
+            for weight_index in 0..3 {
+                assert_eq!(
+                    deserialized.get_weights()[index * 3 + weight_index],
+                    neuron.get_weight(weight_index)?
+                );
+            }
+        }
+
+        Ok(())
+    }
 }
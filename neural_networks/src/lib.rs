@@ -1,6 +1,19 @@
+pub mod action_selection;
+pub mod backpropagation;
 pub mod evolution;
+pub mod evolution_strategies;
 pub mod get_index_max_output;
 pub mod layer;
+pub mod named_io;
+pub mod neat;
+pub mod network_layers;
 pub mod neural_network;
+pub mod neural_networks_error;
 pub mod neuron;
 pub mod neuron_activation;
+pub mod neuron_initialization;
+pub mod neuron_view;
+pub mod neuron_view_mut;
+pub mod output_transformation;
+pub mod parameter_layout;
+pub mod recurrence;
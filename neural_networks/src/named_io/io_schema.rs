@@ -0,0 +1,230 @@
+extern crate serde;
+
+use self::serde::{Deserialize, Serialize};
+use named_io::named_value::NamedValue;
+use neural_networks_error::NeuralNetworksError;
+use std::collections::HashMap;
+
+// Names every input and output of a neural network by its position, so callers don't need to
+// know which index stands for what, and a network trained for other inputs gets caught when
+// loaded rather than quietly producing nonsense.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IoSchema {
+    inputs: Vec<NamedValue>,
+    outputs: Vec<NamedValue>,
+}
+
+impl IoSchema {
+    pub fn new(
+        inputs: Vec<NamedValue>,
+        outputs: Vec<NamedValue>,
+    ) -> Result<Self, NeuralNetworksError> {
+        let io_schema = IoSchema { inputs, outputs };
+
+        io_schema.validate()?;
+
+        Ok(io_schema)
+    }
+
+    pub fn get_inputs(&self) -> &[NamedValue] {
+        &self.inputs
+    }
+
+    pub fn get_outputs(&self) -> &[NamedValue] {
+        &self.outputs
+    }
+
+    pub fn get_number_of_inputs(&self) -> usize {
+        self.inputs.len()
+    }
+
+    pub fn get_number_of_outputs(&self) -> usize {
+        self.outputs.len()
+    }
+
+    // Names have to be unique among the inputs and among the outputs, and ranges can't be empty.
+    pub fn validate(&self) -> Result<(), NeuralNetworksError> {
+        for named_values in [&self.inputs, &self.outputs].iter() {
+            for (index, named_value) in named_values.iter().enumerate() {
+                if named_values[..index]
+                    .iter()
+                    .any(|previous| previous.get_name() == named_value.get_name())
+                {
+                    return Err(NeuralNetworksError::RepeatedName(
+                        named_value.get_name().to_string(),
+                    ));
+                }
+                if named_value.get_minimum().is_nan()
+                    || named_value.get_maximum().is_nan()
+                    || named_value.get_minimum() > named_value.get_maximum()
+                {
+                    return Err(NeuralNetworksError::EmptyRange {
+                        name: named_value.get_name().to_string(),
+                        minimum: named_value.get_minimum(),
+                        maximum: named_value.get_maximum(),
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn check_matches(&self, expected: &IoSchema) -> Result<(), NeuralNetworksError> {
+        if self != expected {
+            let get_names = |named_values: &[NamedValue]| -> Vec<String> {
+                named_values
+                    .iter()
+                    .map(|named_value| named_value.get_name().to_string())
+                    .collect()
+            };
+
+            return Err(NeuralNetworksError::IoSchemaMismatch {
+                inputs: get_names(&self.inputs),
+                outputs: get_names(&self.outputs),
+                expected_inputs: get_names(&expected.inputs),
+                expected_outputs: get_names(&expected.outputs),
+            });
+        }
+
+        Ok(())
+    }
+
+    // Puts the named inputs in the order the neural network takes them in.
+    pub fn order_inputs(
+        &self,
+        named_inputs: &HashMap<&str, f64>,
+    ) -> Result<Vec<f64>, NeuralNetworksError> {
+        if let Some(unknown_name) = named_inputs
+            .keys()
+            .find(|name| !self.inputs.iter().any(|input| input.get_name() == **name))
+        {
+            return Err(NeuralNetworksError::UnknownNamedInput(
+                unknown_name.to_string(),
+            ));
+        }
+
+        self.inputs
+            .iter()
+            .map(|input| {
+                let value = *named_inputs.get(input.get_name()).ok_or_else(|| {
+                    NeuralNetworksError::MissingNamedInput(input.get_name().to_string())
+                })?;
+
+                if !input.contains(value) {
+                    return Err(NeuralNetworksError::NamedInputOutOfRange {
+                        name: input.get_name().to_string(),
+                        value,
+                    });
+                }
+
+                Ok(value)
+            })
+            .collect()
+    }
+
+    pub fn name_outputs(
+        &self,
+        outputs: &[f64],
+    ) -> Result<HashMap<String, f64>, NeuralNetworksError> {
+        if outputs.len() != self.outputs.len() {
+            return Err(NeuralNetworksError::WrongNumberOfOutputs {
+                expected: self.outputs.len(),
+                received: outputs.len(),
+            });
+        }
+
+        Ok(self
+            .outputs
+            .iter()
+            .zip(outputs.iter())
+            .map(|(output, value)| (output.get_name().to_string(), *value))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn setup_io_schema() -> Result<IoSchema, NeuralNetworksError> {
+        IoSchema::new(
+            vec![
+                NamedValue::new("Hunger", 0.0, 1.0),
+                NamedValue::new("Distance", 0.0, 100.0),
+            ],
+            vec![NamedValue::new_unbounded("Eat")],
+        )
+    }
+
+    #[test]
+    fn test_named_inputs_get_ordered_and_checked_against_the_schema(
+    ) -> Result<(), NeuralNetworksError> {
+        let io_schema = setup_io_schema()?;
+
+        let named_inputs: HashMap<&str, f64> = [("Distance", 40.0), ("Hunger", 0.5)]
+            .iter()
+            .cloned()
+            .collect();
+
+        assert_eq!(io_schema.order_inputs(&named_inputs)?, vec![0.5, 40.0]);
+
+        let missing: HashMap<&str, f64> = [("Hunger", 0.5)].iter().cloned().collect();
+        assert_eq!(
+            io_schema.order_inputs(&missing),
+            Err(NeuralNetworksError::MissingNamedInput(
+                "Distance".to_string()
+            ))
+        );
+
+        let unknown: HashMap<&str, f64> = [("Hunger", 0.5), ("Distance", 1.0), ("Thirst", 1.0)]
+            .iter()
+            .cloned()
+            .collect();
+        assert_eq!(
+            io_schema.order_inputs(&unknown),
+            Err(NeuralNetworksError::UnknownNamedInput("Thirst".to_string()))
+        );
+
+        let out_of_range: HashMap<&str, f64> = [("Hunger", 2.0), ("Distance", 1.0)]
+            .iter()
+            .cloned()
+            .collect();
+        assert_eq!(
+            io_schema.order_inputs(&out_of_range),
+            Err(NeuralNetworksError::NamedInputOutOfRange {
+                name: "Hunger".to_string(),
+                value: 2.0
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_duplicate_names_and_mismatched_schemas_get_rejected() -> Result<(), NeuralNetworksError>
+    {
+        assert_eq!(
+            IoSchema::new(
+                vec![NamedValue::new("Hunger", 0.0, 1.0); 2],
+                vec![NamedValue::new_unbounded("Eat")],
+            ),
+            Err(NeuralNetworksError::RepeatedName("Hunger".to_string()))
+        );
+
+        let io_schema = setup_io_schema()?;
+        let swapped = IoSchema::new(
+            vec![
+                NamedValue::new("Distance", 0.0, 100.0),
+                NamedValue::new("Hunger", 0.0, 1.0),
+            ],
+            vec![NamedValue::new_unbounded("Eat")],
+        )?;
+
+        assert!(io_schema.check_matches(&io_schema.clone()).is_ok());
+        assert!(io_schema.check_matches(&swapped).is_err());
+
+        Ok(())
+    }
+}
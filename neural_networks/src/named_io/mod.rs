@@ -0,0 +1,2 @@
+pub mod io_schema;
+pub mod named_value;
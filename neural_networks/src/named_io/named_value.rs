@@ -0,0 +1,40 @@
+extern crate serde;
+
+use self::serde::{Deserialize, Serialize};
+
+// One input or output of a neural network, with the range its values are expected to fall in.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NamedValue {
+    name: String,
+    range: [f64; 2],
+}
+
+impl NamedValue {
+    pub fn new(name: &str, minimum: f64, maximum: f64) -> Self {
+        NamedValue {
+            name: name.to_string(),
+            range: [minimum, maximum],
+        }
+    }
+
+    // For values, such as scores compared against each other, that have no meaningful bounds.
+    pub fn new_unbounded(name: &str) -> Self {
+        Self::new(name, f64::MIN, f64::MAX)
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_minimum(&self) -> f64 {
+        self.range[0]
+    }
+
+    pub fn get_maximum(&self) -> f64 {
+        self.range[1]
+    }
+
+    pub fn contains(&self, value: f64) -> bool {
+        value >= self.range[0] && value <= self.range[1]
+    }
+}
@@ -0,0 +1,263 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use evolution::domain::genome::Genome;
+use evolution::domain::genome::GenomeTrait;
+use evolution::domain::population::Population;
+use evolution::domain::population::PopulationTrait;
+use neat::domain::crossover_neat_networks::crossover_neat_networks;
+use neat::domain::innovation_tracker::InnovationTracker;
+use neat::domain::mutate_neat_network::mutate_neat_network;
+use neat::domain::neat_genome::NeatGenome;
+use neat::domain::neat_network::NeatNetwork;
+use neat::domain::neat_parameters::NeatParameters;
+use neat::domain::speciate_population::speciate_population;
+use neat::domain::species::Species;
+use neural_networks_error::NeuralNetworksError;
+use neuron::Neuron;
+
+type NeatPopulation = Population<NeatGenome, NeatNetwork, Neuron>;
+
+// Each species receives a share of the next generation proportional to the average fitness of its
+// members (fitness sharing), keeps its champion untouched, and breeds the rest of its share from its
+// best members. Unlike create_next_generation, the population doesn't need to be ordered by fitness.
+pub fn create_next_neat_generation<T: RandomizerTrait>(
+    population: &NeatPopulation,
+    species: &mut Vec<Species>,
+    innovation_tracker: &mut InnovationTracker,
+    neat_parameters: &NeatParameters,
+    randomizer: &mut T,
+) -> Result<NeatPopulation, NeuralNetworksError> {
+    let genomes = population.get_genomes()?;
+
+    if genomes.is_empty() {
+        return Err(NeuralNetworksError::EmptyPopulation);
+    }
+
+    speciate_population(genomes, species, neat_parameters)?;
+
+    let offspring_per_species = distribute_offspring(genomes, species);
+
+    let mut next_generation = Population::new();
+
+    for (existing_species, number_of_offspring) in species.iter_mut().zip(offspring_per_species) {
+        let mut members = existing_species.get_members().clone();
+
+        members.sort_by(|a, b| {
+            genomes[*b]
+                .get_fitness()
+                .partial_cmp(&genomes[*a].get_fitness())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let representative = randomizer.choose_random_from_vec(&members);
+        existing_species.set_representative(genomes[representative].get_neural_network().clone());
+
+        if number_of_offspring == 0 {
+            continue;
+        }
+
+        next_generation.add(Genome::new(
+            0,
+            genomes[members[0]].get_neural_network().clone(),
+        ))?;
+
+        let number_of_survivors =
+            ((members.len() as f64 * neat_parameters.get_survival_threshold()).ceil() as usize)
+                .max(1);
+        let survivors = &members[0..number_of_survivors];
+
+        for _ in 1..number_of_offspring {
+            let first = randomizer.choose_random_from_vec(survivors);
+            let second = randomizer.choose_random_from_vec(survivors);
+
+            let (fitter, other) = if genomes[first].get_fitness() >= genomes[second].get_fitness() {
+                (first, second)
+            } else {
+                (second, first)
+            };
+
+            let mut child = crossover_neat_networks(
+                genomes[fitter].get_neural_network(),
+                genomes[other].get_neural_network(),
+                randomizer,
+            )?;
+
+            mutate_neat_network(&mut child, innovation_tracker, neat_parameters, randomizer)?;
+
+            next_generation.add(Genome::new(0, child))?;
+        }
+    }
+
+    // Give new identifiers to the genomes.
+    for (index, genome) in next_generation.get_genomes_mut()?.iter_mut().enumerate() {
+        genome.set_identifier(index as u32 + 1);
+    }
+
+    Ok(next_generation)
+}
+
+fn distribute_offspring(genomes: &[NeatGenome], species: &[Species]) -> Vec<usize> {
+    let population_size = genomes.len();
+
+    // Negative fitnesses would make the shares meaningless.
+    let shared_fitnesses: Vec<f64> = species
+        .iter()
+        .map(|existing_species| {
+            existing_species
+                .get_members()
+                .iter()
+                .map(|member| genomes[*member].get_fitness().max(0f64))
+                .sum::<f64>()
+                / existing_species.get_members().len() as f64
+        })
+        .collect();
+
+    let total_shared_fitness: f64 = shared_fitnesses.iter().sum();
+
+    let mut offspring: Vec<usize> = species
+        .iter()
+        .zip(shared_fitnesses.iter())
+        .map(|(existing_species, shared_fitness)| {
+            if total_shared_fitness > 0f64 {
+                (shared_fitness / total_shared_fitness * population_size as f64).floor() as usize
+            } else {
+                existing_species.get_members().len()
+            }
+        })
+        .collect();
+
+    // Whatever got lost when rounding down goes to the species with the highest shared fitness first.
+    let mut ranking: Vec<usize> = (0..species.len()).collect();
+    ranking.sort_by(|a, b| {
+        shared_fitnesses[*b]
+            .partial_cmp(&shared_fitnesses[*a])
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let mut position = 0;
+
+    while offspring.iter().sum::<usize>() < population_size {
+        offspring[ranking[position % ranking.len()]] += 1;
+        position += 1;
+    }
+
+    offspring
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use neural_network::NeuralNetworkTrait;
+    use neuron_activation::activation_functions::ActivationFunctions;
+
+    use self::randomization::randomizer::Randomizer;
+
+    fn setup_neat_population(
+        randomizer: &mut Randomizer,
+    ) -> Result<NeatPopulation, NeuralNetworksError> {
+        let mut population = Population::new();
+
+        for genome_identifier in 1..=12 {
+            population.add(Genome::new(
+                genome_identifier,
+                NeatNetwork::new_with_specified_layers(
+                    &[[2, 1]],
+                    randomizer,
+                    |number_of_inputs, randomizer| {
+                        Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+                    },
+                )?,
+            ))?;
+        }
+
+        Ok(population)
+    }
+
+    #[test]
+    fn test_next_neat_generation_keeps_size_and_gives_unique_identifiers(
+    ) -> Result<(), NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+
+        let mut population = setup_neat_population(&mut randomizer)?;
+
+        let mut species = Vec::new();
+        let mut innovation_tracker = InnovationTracker::new(2, 1);
+        let mut neat_parameters = NeatParameters::new();
+        neat_parameters.set_add_node_probability(0.5);
+        neat_parameters.set_add_connection_probability(0.5);
+
+        for generation in 0..10 {
+            for genome in population.get_genomes_mut()?.iter_mut() {
+                let outputs = genome.get_neural_network().propagate(&[1.0, 0.0])?;
+                genome.set_fitness(outputs[0] + generation as f64);
+            }
+
+            population = create_next_neat_generation(
+                &population,
+                &mut species,
+                &mut innovation_tracker,
+                &neat_parameters,
+                &mut randomizer,
+            )?;
+
+            assert_eq!(population.get_size(), 12);
+            assert!(!species.is_empty());
+        }
+
+        let mut identifiers: Vec<u32> = population
+            .get_genomes()?
+            .iter()
+            .map(|genome| genome.get_identifier())
+            .collect();
+        identifiers.dedup();
+
+        assert_eq!(identifiers, (1..=12).collect::<Vec<u32>>());
+
+        assert!(population.get_genomes()?.iter().any(|genome| genome
+            .get_neural_network()
+            .get_nodes()
+            .len()
+            > 3));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_the_champion_of_a_species_survives_unchanged() -> Result<(), NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+
+        let mut population = setup_neat_population(&mut randomizer)?;
+
+        population.get_genome_mut(5)?.set_fitness(100.0);
+
+        let champion_weights: Vec<f64> = population
+            .get_genome(5)?
+            .get_neural_network()
+            .get_connections()
+            .iter()
+            .map(|connection| connection.get_weight())
+            .collect();
+
+        let next_generation = create_next_neat_generation(
+            &population,
+            &mut Vec::new(),
+            &mut InnovationTracker::new(2, 1),
+            &NeatParameters::new(),
+            &mut randomizer,
+        )?;
+
+        assert!(next_generation.get_genomes()?.iter().any(|genome| {
+            genome
+                .get_neural_network()
+                .get_connections()
+                .iter()
+                .map(|connection| connection.get_weight())
+                .collect::<Vec<f64>>()
+                == champion_weights
+        }));
+
+        Ok(())
+    }
+}
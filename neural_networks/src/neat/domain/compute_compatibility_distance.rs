@@ -0,0 +1,130 @@
+use neat::domain::neat_network::NeatNetwork;
+use neat::domain::neat_parameters::NeatParameters;
+
+// Below this many genes the excess and disjoint counts don't get normalized, as in the original paper.
+const SMALL_GENOME_SIZE: usize = 20;
+
+pub fn compute_compatibility_distance(
+    first: &NeatNetwork,
+    second: &NeatNetwork,
+    neat_parameters: &NeatParameters,
+) -> f64 {
+    let highest_innovation = |neat_network: &NeatNetwork| {
+        neat_network
+            .get_connections()
+            .iter()
+            .map(|connection| connection.get_innovation_number())
+            .max()
+    };
+
+    let first_highest = highest_innovation(first);
+    let second_highest = highest_innovation(second);
+
+    let mut excess = 0;
+    let mut disjoint = 0;
+    let mut matching = 0;
+    let mut weight_difference = 0f64;
+
+    // Genes beyond the highest innovation number of the other network are excess; the rest of the
+    // unmatched ones are disjoint.
+    let mut count_unmatched = |neat_network: &NeatNetwork,
+                               other: &NeatNetwork,
+                               other_highest: Option<u32>,
+                               count_matching: bool| {
+        for connection in neat_network.get_connections() {
+            match other
+                .get_connections()
+                .iter()
+                .find(|o| o.get_innovation_number() == connection.get_innovation_number())
+            {
+                Some(other_connection) => {
+                    if count_matching {
+                        matching += 1;
+                        weight_difference +=
+                            (connection.get_weight() - other_connection.get_weight()).abs();
+                    }
+                }
+                None => {
+                    if other_highest
+                        .is_none_or(|highest| connection.get_innovation_number() > highest)
+                    {
+                        excess += 1;
+                    } else {
+                        disjoint += 1;
+                    }
+                }
+            }
+        }
+    };
+
+    count_unmatched(first, second, second_highest, true);
+    count_unmatched(second, first, first_highest, false);
+
+    let largest_size = first
+        .get_connections()
+        .len()
+        .max(second.get_connections().len());
+
+    let normalizer = if largest_size < SMALL_GENOME_SIZE {
+        1f64
+    } else {
+        largest_size as f64
+    };
+
+    let average_weight_difference = if matching > 0 {
+        weight_difference / matching as f64
+    } else {
+        0f64
+    };
+
+    neat_parameters.get_excess_coefficient() * excess as f64 / normalizer
+        + neat_parameters.get_disjoint_coefficient() * disjoint as f64 / normalizer
+        + neat_parameters.get_weight_difference_coefficient() * average_weight_difference
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use neat::domain::innovation_tracker::InnovationTracker;
+    use neat::domain::mutate_add_node::mutate_add_node;
+    use neuron::Neuron;
+    use neuron_activation::activation_functions::ActivationFunctions;
+
+    extern crate randomization;
+    use self::randomization::randomizer::Randomizer;
+    use neural_networks_error::NeuralNetworksError;
+
+    #[test]
+    fn test_structural_differences_increase_the_compatibility_distance(
+    ) -> Result<(), NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+        let mut innovation_tracker = InnovationTracker::new(3, 1);
+        let neat_parameters = NeatParameters::new();
+
+        let neat_network = NeatNetwork::new_with_specified_layers(
+            &[[3, 1]],
+            &mut randomizer,
+            |number_of_inputs, randomizer| {
+                Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+            },
+        )?;
+
+        assert_eq!(
+            compute_compatibility_distance(&neat_network, &neat_network, &neat_parameters),
+            0f64
+        );
+
+        let mut grown_network = neat_network.clone();
+
+        mutate_add_node(&mut grown_network, &mut innovation_tracker, &mut randomizer)?;
+
+        // The two new connections are excess genes.
+        assert_eq!(
+            compute_compatibility_distance(&neat_network, &grown_network, &neat_parameters),
+            2f64
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+extern crate serde;
+
+use self::serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectionGene {
+    innovation_number: u32,
+    from: u32,
+    to: u32,
+    weight: f64,
+    enabled: bool,
+}
+
+impl ConnectionGene {
+    pub fn new(innovation_number: u32, from: u32, to: u32, weight: f64) -> Self {
+        ConnectionGene {
+            innovation_number,
+            from,
+            to,
+            weight,
+            enabled: true,
+        }
+    }
+
+    pub fn get_innovation_number(&self) -> u32 {
+        self.innovation_number
+    }
+
+    pub fn get_from(&self) -> u32 {
+        self.from
+    }
+
+    pub fn get_to(&self) -> u32 {
+        self.to
+    }
+
+    pub fn get_weight(&self) -> f64 {
+        self.weight
+    }
+
+    pub fn set_weight(&mut self, weight: f64) {
+        self.weight = weight;
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+}
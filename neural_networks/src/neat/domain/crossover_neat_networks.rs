@@ -0,0 +1,105 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use neat::domain::neat_network::NeatNetwork;
+use neural_networks_error::NeuralNetworksError;
+
+// Chance that a gene disabled in either parent stays disabled in the child.
+const PROBABILITY_OF_INHERITING_DISABLED_GENE: f64 = 0.75;
+
+// The child inherits the topology of the fitter parent. Genes that both parents share (same
+// innovation number) take their values from either parent at random.
+pub fn crossover_neat_networks<T: RandomizerTrait>(
+    fitter_parent: &NeatNetwork,
+    other_parent: &NeatNetwork,
+    randomizer: &mut T,
+) -> Result<NeatNetwork, NeuralNetworksError> {
+    let mut connections = fitter_parent.get_connections().clone();
+
+    for connection in connections.iter_mut() {
+        if let Some(matching) = other_parent
+            .get_connections()
+            .iter()
+            .find(|other| other.get_innovation_number() == connection.get_innovation_number())
+        {
+            let was_disabled = !connection.is_enabled() || !matching.is_enabled();
+
+            if randomizer.generate_float_from_0_to_1() < 0.5 {
+                connection.set_weight(matching.get_weight());
+            }
+
+            connection.set_enabled(
+                !was_disabled
+                    || randomizer.generate_float_from_0_to_1()
+                        >= PROBABILITY_OF_INHERITING_DISABLED_GENE,
+            );
+        }
+    }
+
+    let mut nodes = fitter_parent.get_nodes().clone();
+
+    for node in nodes.iter_mut() {
+        if let Some(matching) = other_parent.get_node(node.get_identifier()) {
+            if randomizer.generate_float_from_0_to_1() < 0.5 {
+                node.set_bias(matching.get_bias());
+                node.set_activation_function(*matching.get_activation_function());
+            }
+        }
+    }
+
+    NeatNetwork::new(
+        fitter_parent.get_number_of_inputs(),
+        fitter_parent.get_number_of_outputs(),
+        nodes,
+        connections,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use neat::domain::innovation_tracker::InnovationTracker;
+    use neat::domain::mutate_add_node::mutate_add_node;
+    use neural_network::NeuralNetworkTrait;
+    use neuron::Neuron;
+    use neuron_activation::activation_functions::ActivationFunctions;
+
+    use self::randomization::randomizer::Randomizer;
+
+    #[test]
+    fn test_child_inherits_the_topology_of_the_fitter_parent() -> Result<(), NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+        let mut innovation_tracker = InnovationTracker::new(2, 2);
+
+        let neuron_creator = |number_of_inputs, randomizer: &mut Randomizer| {
+            Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+        };
+
+        let mut fitter_parent =
+            NeatNetwork::new_with_specified_layers(&[[2, 2]], &mut randomizer, neuron_creator)?;
+        let other_parent =
+            NeatNetwork::new_with_specified_layers(&[[2, 2]], &mut randomizer, neuron_creator)?;
+
+        mutate_add_node(&mut fitter_parent, &mut innovation_tracker, &mut randomizer)?;
+
+        let child = crossover_neat_networks(&fitter_parent, &other_parent, &mut randomizer)?;
+
+        let innovation_numbers = |neat_network: &NeatNetwork| -> Vec<u32> {
+            neat_network
+                .get_connections()
+                .iter()
+                .map(|connection| connection.get_innovation_number())
+                .collect()
+        };
+
+        assert_eq!(
+            innovation_numbers(&child),
+            innovation_numbers(&fitter_parent)
+        );
+        assert_eq!(child.get_nodes().len(), fitter_parent.get_nodes().len());
+        assert_eq!(child.propagate(&[0.2, 0.8])?.len(), 2);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+extern crate serde;
+
+use self::serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// Hands out the innovation numbers shared by every genome of a training run. The same structural
+// change (a connection between the same two nodes, or splitting the same connection) always receives
+// the same number, which is what allows crossover to line up the genes of both parents.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InnovationTracker {
+    next_innovation_number: u32,
+    next_node_identifier: u32,
+    connections: HashMap<String, u32>,
+    split_connections: HashMap<u32, u32>,
+}
+
+fn connection_key(from: u32, to: u32) -> String {
+    // Keys are strings so that the tracker can be stored as JSON.
+    format!("{}-{}", from, to)
+}
+
+impl InnovationTracker {
+    // The initial networks connect every input to every output, and those connections receive the
+    // innovation numbers in the same order NeatNetwork::new_with_specified_layers creates them.
+    pub fn new(number_of_inputs: u32, number_of_outputs: u32) -> Self {
+        let mut innovation_tracker = InnovationTracker {
+            next_innovation_number: 0,
+            next_node_identifier: number_of_inputs + number_of_outputs,
+            connections: HashMap::new(),
+            split_connections: HashMap::new(),
+        };
+
+        for input in 0..number_of_inputs {
+            for output in 0..number_of_outputs {
+                innovation_tracker.get_innovation_number(input, number_of_inputs + output);
+            }
+        }
+
+        innovation_tracker
+    }
+
+    pub fn get_innovation_number(&mut self, from: u32, to: u32) -> u32 {
+        let next_innovation_number = &mut self.next_innovation_number;
+
+        *self
+            .connections
+            .entry(connection_key(from, to))
+            .or_insert_with(|| {
+                *next_innovation_number += 1;
+                *next_innovation_number - 1
+            })
+    }
+
+    pub fn get_node_identifier_for_split(&mut self, innovation_number: u32) -> u32 {
+        let next_node_identifier = &mut self.next_node_identifier;
+
+        *self
+            .split_connections
+            .entry(innovation_number)
+            .or_insert_with(|| {
+                *next_node_identifier += 1;
+                *next_node_identifier - 1
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_the_same_structural_change_receives_the_same_numbers() {
+        let mut innovation_tracker = InnovationTracker::new(2, 1);
+
+        assert_eq!(innovation_tracker.get_innovation_number(0, 2), 0);
+        assert_eq!(innovation_tracker.get_innovation_number(1, 2), 1);
+
+        let new_connection = innovation_tracker.get_innovation_number(3, 2);
+
+        assert_eq!(new_connection, 2);
+        assert_eq!(
+            innovation_tracker.get_innovation_number(3, 2),
+            new_connection
+        );
+
+        assert_eq!(innovation_tracker.get_node_identifier_for_split(0), 3);
+        assert_eq!(innovation_tracker.get_node_identifier_for_split(1), 4);
+        assert_eq!(innovation_tracker.get_node_identifier_for_split(0), 3);
+    }
+}
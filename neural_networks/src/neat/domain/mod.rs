@@ -0,0 +1,15 @@
+pub mod compute_compatibility_distance;
+pub mod connection_gene;
+pub mod crossover_neat_networks;
+pub mod innovation_tracker;
+pub mod mutate_add_connection;
+pub mod mutate_add_node;
+pub mod mutate_neat_network;
+pub mod mutate_neat_weights;
+pub mod neat_genome;
+pub mod neat_network;
+pub mod neat_parameters;
+pub mod node_gene;
+pub mod node_kinds;
+pub mod speciate_population;
+pub mod species;
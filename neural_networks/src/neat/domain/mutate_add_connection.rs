@@ -0,0 +1,60 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use neat::domain::connection_gene::ConnectionGene;
+use neat::domain::innovation_tracker::InnovationTracker;
+use neat::domain::neat_network::NeatNetwork;
+use neat::domain::node_kinds::NodeKinds;
+use neural_networks_error::NeuralNetworksError;
+
+// How many random pairs of nodes get tried before giving up on finding a valid new connection.
+const ATTEMPTS_TO_FIND_CONNECTION: u32 = 20;
+
+pub fn mutate_add_connection<T: RandomizerTrait>(
+    neat_network: &mut NeatNetwork,
+    innovation_tracker: &mut InnovationTracker,
+    randomizer: &mut T,
+) -> Result<bool, NeuralNetworksError> {
+    let sources: Vec<u32> = neat_network
+        .get_nodes()
+        .iter()
+        .filter(|node| node.get_kind() != NodeKinds::Output)
+        .map(|node| node.get_identifier())
+        .collect();
+    let destinations: Vec<u32> = neat_network
+        .get_nodes()
+        .iter()
+        .filter(|node| node.get_kind() != NodeKinds::Input)
+        .map(|node| node.get_identifier())
+        .collect();
+
+    if sources.is_empty() || destinations.is_empty() {
+        return Ok(false);
+    }
+
+    for _ in 0..ATTEMPTS_TO_FIND_CONNECTION {
+        let from = randomizer.choose_random_from_vec(&sources);
+        let to = randomizer.choose_random_from_vec(&destinations);
+
+        // The new connection can't close a cycle, or the network couldn't be fed forward anymore.
+        if from == to
+            || neat_network.has_connection(from, to)
+            || neat_network.is_reachable(to, from)
+        {
+            continue;
+        }
+
+        let innovation_number = innovation_tracker.get_innovation_number(from, to);
+
+        neat_network.add_connection(ConnectionGene::new(
+            innovation_number,
+            from,
+            to,
+            randomizer.get_normal(),
+        ))?;
+
+        return Ok(true);
+    }
+
+    Ok(false)
+}
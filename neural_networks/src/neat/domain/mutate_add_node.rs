@@ -0,0 +1,140 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use neat::domain::connection_gene::ConnectionGene;
+use neat::domain::innovation_tracker::InnovationTracker;
+use neat::domain::neat_network::NeatNetwork;
+use neat::domain::node_gene::NodeGene;
+use neat::domain::node_kinds::NodeKinds;
+use neural_networks_error::NeuralNetworksError;
+use neuron_activation::choose_random_activation_function::choose_random_activation_function;
+
+pub fn mutate_add_node<T: RandomizerTrait>(
+    neat_network: &mut NeatNetwork,
+    innovation_tracker: &mut InnovationTracker,
+    randomizer: &mut T,
+) -> Result<bool, NeuralNetworksError> {
+    let enabled_connections: Vec<usize> = neat_network
+        .get_connections()
+        .iter()
+        .enumerate()
+        .filter(|(_, connection)| connection.is_enabled())
+        .map(|(index, _)| index)
+        .collect();
+
+    if enabled_connections.is_empty() {
+        return Ok(false);
+    }
+
+    let split_index = randomizer.choose_random_from_vec(&enabled_connections);
+    let split_connection = neat_network.get_connections()[split_index].clone();
+
+    let node_identifier =
+        innovation_tracker.get_node_identifier_for_split(split_connection.get_innovation_number());
+
+    // The same connection may have been split before in this genome, and then re-enabled through crossover.
+    if neat_network.get_node(node_identifier).is_some() {
+        return Ok(false);
+    }
+
+    neat_network.set_connection_enabled(split_index, false)?;
+
+    neat_network.add_node(NodeGene::new(
+        node_identifier,
+        NodeKinds::Hidden,
+        0f64,
+        choose_random_activation_function(randomizer),
+    ))?;
+
+    // The incoming connection gets a weight of 1 and the outgoing one keeps the old weight,
+    // so the new node disturbs the behavior of the network as little as possible.
+    let incoming_innovation_number =
+        innovation_tracker.get_innovation_number(split_connection.get_from(), node_identifier);
+    let outgoing_innovation_number =
+        innovation_tracker.get_innovation_number(node_identifier, split_connection.get_to());
+
+    neat_network.add_connection(ConnectionGene::new(
+        incoming_innovation_number,
+        split_connection.get_from(),
+        node_identifier,
+        1f64,
+    ))?;
+    neat_network.add_connection(ConnectionGene::new(
+        outgoing_innovation_number,
+        node_identifier,
+        split_connection.get_to(),
+        split_connection.get_weight(),
+    ))?;
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use neat::domain::mutate_add_connection::mutate_add_connection;
+    use neural_network::NeuralNetworkTrait;
+    use neuron::Neuron;
+    use neuron_activation::activation_functions::ActivationFunctions;
+
+    use self::randomization::randomizer::Randomizer;
+
+    #[test]
+    fn test_adding_a_node_splits_an_enabled_connection() -> Result<(), NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+
+        let mut neat_network = NeatNetwork::new_with_specified_layers(
+            &[[2, 1]],
+            &mut randomizer,
+            |number_of_inputs, randomizer| {
+                Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+            },
+        )?;
+        let mut innovation_tracker = InnovationTracker::new(2, 1);
+
+        assert!(mutate_add_node(
+            &mut neat_network,
+            &mut innovation_tracker,
+            &mut randomizer
+        )?);
+
+        assert_eq!(neat_network.get_nodes().len(), 4);
+        assert_eq!(neat_network.get_connections().len(), 4);
+        assert_eq!(
+            neat_network
+                .get_connections()
+                .iter()
+                .filter(|connection| !connection.is_enabled())
+                .count(),
+            1
+        );
+        assert_eq!(neat_network.propagate(&[1.0, 0.5])?.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_growing_a_network_never_introduces_cycles() -> Result<(), NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+
+        let mut neat_network = NeatNetwork::new_with_specified_layers(
+            &[[3, 2]],
+            &mut randomizer,
+            |number_of_inputs, randomizer| {
+                Neuron::new(number_of_inputs, ActivationFunctions::Tanh, randomizer)
+            },
+        )?;
+        let mut innovation_tracker = InnovationTracker::new(3, 2);
+
+        for _ in 0..30 {
+            mutate_add_node(&mut neat_network, &mut innovation_tracker, &mut randomizer)?;
+            mutate_add_connection(&mut neat_network, &mut innovation_tracker, &mut randomizer)?;
+        }
+
+        assert!(neat_network.get_nodes().len() > 5);
+        assert_eq!(neat_network.propagate(&[1.0, 0.5, -0.3])?.len(), 2);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use neat::domain::innovation_tracker::InnovationTracker;
+use neat::domain::mutate_add_connection::mutate_add_connection;
+use neat::domain::mutate_add_node::mutate_add_node;
+use neat::domain::mutate_neat_weights::mutate_neat_weights;
+use neat::domain::neat_network::NeatNetwork;
+use neat::domain::neat_parameters::NeatParameters;
+use neural_networks_error::NeuralNetworksError;
+
+pub fn mutate_neat_network<T: RandomizerTrait>(
+    neat_network: &mut NeatNetwork,
+    innovation_tracker: &mut InnovationTracker,
+    neat_parameters: &NeatParameters,
+    randomizer: &mut T,
+) -> Result<(), NeuralNetworksError> {
+    if randomizer.generate_float_from_0_to_1() < neat_parameters.get_add_node_probability() {
+        mutate_add_node(neat_network, innovation_tracker, randomizer)?;
+    }
+
+    if randomizer.generate_float_from_0_to_1() < neat_parameters.get_add_connection_probability() {
+        mutate_add_connection(neat_network, innovation_tracker, randomizer)?;
+    }
+
+    mutate_neat_weights(neat_network, neat_parameters, randomizer)
+}
@@ -0,0 +1,40 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use neat::domain::neat_network::NeatNetwork;
+use neat::domain::neat_parameters::NeatParameters;
+use neural_networks_error::NeuralNetworksError;
+
+pub fn mutate_neat_weights<T: RandomizerTrait>(
+    neat_network: &mut NeatNetwork,
+    neat_parameters: &NeatParameters,
+    randomizer: &mut T,
+) -> Result<(), NeuralNetworksError> {
+    if randomizer.generate_float_from_0_to_1() >= neat_parameters.get_weight_mutation_probability()
+    {
+        return Ok(());
+    }
+
+    // Most weights get nudged, while a few get replaced altogether.
+    for index in 0..neat_network.get_connections().len() {
+        let weight = if randomizer.generate_float_from_0_to_1()
+            < neat_parameters.get_weight_replacement_probability()
+        {
+            randomizer.get_normal()
+        } else {
+            neat_network.get_connections()[index].get_weight()
+                + randomizer.get_normal() * neat_parameters.get_weight_perturbation_power()
+        };
+
+        neat_network.set_connection_weight(index, weight)?;
+    }
+
+    for index in 0..neat_network.get_nodes().len() {
+        let bias = neat_network.get_nodes()[index].get_bias()
+            + randomizer.get_normal() * neat_parameters.get_weight_perturbation_power();
+
+        neat_network.set_node_bias(index, bias)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,5 @@
+use evolution::domain::genome::Genome;
+use neat::domain::neat_network::NeatNetwork;
+use neuron::Neuron;
+
+pub type NeatGenome = Genome<NeatNetwork, Neuron>;
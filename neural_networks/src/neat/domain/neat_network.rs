@@ -0,0 +1,647 @@
+extern crate randomization;
+extern crate serde;
+
+use self::randomization::randomizer::RandomizerTrait;
+use self::serde::{Deserialize, Serialize};
+use neat::domain::connection_gene::ConnectionGene;
+use neat::domain::node_gene::NodeGene;
+use neat::domain::node_kinds::NodeKinds;
+use neural_network::NeuralNetworkTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::Neuron;
+use neuron::NeuronTrait;
+use neuron_activation::activate_neuron::activate_neuron;
+use neuron_activation::activation_functions::ActivationFunctions;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+// A network made of node and connection genes instead of layers, so that its topology can grow
+// through mutations. Input nodes get the identifiers 0..number_of_inputs, and output nodes the ones
+// right after them. Connections only ever get added when they don't close a cycle, so the network
+// can always be evaluated in topological order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "SerializedNeatNetwork")]
+pub struct NeatNetwork {
+    number_of_inputs: u32,
+    number_of_outputs: u32,
+    nodes: Vec<NodeGene>,
+    connections: Vec<ConnectionGene>,
+    // Worked out again whenever the topology changes, rather than on every propagation.
+    #[serde(skip)]
+    evaluation_order: Vec<usize>,
+    // For every node, the node and the connection each of its enabled incoming connections comes through.
+    #[serde(skip)]
+    incoming_connections: Vec<Vec<(usize, usize)>>,
+    #[serde(skip)]
+    output_nodes: Vec<usize>,
+}
+
+#[derive(Deserialize)]
+struct SerializedNeatNetwork {
+    number_of_inputs: u32,
+    number_of_outputs: u32,
+    nodes: Vec<NodeGene>,
+    connections: Vec<ConnectionGene>,
+}
+
+impl TryFrom<SerializedNeatNetwork> for NeatNetwork {
+    type Error = NeuralNetworksError;
+
+    fn try_from(serialized: SerializedNeatNetwork) -> Result<Self, NeuralNetworksError> {
+        NeatNetwork::new(
+            serialized.number_of_inputs,
+            serialized.number_of_outputs,
+            serialized.nodes,
+            serialized.connections,
+        )
+    }
+}
+
+impl NeatNetwork {
+    pub fn new(
+        number_of_inputs: u32,
+        number_of_outputs: u32,
+        nodes: Vec<NodeGene>,
+        connections: Vec<ConnectionGene>,
+    ) -> Result<Self, NeuralNetworksError> {
+        let mut neat_network = NeatNetwork {
+            number_of_inputs,
+            number_of_outputs,
+            nodes,
+            connections,
+            evaluation_order: Vec::new(),
+            incoming_connections: Vec::new(),
+            output_nodes: Vec::new(),
+        };
+
+        neat_network.update_evaluation_order()?;
+
+        Ok(neat_network)
+    }
+
+    // NEAT starts from the minimal topology, so the layers definition can only hold a single layer:
+    // its number of inputs and of neurons. The neuron creator provides the weights, bias and
+    // activation function of each output node.
+    pub fn new_with_specified_layers<U: RandomizerTrait, V: Fn(u32, &mut U) -> Neuron>(
+        layers_definition: &[[usize; 2]],
+        randomizer: &mut U,
+        neuron_creator: V,
+    ) -> Result<Self, NeuralNetworksError> {
+        let (number_of_inputs, number_of_outputs) = match layers_definition {
+            [] => return Err(NeuralNetworksError::NoLayers),
+            [[number_of_inputs, number_of_outputs]] => {
+                (*number_of_inputs as u32, *number_of_outputs as u32)
+            }
+            _ => {
+                return Err(NeuralNetworksError::HiddenLayersInNeatDefinition {
+                    number_of_layers: layers_definition.len(),
+                })
+            }
+        };
+
+        let mut nodes: Vec<NodeGene> = (0..number_of_inputs)
+            .map(|identifier| {
+                // Input nodes just hand over their inputs, so their activation function is never used.
+                NodeGene::new(
+                    identifier,
+                    NodeKinds::Input,
+                    0f64,
+                    ActivationFunctions::Sigmoid,
+                )
+            })
+            .collect();
+
+        let output_neurons: Vec<Neuron> = (0..number_of_outputs)
+            .map(|_| neuron_creator(number_of_inputs, randomizer))
+            .collect();
+
+        let mut connections = Vec::new();
+
+        for input in 0..number_of_inputs {
+            for (output, neuron) in output_neurons.iter().enumerate() {
+                connections.push(ConnectionGene::new(
+                    input * number_of_outputs + output as u32,
+                    input,
+                    number_of_inputs + output as u32,
+                    neuron.get_weight(input as usize)?,
+                ));
+            }
+        }
+
+        for (output, neuron) in output_neurons.iter().enumerate() {
+            nodes.push(NodeGene::new(
+                number_of_inputs + output as u32,
+                NodeKinds::Output,
+                neuron.get_bias(),
+                *neuron.get_activation_function(),
+            ));
+        }
+
+        NeatNetwork::new(number_of_inputs, number_of_outputs, nodes, connections)
+    }
+
+    pub fn get_number_of_inputs(&self) -> u32 {
+        self.number_of_inputs
+    }
+
+    pub fn get_number_of_outputs(&self) -> u32 {
+        self.number_of_outputs
+    }
+
+    pub fn get_nodes(&self) -> &Vec<NodeGene> {
+        &self.nodes
+    }
+
+    pub fn get_node(&self, identifier: u32) -> Option<&NodeGene> {
+        self.nodes
+            .iter()
+            .find(|node| node.get_identifier() == identifier)
+    }
+
+    pub fn add_node(&mut self, node: NodeGene) -> Result<(), NeuralNetworksError> {
+        if self.get_node(node.get_identifier()).is_some() {
+            return Err(NeuralNetworksError::RepeatedNode(node.get_identifier()));
+        }
+
+        self.nodes.push(node);
+
+        self.update_evaluation_order().inspect_err(|_| {
+            self.nodes.pop();
+        })
+    }
+
+    pub fn set_node_bias(&mut self, index: usize, bias: f64) -> Result<(), NeuralNetworksError> {
+        self.get_node_at_mut(index)?.set_bias(bias);
+
+        Ok(())
+    }
+
+    pub fn set_node_activation_function(
+        &mut self,
+        index: usize,
+        activation_function: ActivationFunctions,
+    ) -> Result<(), NeuralNetworksError> {
+        self.get_node_at_mut(index)?
+            .set_activation_function(activation_function);
+
+        Ok(())
+    }
+
+    pub fn get_connections(&self) -> &Vec<ConnectionGene> {
+        &self.connections
+    }
+
+    // Fails, leaving the network untouched, if the connection would close a cycle.
+    pub fn add_connection(
+        &mut self,
+        connection: ConnectionGene,
+    ) -> Result<(), NeuralNetworksError> {
+        let (source, target) = (connection.get_from(), connection.get_to());
+
+        self.connections.push(connection);
+
+        self.update_evaluation_order()
+            .inspect_err(|_| {
+                self.connections.pop();
+            })
+            .map_err(|error| NeatNetwork::blame_cycle_on(error, source, target))
+    }
+
+    pub fn set_connection_weight(
+        &mut self,
+        index: usize,
+        weight: f64,
+    ) -> Result<(), NeuralNetworksError> {
+        self.get_connection_at_mut(index)?.set_weight(weight);
+
+        Ok(())
+    }
+
+    // Fails, leaving the network untouched, if enabling the connection would close a cycle.
+    pub fn set_connection_enabled(
+        &mut self,
+        index: usize,
+        enabled: bool,
+    ) -> Result<(), NeuralNetworksError> {
+        let was_enabled = self.get_connection_at_mut(index)?.is_enabled();
+        let (source, target) = (
+            self.connections[index].get_from(),
+            self.connections[index].get_to(),
+        );
+
+        self.connections[index].set_enabled(enabled);
+
+        self.update_evaluation_order()
+            .inspect_err(|_| {
+                self.connections[index].set_enabled(was_enabled);
+            })
+            .map_err(|error| NeatNetwork::blame_cycle_on(error, source, target))
+    }
+
+    pub fn has_connection(&self, from: u32, to: u32) -> bool {
+        self.connections
+            .iter()
+            .any(|connection| connection.get_from() == from && connection.get_to() == to)
+    }
+
+    // Whether 'to' can already be reached from 'from'. Disabled connections count as well, given that
+    // crossover may enable them again.
+    pub fn is_reachable(&self, from: u32, to: u32) -> bool {
+        let mut pending = vec![from];
+        let mut visited = vec![from];
+
+        while let Some(current) = pending.pop() {
+            if current == to {
+                return true;
+            }
+
+            for connection in self
+                .connections
+                .iter()
+                .filter(|connection| connection.get_from() == current)
+            {
+                if !visited.contains(&connection.get_to()) {
+                    visited.push(connection.get_to());
+                    pending.push(connection.get_to());
+                }
+            }
+        }
+
+        false
+    }
+
+    fn get_node_at_mut(&mut self, index: usize) -> Result<&mut NodeGene, NeuralNetworksError> {
+        let number_of_nodes = self.nodes.len();
+
+        self.nodes
+            .get_mut(index)
+            .ok_or(NeuralNetworksError::NodeOutOfRange {
+                node: index,
+                number_of_nodes,
+            })
+    }
+
+    fn get_connection_at_mut(
+        &mut self,
+        index: usize,
+    ) -> Result<&mut ConnectionGene, NeuralNetworksError> {
+        let number_of_connections = self.connections.len();
+
+        self.connections
+            .get_mut(index)
+            .ok_or(NeuralNetworksError::ConnectionOutOfRange {
+                index,
+                number_of_connections,
+            })
+    }
+
+    // A cycle found right after adding or enabling a connection can only have been closed by it.
+    fn blame_cycle_on(error: NeuralNetworksError, source: u32, target: u32) -> NeuralNetworksError {
+        match error {
+            NeuralNetworksError::CycleInConnections => NeuralNetworksError::WouldCloseCycle {
+                source: source as usize,
+                target: target as usize,
+            },
+            error => error,
+        }
+    }
+
+    // Sorts the nodes topologically and gathers the incoming connections of each of them.
+    fn update_evaluation_order(&mut self) -> Result<(), NeuralNetworksError> {
+        let node_indexes: HashMap<u32, usize> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (node.get_identifier(), index))
+            .collect();
+
+        let find_node = |identifier: u32| {
+            node_indexes
+                .get(&identifier)
+                .cloned()
+                .ok_or(NeuralNetworksError::UnknownNode(identifier))
+        };
+
+        let mut incoming_connections = vec![Vec::new(); self.nodes.len()];
+        let mut outgoing_connections = vec![Vec::new(); self.nodes.len()];
+
+        for (index, connection) in self.connections.iter().enumerate() {
+            let from = find_node(connection.get_from())?;
+            let to = find_node(connection.get_to())?;
+
+            if self.nodes[to].get_kind() == NodeKinds::Input {
+                return Err(NeuralNetworksError::ConnectionIntoInputNode(
+                    connection.get_to(),
+                ));
+            }
+
+            if connection.is_enabled() {
+                incoming_connections[to].push((from, index));
+                outgoing_connections[from].push(to);
+            }
+        }
+
+        let mut remaining: Vec<usize> = incoming_connections.iter().map(Vec::len).collect();
+        let mut pending: Vec<usize> = (0..self.nodes.len())
+            .filter(|index| remaining[*index] == 0)
+            .collect();
+        let mut evaluation_order = Vec::with_capacity(self.nodes.len());
+
+        while let Some(current) = pending.pop() {
+            evaluation_order.push(current);
+
+            for target in &outgoing_connections[current] {
+                remaining[*target] -= 1;
+
+                if remaining[*target] == 0 {
+                    pending.push(*target);
+                }
+            }
+        }
+
+        if evaluation_order.len() != self.nodes.len() {
+            return Err(NeuralNetworksError::CycleInConnections);
+        }
+
+        let mut output_nodes = Vec::with_capacity(self.number_of_outputs as usize);
+
+        for identifier in 0..self.number_of_inputs + self.number_of_outputs {
+            let index = node_indexes
+                .get(&identifier)
+                .cloned()
+                .ok_or(NeuralNetworksError::MissingInputOrOutputNode(identifier))?;
+
+            if identifier >= self.number_of_inputs {
+                output_nodes.push(index);
+            }
+        }
+
+        self.evaluation_order = evaluation_order;
+        self.incoming_connections = incoming_connections;
+        self.output_nodes = output_nodes;
+
+        Ok(())
+    }
+}
+
+// The parameters of a NEAT network are the biases of its hidden and output nodes, followed by the
+// weights of all its connections (disabled ones included, so the count only changes along with the
+// topology).
+impl NeuralNetworkTrait<Neuron> for NeatNetwork {
+    fn propagate(&self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError> {
+        if inputs.len() != self.number_of_inputs as usize {
+            return Err(NeuralNetworksError::WrongNumberOfInputs {
+                expected: self.number_of_inputs as usize,
+                received: inputs.len(),
+            });
+        }
+
+        let mut values = vec![0f64; self.nodes.len()];
+
+        for index in &self.evaluation_order {
+            let node = &self.nodes[*index];
+
+            values[*index] = match node.get_kind() {
+                NodeKinds::Input => inputs[node.get_identifier() as usize],
+                _ => activate_neuron(
+                    self.incoming_connections[*index]
+                        .iter()
+                        .map(|(from, connection)| {
+                            self.connections[*connection].get_weight() * values[*from]
+                        })
+                        .sum::<f64>()
+                        + node.get_bias(),
+                    node.get_activation_function(),
+                ),
+            };
+        }
+
+        Ok(self
+            .output_nodes
+            .iter()
+            .map(|index| values[*index])
+            .collect())
+    }
+
+    fn propagate_batch(
+        &self,
+        inputs: &[f64],
+        outputs: &mut Vec<f64>,
+    ) -> Result<(), NeuralNetworksError> {
+        let number_of_inputs = self.number_of_inputs as usize;
+
+        if number_of_inputs == 0 || !inputs.len().is_multiple_of(number_of_inputs) {
+            return Err(NeuralNetworksError::WrongNumberOfInputs {
+                expected: number_of_inputs,
+                received: inputs.len(),
+            });
+        }
+
+        outputs.clear();
+
+        for sample in inputs.chunks(number_of_inputs) {
+            outputs.extend(self.propagate(sample)?);
+        }
+
+        Ok(())
+    }
+
+    // NEAT networks have no recurrent connections, so there is nothing to carry on.
+    fn propagate_step(&mut self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError> {
+        self.propagate(inputs)
+    }
+
+    fn reset_state(&mut self) {}
+
+    fn parameters(&self) -> Vec<f64> {
+        self.nodes
+            .iter()
+            .filter(|node| node.get_kind() != NodeKinds::Input)
+            .map(|node| node.get_bias())
+            .chain(
+                self.connections
+                    .iter()
+                    .map(|connection| connection.get_weight()),
+            )
+            .collect()
+    }
+
+    fn set_parameters(&mut self, parameters: &[f64]) -> Result<(), NeuralNetworksError> {
+        if parameters.len() != self.get_number_of_parameters() {
+            return Err(NeuralNetworksError::WrongNumberOfParameters {
+                expected: self.get_number_of_parameters(),
+                received: parameters.len(),
+            });
+        }
+
+        let mut parameters = parameters.iter();
+
+        for node in self
+            .nodes
+            .iter_mut()
+            .filter(|node| node.get_kind() != NodeKinds::Input)
+        {
+            node.set_bias(*parameters.next().unwrap());
+        }
+        for connection in self.connections.iter_mut() {
+            connection.set_weight(*parameters.next().unwrap());
+        }
+
+        Ok(())
+    }
+
+    fn get_number_of_parameters(&self) -> usize {
+        self.nodes.len() - self.number_of_inputs as usize + self.connections.len()
+    }
+
+    // Networks with as many parameters may still have grown different genes.
+    fn has_same_parameter_layout(&self, other: &Self) -> bool {
+        self.nodes
+            .iter()
+            .map(NodeGene::get_identifier)
+            .eq(other.nodes.iter().map(NodeGene::get_identifier))
+            && self
+                .connections
+                .iter()
+                .map(ConnectionGene::get_innovation_number)
+                .eq(other
+                    .connections
+                    .iter()
+                    .map(ConnectionGene::get_innovation_number))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use self::randomization::randomizer::Randomizer;
+
+    fn setup_neat_network() -> Result<NeatNetwork, NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+
+        NeatNetwork::new_with_specified_layers(
+            &[[3, 2]],
+            &mut randomizer,
+            |number_of_inputs, randomizer| {
+                Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+            },
+        )
+    }
+
+    #[test]
+    fn test_a_new_neat_network_connects_every_input_to_every_output(
+    ) -> Result<(), NeuralNetworksError> {
+        let neat_network = setup_neat_network()?;
+
+        assert_eq!(neat_network.get_nodes().len(), 5);
+        assert_eq!(neat_network.get_connections().len(), 6);
+        assert!(neat_network.has_connection(2, 4));
+        assert!(neat_network.is_reachable(0, 3));
+        assert!(!neat_network.is_reachable(3, 0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_propagating_through_a_neat_network_produces_an_output_per_output_node(
+    ) -> Result<(), NeuralNetworksError> {
+        let neat_network = setup_neat_network()?;
+
+        let outputs = neat_network.propagate(&[0.0, 1.0, 0.5])?;
+
+        assert_eq!(outputs.len(), 2);
+        assert!(outputs
+            .iter()
+            .all(|output| *output >= 0.0 && *output <= 1.0));
+
+        assert!(neat_network.propagate(&[0.0, 1.0]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_can_serde_a_neat_network() -> Result<(), NeuralNetworksError> {
+        let neat_network = setup_neat_network()?;
+
+        let serialized = serde_json::to_string(&neat_network).unwrap();
+
+        let deserialized: NeatNetwork = serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(deserialized.get_connections().len(), 6);
+        assert_eq!(deserialized.get_number_of_outputs(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_a_neat_network_cant_start_with_hidden_layers() {
+        let mut randomizer = Randomizer::new();
+
+        let neat_network = NeatNetwork::new_with_specified_layers(
+            &[[3, 5], [5, 2]],
+            &mut randomizer,
+            |number_of_inputs, randomizer| {
+                Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+            },
+        );
+
+        assert!(matches!(
+            neat_network,
+            Err(NeuralNetworksError::HiddenLayersInNeatDefinition {
+                number_of_layers: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn test_a_connection_closing_a_cycle_gets_rejected_and_leaves_the_network_as_it_was(
+    ) -> Result<(), NeuralNetworksError> {
+        let mut neat_network = setup_neat_network()?;
+
+        neat_network.add_node(NodeGene::new(
+            5,
+            NodeKinds::Hidden,
+            0.0,
+            ActivationFunctions::Tanh,
+        ))?;
+        neat_network.add_connection(ConnectionGene::new(6, 3, 5, 1.0))?;
+        let outputs = neat_network.propagate(&[0.0, 1.0, 0.5])?;
+
+        assert_eq!(
+            neat_network.add_connection(ConnectionGene::new(7, 5, 3, 1.0)),
+            Err(NeuralNetworksError::WouldCloseCycle {
+                source: 5,
+                target: 3
+            })
+        );
+        assert_eq!(neat_network.get_connections().len(), 7);
+        assert_eq!(neat_network.propagate(&[0.0, 1.0, 0.5])?, outputs);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_setting_the_parameters_round_trips_the_biases_and_weights(
+    ) -> Result<(), NeuralNetworksError> {
+        let mut neat_network = setup_neat_network()?;
+
+        // Two output biases and six weights.
+        let parameters: Vec<f64> = (0..8).map(|index| index as f64 * 0.1).collect();
+
+        assert_eq!(neat_network.get_number_of_parameters(), 8);
+
+        neat_network.set_parameters(&parameters)?;
+
+        assert_eq!(neat_network.parameters(), parameters);
+        assert_eq!(neat_network.get_nodes()[3].get_bias(), 0.0);
+        assert_eq!(
+            neat_network.get_connections()[5].get_weight(),
+            parameters[7]
+        );
+        assert!(neat_network.set_parameters(&parameters[1..]).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+extern crate serde;
+
+use self::serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NeatParameters {
+    compatibility_threshold: f64,
+    excess_coefficient: f64,
+    disjoint_coefficient: f64,
+    weight_difference_coefficient: f64,
+    weight_mutation_probability: f64,
+    weight_perturbation_power: f64,
+    weight_replacement_probability: f64,
+    add_node_probability: f64,
+    add_connection_probability: f64,
+    survival_threshold: f64,
+}
+
+// Defaults taken from the original NEAT paper (Stanley & Miikkulainen, 2002).
+impl Default for NeatParameters {
+    fn default() -> Self {
+        NeatParameters {
+            compatibility_threshold: 3.0,
+            excess_coefficient: 1.0,
+            disjoint_coefficient: 1.0,
+            weight_difference_coefficient: 0.4,
+            weight_mutation_probability: 0.8,
+            weight_perturbation_power: 0.5,
+            weight_replacement_probability: 0.1,
+            add_node_probability: 0.03,
+            add_connection_probability: 0.05,
+            survival_threshold: 0.2,
+        }
+    }
+}
+
+impl NeatParameters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get_compatibility_threshold(&self) -> f64 {
+        self.compatibility_threshold
+    }
+
+    pub fn set_compatibility_threshold(&mut self, compatibility_threshold: f64) {
+        self.compatibility_threshold = compatibility_threshold;
+    }
+
+    pub fn get_excess_coefficient(&self) -> f64 {
+        self.excess_coefficient
+    }
+
+    pub fn get_disjoint_coefficient(&self) -> f64 {
+        self.disjoint_coefficient
+    }
+
+    pub fn get_weight_difference_coefficient(&self) -> f64 {
+        self.weight_difference_coefficient
+    }
+
+    pub fn get_weight_mutation_probability(&self) -> f64 {
+        self.weight_mutation_probability
+    }
+
+    pub fn set_weight_mutation_probability(&mut self, weight_mutation_probability: f64) {
+        self.weight_mutation_probability = weight_mutation_probability;
+    }
+
+    pub fn get_weight_perturbation_power(&self) -> f64 {
+        self.weight_perturbation_power
+    }
+
+    pub fn get_weight_replacement_probability(&self) -> f64 {
+        self.weight_replacement_probability
+    }
+
+    pub fn get_add_node_probability(&self) -> f64 {
+        self.add_node_probability
+    }
+
+    pub fn set_add_node_probability(&mut self, add_node_probability: f64) {
+        self.add_node_probability = add_node_probability;
+    }
+
+    pub fn get_add_connection_probability(&self) -> f64 {
+        self.add_connection_probability
+    }
+
+    pub fn set_add_connection_probability(&mut self, add_connection_probability: f64) {
+        self.add_connection_probability = add_connection_probability;
+    }
+
+    pub fn get_survival_threshold(&self) -> f64 {
+        self.survival_threshold
+    }
+
+    pub fn set_survival_threshold(&mut self, survival_threshold: f64) {
+        self.survival_threshold = survival_threshold;
+    }
+}
@@ -0,0 +1,53 @@
+extern crate serde;
+
+use self::serde::{Deserialize, Serialize};
+use neat::domain::node_kinds::NodeKinds;
+use neuron_activation::activation_functions::ActivationFunctions;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeGene {
+    identifier: u32,
+    kind: NodeKinds,
+    bias: f64,
+    activation_function: ActivationFunctions,
+}
+
+impl NodeGene {
+    pub fn new(
+        identifier: u32,
+        kind: NodeKinds,
+        bias: f64,
+        activation_function: ActivationFunctions,
+    ) -> Self {
+        NodeGene {
+            identifier,
+            kind,
+            bias,
+            activation_function,
+        }
+    }
+
+    pub fn get_identifier(&self) -> u32 {
+        self.identifier
+    }
+
+    pub fn get_kind(&self) -> NodeKinds {
+        self.kind
+    }
+
+    pub fn get_bias(&self) -> f64 {
+        self.bias
+    }
+
+    pub fn set_bias(&mut self, bias: f64) {
+        self.bias = bias;
+    }
+
+    pub fn get_activation_function(&self) -> &ActivationFunctions {
+        &self.activation_function
+    }
+
+    pub fn set_activation_function(&mut self, activation_function: ActivationFunctions) {
+        self.activation_function = activation_function;
+    }
+}
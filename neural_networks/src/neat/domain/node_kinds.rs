@@ -0,0 +1,10 @@
+extern crate serde;
+
+use self::serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum NodeKinds {
+    Input,
+    Output,
+    Hidden,
+}
@@ -0,0 +1,44 @@
+use evolution::domain::genome::GenomeTrait;
+use neat::domain::compute_compatibility_distance::compute_compatibility_distance;
+use neat::domain::neat_genome::NeatGenome;
+use neat::domain::neat_parameters::NeatParameters;
+use neat::domain::species::Species;
+use neural_networks_error::NeuralNetworksError;
+
+pub fn speciate_population(
+    genomes: &[NeatGenome],
+    species: &mut Vec<Species>,
+    neat_parameters: &NeatParameters,
+) -> Result<(), NeuralNetworksError> {
+    for existing_species in species.iter_mut() {
+        existing_species.get_members_mut().clear();
+    }
+
+    for (index, genome) in genomes.iter().enumerate() {
+        match species.iter_mut().find(|existing_species| {
+            compute_compatibility_distance(
+                existing_species.get_representative(),
+                genome.get_neural_network(),
+                neat_parameters,
+            ) < neat_parameters.get_compatibility_threshold()
+        }) {
+            Some(existing_species) => existing_species.get_members_mut().push(index),
+            None => {
+                let identifier = species
+                    .iter()
+                    .map(|existing_species| existing_species.get_identifier() + 1)
+                    .max()
+                    .unwrap_or(1);
+
+                let mut new_species = Species::new(identifier, genome.get_neural_network().clone());
+                new_species.get_members_mut().push(index);
+
+                species.push(new_species);
+            }
+        }
+    }
+
+    species.retain(|existing_species| !existing_species.get_members().is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+extern crate serde;
+
+use self::serde::{Deserialize, Serialize};
+use neat::domain::neat_network::NeatNetwork;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Species {
+    identifier: u32,
+    representative: NeatNetwork,
+    members: Vec<usize>,
+}
+
+impl Species {
+    pub fn new(identifier: u32, representative: NeatNetwork) -> Self {
+        Species {
+            identifier,
+            representative,
+            members: Vec::new(),
+        }
+    }
+
+    pub fn get_identifier(&self) -> u32 {
+        self.identifier
+    }
+
+    pub fn get_representative(&self) -> &NeatNetwork {
+        &self.representative
+    }
+
+    pub fn set_representative(&mut self, representative: NeatNetwork) {
+        self.representative = representative;
+    }
+
+    // The indexes of the genomes of the population that belong to this species.
+    pub fn get_members(&self) -> &Vec<usize> {
+        &self.members
+    }
+
+    pub fn get_members_mut(&mut self) -> &mut Vec<usize> {
+        &mut self.members
+    }
+}
@@ -0,0 +1,430 @@
+extern crate randomization;
+extern crate serde;
+extern crate serde_json;
+
+use self::randomization::randomizer::RandomizerTrait;
+use self::serde::{Deserialize, Serialize};
+use network_layers::network_layer_trait::NetworkLayerTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron_activation::activate_neuron::activate_neuron;
+use neuron_activation::activation_functions::ActivationFunctions;
+use neuron_initialization::draw_initial_bias::draw_initial_bias;
+use neuron_initialization::draw_initial_weight::draw_initial_weight;
+use neuron_initialization::initializers::Initializers;
+use std::convert::TryFrom;
+
+// Slides a set of filters over a (channels, height, width) tensor, stored channel after channel
+// and row after row, and outputs one activated feature map per filter. Borders are padded with
+// zeros. Each filter holds a bias followed by its kernel, which is also how the parameters of the
+// layer are laid out, so the kernels can be evolved like any other weights. Deserialized layers go
+// through the same checks as new ones.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "SerializedConv2d")]
+pub struct Conv2d {
+    input_shape: [usize; 3],
+    number_of_filters: usize,
+    kernel_size: [usize; 2],
+    stride: usize,
+    padding: usize,
+    activation_function: ActivationFunctions,
+    kernels: Vec<f64>,
+    biases: Vec<f64>,
+}
+
+#[derive(Deserialize)]
+struct SerializedConv2d {
+    input_shape: [usize; 3],
+    number_of_filters: usize,
+    kernel_size: [usize; 2],
+    stride: usize,
+    padding: usize,
+    activation_function: ActivationFunctions,
+    kernels: Vec<f64>,
+    biases: Vec<f64>,
+}
+
+impl TryFrom<SerializedConv2d> for Conv2d {
+    type Error = NeuralNetworksError;
+
+    fn try_from(serialized: SerializedConv2d) -> Result<Self, NeuralNetworksError> {
+        let conv2d = Conv2d {
+            input_shape: serialized.input_shape,
+            number_of_filters: serialized.number_of_filters,
+            kernel_size: serialized.kernel_size,
+            stride: serialized.stride,
+            padding: serialized.padding,
+            activation_function: serialized.activation_function,
+            kernels: serialized.kernels,
+            biases: serialized.biases,
+        };
+
+        conv2d.validate()?;
+
+        Ok(conv2d)
+    }
+}
+
+impl Conv2d {
+    pub fn new<T: RandomizerTrait>(
+        input_shape: [usize; 3],
+        number_of_filters: usize,
+        kernel_size: [usize; 2],
+        activation_function: ActivationFunctions,
+        initializer: Initializers,
+        randomizer: &mut T,
+    ) -> Result<Self, NeuralNetworksError> {
+        let kernel_area = kernel_size[0] * kernel_size[1];
+        let fan_in = input_shape[0] * kernel_area;
+        let fan_out = number_of_filters * kernel_area;
+
+        let conv2d = Conv2d {
+            input_shape,
+            number_of_filters,
+            kernel_size,
+            stride: 1,
+            padding: 0,
+            activation_function,
+            kernels: (0..number_of_filters * fan_in)
+                .map(|_| draw_initial_weight(initializer, fan_in, fan_out, randomizer))
+                .collect(),
+            biases: (0..number_of_filters)
+                .map(|_| draw_initial_bias(initializer, randomizer))
+                .collect(),
+        };
+
+        conv2d.validate()?;
+
+        Ok(conv2d)
+    }
+
+    pub fn get_stride(&self) -> usize {
+        self.stride
+    }
+
+    pub fn set_stride(&mut self, stride: usize) -> Result<(), NeuralNetworksError> {
+        if stride == 0 {
+            return Err(NeuralNetworksError::ZeroStride);
+        }
+
+        self.stride = stride;
+
+        Ok(())
+    }
+
+    pub fn get_padding(&self) -> usize {
+        self.padding
+    }
+
+    pub fn set_padding(&mut self, padding: usize) {
+        self.padding = padding;
+    }
+
+    pub fn get_activation_function(&self) -> &ActivationFunctions {
+        &self.activation_function
+    }
+
+    pub fn get_kernels(&self) -> &[f64] {
+        &self.kernels
+    }
+
+    pub fn get_biases(&self) -> &[f64] {
+        &self.biases
+    }
+
+    fn validate(&self) -> Result<(), NeuralNetworksError> {
+        let [channels, height, width] = self.input_shape;
+
+        if channels == 0 || self.number_of_filters == 0 || self.kernel_size.contains(&0) {
+            return Err(NeuralNetworksError::EmptyConvolution);
+        }
+        if self.kernel_size[0] > height + 2 * self.padding
+            || self.kernel_size[1] > width + 2 * self.padding
+        {
+            return Err(NeuralNetworksError::KernelDoesntFit {
+                kernel_size: self.kernel_size,
+                input_shape: self.input_shape,
+            });
+        }
+        if self.stride == 0 {
+            return Err(NeuralNetworksError::ZeroStride);
+        }
+
+        let kernel_length = channels * self.kernel_size[0] * self.kernel_size[1];
+
+        if self.kernels.len() != self.number_of_filters * kernel_length
+            || self.biases.len() != self.number_of_filters
+        {
+            return Err(NeuralNetworksError::WrongNumberOfParameters {
+                expected: self.number_of_filters * (kernel_length + 1),
+                received: self.kernels.len() + self.biases.len(),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn get_output_size(&self) -> [usize; 2] {
+        let [_, height, width] = self.input_shape;
+
+        [
+            (height + 2 * self.padding - self.kernel_size[0]) / self.stride + 1,
+            (width + 2 * self.padding - self.kernel_size[1]) / self.stride + 1,
+        ]
+    }
+}
+
+impl NetworkLayerTrait for Conv2d {
+    fn get_layer_type(&self) -> &str {
+        "Conv2d"
+    }
+
+    fn get_input_shape(&self) -> Vec<usize> {
+        self.input_shape.to_vec()
+    }
+
+    fn get_output_shape(&self) -> Vec<usize> {
+        let [output_height, output_width] = self.get_output_size();
+
+        vec![self.number_of_filters, output_height, output_width]
+    }
+
+    fn forward(&self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError> {
+        let [channels, height, width] = self.input_shape;
+
+        if inputs.len() != channels * height * width {
+            return Err(NeuralNetworksError::WrongNumberOfInputs {
+                expected: channels * height * width,
+                received: inputs.len(),
+            });
+        }
+
+        let [kernel_height, kernel_width] = self.kernel_size;
+        let [output_height, output_width] = self.get_output_size();
+        let kernel_length = channels * kernel_height * kernel_width;
+
+        let mut outputs = Vec::with_capacity(self.number_of_filters * output_height * output_width);
+
+        for (kernel, bias) in self.kernels.chunks(kernel_length).zip(self.biases.iter()) {
+            for output_y in 0..output_height {
+                for output_x in 0..output_width {
+                    let mut sum = *bias;
+
+                    for (channel, channel_kernel) in
+                        kernel.chunks(kernel_height * kernel_width).enumerate()
+                    {
+                        for (kernel_y, kernel_row) in
+                            channel_kernel.chunks(kernel_width).enumerate()
+                        {
+                            // Rows and columns of the padding hold zeros, so they get skipped.
+                            let y = (output_y * self.stride + kernel_y).wrapping_sub(self.padding);
+
+                            if y >= height {
+                                continue;
+                            }
+
+                            for (kernel_x, weight) in kernel_row.iter().enumerate() {
+                                let x =
+                                    (output_x * self.stride + kernel_x).wrapping_sub(self.padding);
+
+                                if x < width {
+                                    sum += weight * inputs[(channel * height + y) * width + x];
+                                }
+                            }
+                        }
+                    }
+
+                    outputs.push(activate_neuron(sum, &self.activation_function));
+                }
+            }
+        }
+
+        Ok(outputs)
+    }
+
+    fn to_json(&self) -> Result<serde_json::Value, NeuralNetworksError> {
+        serde_json::to_value(self).map_err(|error| NeuralNetworksError::LayerSerialization {
+            layer_type: self.get_layer_type().to_string(),
+            message: error.to_string(),
+        })
+    }
+
+    fn clone_box(&self) -> Box<dyn NetworkLayerTrait> {
+        Box::new(self.clone())
+    }
+
+    fn parameters(&self) -> Vec<f64> {
+        let kernel_length = self.kernels.len() / self.number_of_filters;
+        let mut parameters = Vec::with_capacity(self.kernels.len() + self.biases.len());
+
+        for (kernel, bias) in self.kernels.chunks(kernel_length).zip(self.biases.iter()) {
+            parameters.push(*bias);
+            parameters.extend_from_slice(kernel);
+        }
+
+        parameters
+    }
+
+    fn set_parameters(&mut self, parameters: &[f64]) -> Result<(), NeuralNetworksError> {
+        let expected = self.kernels.len() + self.biases.len();
+
+        if parameters.len() != expected {
+            return Err(NeuralNetworksError::WrongNumberOfParameters {
+                expected,
+                received: parameters.len(),
+            });
+        }
+
+        let kernel_length = self.kernels.len() / self.number_of_filters;
+
+        for ((filter_parameters, kernel), bias) in parameters
+            .chunks(kernel_length + 1)
+            .zip(self.kernels.chunks_mut(kernel_length))
+            .zip(self.biases.iter_mut())
+        {
+            *bias = filter_parameters[0];
+            kernel.copy_from_slice(&filter_parameters[1..]);
+        }
+
+        Ok(())
+    }
+
+    fn get_number_of_parameters(&self) -> usize {
+        self.kernels.len() + self.biases.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    extern crate randomization;
+    use self::randomization::seeded_randomizer::SeededRandomizer;
+
+    #[test]
+    fn test_convolving_with_a_known_kernel() -> Result<(), NeuralNetworksError> {
+        let mut randomizer = SeededRandomizer::new(1);
+
+        let mut conv2d = Conv2d::new(
+            [1, 3, 3],
+            1,
+            [2, 2],
+            ActivationFunctions::Identity,
+            Initializers::HeNormal,
+            &mut randomizer,
+        )?;
+
+        // A bias of 0.5, and a kernel that adds the top left and bottom right values.
+        conv2d.set_parameters(&[0.5, 1.0, 0.0, 0.0, 1.0])?;
+
+        let inputs = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+
+        assert_eq!(conv2d.get_output_shape(), vec![1, 2, 2]);
+        assert_eq!(conv2d.forward(&inputs)?, vec![6.5, 8.5, 12.5, 14.5]);
+
+        conv2d.set_padding(1);
+        conv2d.set_stride(2)?;
+
+        assert_eq!(conv2d.get_output_shape(), vec![1, 2, 2]);
+        assert_eq!(conv2d.forward(&inputs)?, vec![1.5, 3.5, 7.5, 14.5]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_deserializing_a_layer_with_missing_filters_or_kernels_fails(
+    ) -> Result<(), NeuralNetworksError> {
+        let mut randomizer = SeededRandomizer::new(1);
+
+        let conv2d = Conv2d::new(
+            [2, 4, 4],
+            3,
+            [3, 3],
+            ActivationFunctions::Relu,
+            Initializers::HeNormal,
+            &mut randomizer,
+        )?;
+
+        let value = conv2d.to_json()?;
+        assert_eq!(
+            serde_json::from_value::<Conv2d>(value.clone()).ok(),
+            Some(conv2d.clone())
+        );
+
+        let mut without_filters = value.clone();
+        without_filters["number_of_filters"] = serde_json::json!(0);
+        without_filters["kernels"] = serde_json::json!([]);
+        without_filters["biases"] = serde_json::json!([]);
+        assert!(serde_json::from_value::<Conv2d>(without_filters).is_err());
+
+        let mut short_kernels = value.clone();
+        short_kernels["kernels"] = serde_json::json!(conv2d.get_kernels()[1..].to_vec());
+        assert!(serde_json::from_value::<Conv2d>(short_kernels).is_err());
+
+        let mut without_stride = value;
+        without_stride["stride"] = serde_json::json!(0);
+        assert!(serde_json::from_value::<Conv2d>(without_stride).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_convolutional_layers_get_evolved_within_a_modular_network(
+    ) -> Result<(), NeuralNetworksError> {
+        use evolution::controllers::create_next_generation_of_parameters::create_next_generation_of_parameters;
+        use evolution::domain::evolution_config::EvolutionConfig;
+        use evolution::domain::genome::{Genome, GenomeTrait};
+        use evolution::domain::mutation_operators::MutationOperators;
+        use evolution::domain::population::{Population, PopulationTrait};
+        use evolution::domain::selection::truncation_selection::TruncationSelection;
+        use network_layers::flatten::Flatten;
+        use network_layers::modular_neural_network::ModularNeuralNetwork;
+        use neural_network::NeuralNetworkTrait;
+
+        let mut randomizer = SeededRandomizer::new(4);
+        let mut population = Population::new();
+
+        for identifier in 1..=6 {
+            let mut neural_network = ModularNeuralNetwork::new();
+            neural_network.add(Box::new(Conv2d::new(
+                [1, 3, 3],
+                2,
+                [2, 2],
+                ActivationFunctions::Relu,
+                Initializers::HeNormal,
+                &mut randomizer,
+            )?))?;
+            neural_network.add(Box::new(Flatten::new(vec![2, 2, 2])))?;
+
+            let mut genome = Genome::new(identifier, neural_network);
+            genome.set_fitness(f64::from(identifier));
+            population.add(genome)?;
+        }
+
+        let mut evolution_config = EvolutionConfig::new();
+        evolution_config.set_mutation_rate(0.5)?;
+        evolution_config
+            .set_mutation_operator(MutationOperators::GaussianPerturbation { sigma: 0.1 });
+
+        let next_generation = create_next_generation_of_parameters(
+            &population,
+            &TruncationSelection::new(0.5),
+            &evolution_config,
+            Genome::new,
+            &mut randomizer,
+        )?;
+
+        let parameters_before: Vec<Vec<f64>> = population
+            .get_genomes()?
+            .iter()
+            .map(|genome| genome.get_neural_network().parameters())
+            .collect();
+
+        assert_eq!(next_generation.get_size(), 6);
+        assert!(next_generation.get_genomes()?[1..]
+            .iter()
+            .any(|genome| !parameters_before.contains(&genome.get_neural_network().parameters())));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+extern crate serde_json;
+
+use layer::Layer;
+use layer::LayerTrait;
+use network_layers::network_layer_trait::NetworkLayerTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::Neuron;
+
+// Dense layers, recurrent or not, are the same layers NeuralNetwork holds.
+impl NetworkLayerTrait for Layer<Neuron> {
+    fn get_layer_type(&self) -> &str {
+        "Dense"
+    }
+
+    fn get_input_shape(&self) -> Vec<usize> {
+        vec![self.get_number_of_inputs() as usize]
+    }
+
+    fn get_output_shape(&self) -> Vec<usize> {
+        vec![self.get_number_of_outputs() as usize]
+    }
+
+    fn forward(&self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError> {
+        self.feed_forward(inputs)
+    }
+
+    fn to_json(&self) -> Result<serde_json::Value, NeuralNetworksError> {
+        serde_json::to_value(self).map_err(|error| NeuralNetworksError::LayerSerialization {
+            layer_type: self.get_layer_type().to_string(),
+            message: error.to_string(),
+        })
+    }
+
+    fn clone_box(&self) -> Box<dyn NetworkLayerTrait> {
+        Box::new(self.clone())
+    }
+
+    fn forward_step(&mut self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError> {
+        self.feed_forward_step(inputs)
+    }
+
+    fn reset_state(&mut self) {
+        LayerTrait::reset_state(self);
+    }
+
+    fn parameters(&self) -> Vec<f64> {
+        self.get_parameters()
+    }
+
+    fn set_parameters(&mut self, parameters: &[f64]) -> Result<(), NeuralNetworksError> {
+        Layer::set_parameters(self, parameters)
+    }
+
+    fn get_number_of_parameters(&self) -> usize {
+        Layer::get_number_of_parameters(self)
+    }
+}
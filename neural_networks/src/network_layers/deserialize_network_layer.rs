@@ -0,0 +1,96 @@
+extern crate serde_json;
+
+use layer::Layer;
+use network_layers::conv2d::Conv2d;
+use network_layers::dropout::Dropout;
+use network_layers::flatten::Flatten;
+use network_layers::graph_neural_network::GraphNeuralNetwork;
+use network_layers::layer_normalization::LayerNormalization;
+use network_layers::network_layer_trait::NetworkLayerTrait;
+use network_layers::pooling2d::Pooling2d;
+use neural_networks_error::NeuralNetworksError;
+use neuron::Neuron;
+use std::sync::RwLock;
+
+type NetworkLayerDeserializer =
+    fn(serde_json::Value) -> Result<Box<dyn NetworkLayerTrait>, NeuralNetworksError>;
+
+static REGISTRY: RwLock<Vec<(String, NetworkLayerDeserializer)>> = RwLock::new(Vec::new());
+
+// Layer types from outside this crate get deserialized by the function registered for their type.
+pub fn register_network_layer_type(
+    layer_type: &str,
+    deserializer: NetworkLayerDeserializer,
+) -> Result<(), NeuralNetworksError> {
+    let mut registry = REGISTRY
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    if is_built_in(layer_type) || registry.iter().any(|(name, _)| name == layer_type) {
+        return Err(NeuralNetworksError::LayerTypeAlreadyRegistered(
+            layer_type.to_string(),
+        ));
+    }
+
+    registry.push((layer_type.to_string(), deserializer));
+
+    Ok(())
+}
+
+pub fn deserialize_network_layer(
+    layer_type: &str,
+    value: serde_json::Value,
+) -> Result<Box<dyn NetworkLayerTrait>, NeuralNetworksError> {
+    let to_error = |error: serde_json::Error| NeuralNetworksError::LayerDeserialization {
+        layer_type: layer_type.to_string(),
+        message: error.to_string(),
+    };
+
+    match layer_type {
+        "Dense" => Ok(Box::new(
+            serde_json::from_value::<Layer<Neuron>>(value).map_err(to_error)?,
+        )),
+        "LayerNormalization" => Ok(Box::new(
+            serde_json::from_value::<LayerNormalization>(value).map_err(to_error)?,
+        )),
+        "Dropout" => Ok(Box::new(
+            serde_json::from_value::<Dropout>(value).map_err(to_error)?,
+        )),
+        "Conv2d" => Ok(Box::new(
+            serde_json::from_value::<Conv2d>(value).map_err(to_error)?,
+        )),
+        "Pooling2d" => Ok(Box::new(
+            serde_json::from_value::<Pooling2d>(value).map_err(to_error)?,
+        )),
+        "Flatten" => Ok(Box::new(
+            serde_json::from_value::<Flatten>(value).map_err(to_error)?,
+        )),
+        "Graph" => Ok(Box::new(
+            serde_json::from_value::<GraphNeuralNetwork>(value).map_err(to_error)?,
+        )),
+        _ => {
+            let deserializer = REGISTRY
+                .read()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .iter()
+                .find(|(name, _)| name == layer_type)
+                .map(|(_, deserializer)| *deserializer)
+                .ok_or_else(|| NeuralNetworksError::UnknownLayerType(layer_type.to_string()))?;
+
+            deserializer(value)
+        }
+    }
+}
+
+fn is_built_in(layer_type: &str) -> bool {
+    [
+        "Dense",
+        "LayerNormalization",
+        "Dropout",
+        "Conv2d",
+        "Pooling2d",
+        "Flatten",
+        "Graph",
+    ]
+    .contains(&layer_type)
+}
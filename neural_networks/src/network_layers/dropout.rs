@@ -0,0 +1,101 @@
+extern crate randomization;
+extern crate serde;
+extern crate serde_json;
+
+use self::randomization::randomizer::RandomizerTrait;
+use self::randomization::seeded_randomizer::SeededRandomizer;
+use self::serde::{Deserialize, Serialize};
+use network_layers::network_layer_trait::NetworkLayerTrait;
+use neural_networks_error::NeuralNetworksError;
+
+// While training, forward_step zeroes each value with the given rate and scales up the rest so
+// the expected sum stays the same. Otherwise, and always through forward, values pass unchanged.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Dropout {
+    size: usize,
+    rate: f64,
+    randomizer: SeededRandomizer,
+    #[serde(skip)]
+    training: bool,
+}
+
+impl Dropout {
+    pub fn new(size: usize, rate: f64, seed: u64) -> Result<Self, NeuralNetworksError> {
+        if !(0.0..1.0).contains(&rate) {
+            return Err(NeuralNetworksError::DropoutRateOutOfRange(rate));
+        }
+
+        Ok(Dropout {
+            size,
+            rate,
+            randomizer: SeededRandomizer::new(seed),
+            training: false,
+        })
+    }
+
+    pub fn get_rate(&self) -> f64 {
+        self.rate
+    }
+
+    pub fn is_training(&self) -> bool {
+        self.training
+    }
+}
+
+impl NetworkLayerTrait for Dropout {
+    fn get_layer_type(&self) -> &str {
+        "Dropout"
+    }
+
+    fn get_input_shape(&self) -> Vec<usize> {
+        vec![self.size]
+    }
+
+    fn get_output_shape(&self) -> Vec<usize> {
+        vec![self.size]
+    }
+
+    fn forward(&self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError> {
+        if inputs.len() != self.size {
+            return Err(NeuralNetworksError::WrongNumberOfInputs {
+                expected: self.size,
+                received: inputs.len(),
+            });
+        }
+
+        Ok(inputs.to_vec())
+    }
+
+    fn to_json(&self) -> Result<serde_json::Value, NeuralNetworksError> {
+        serde_json::to_value(self).map_err(|error| NeuralNetworksError::LayerSerialization {
+            layer_type: self.get_layer_type().to_string(),
+            message: error.to_string(),
+        })
+    }
+
+    fn clone_box(&self) -> Box<dyn NetworkLayerTrait> {
+        Box::new(self.clone())
+    }
+
+    fn forward_step(&mut self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError> {
+        let mut outputs = self.forward(inputs)?;
+
+        if self.training {
+            let scale = 1.0 / (1.0 - self.rate);
+
+            for output in outputs.iter_mut() {
+                if self.randomizer.generate_float_from_0_to_1() < self.rate {
+                    *output = 0.0;
+                } else {
+                    *output *= scale;
+                }
+            }
+        }
+
+        Ok(outputs)
+    }
+
+    fn set_training(&mut self, training: bool) {
+        self.training = training;
+    }
+}
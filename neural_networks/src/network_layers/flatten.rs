@@ -0,0 +1,57 @@
+extern crate serde;
+extern crate serde_json;
+
+use self::serde::{Deserialize, Serialize};
+use network_layers::network_layer_trait::NetworkLayerTrait;
+use neural_networks_error::NeuralNetworksError;
+
+// The values stay as they are; only the shape they are read with changes, typically so dense
+// layers can follow convolutional ones.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Flatten {
+    input_shape: Vec<usize>,
+}
+
+impl Flatten {
+    pub fn new(input_shape: Vec<usize>) -> Self {
+        Flatten { input_shape }
+    }
+}
+
+impl NetworkLayerTrait for Flatten {
+    fn get_layer_type(&self) -> &str {
+        "Flatten"
+    }
+
+    fn get_input_shape(&self) -> Vec<usize> {
+        self.input_shape.clone()
+    }
+
+    fn get_output_shape(&self) -> Vec<usize> {
+        vec![self.input_shape.iter().product()]
+    }
+
+    fn forward(&self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError> {
+        let expected: usize = self.input_shape.iter().product();
+
+        if inputs.len() != expected {
+            return Err(NeuralNetworksError::WrongNumberOfInputs {
+                expected,
+                received: inputs.len(),
+            });
+        }
+
+        Ok(inputs.to_vec())
+    }
+
+    fn to_json(&self) -> Result<serde_json::Value, NeuralNetworksError> {
+        serde_json::to_value(self).map_err(|error| NeuralNetworksError::LayerSerialization {
+            layer_type: self.get_layer_type().to_string(),
+            message: error.to_string(),
+        })
+    }
+
+    fn clone_box(&self) -> Box<dyn NetworkLayerTrait> {
+        Box::new(self.clone())
+    }
+}
@@ -0,0 +1,796 @@
+extern crate serde;
+extern crate serde_json;
+
+use self::serde::de::Error as DeserializationError;
+use self::serde::ser::Error as SerializationError;
+use self::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use network_layers::merges::Merges;
+use network_layers::network_layer_trait::NetworkLayerTrait;
+use network_layers::tagged_network_layer::TaggedNetworkLayer;
+use neural_network::NeuralNetworkTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::Neuron;
+
+// The node that stands for the inputs of the whole network.
+pub const GRAPH_INPUT_NODE: usize = 0;
+
+#[derive(Debug, Clone)]
+struct GraphNode {
+    layer: Box<dyn NetworkLayerTrait>,
+    sources: Vec<usize>,
+    merge: Merges,
+}
+
+// A network whose layers form a directed acyclic graph rather than a chain. Each node holds a
+// layer, and feeds it the merged outputs of the nodes it is connected from, so outputs can skip
+// ahead of the layers in between (residual connections, for instance). Nodes get identified by
+// the order they were added in, starting at 1, since 0 is the input node. Connections that would
+// close a cycle are refused as they get made, and validate checks that every node gets as many
+// values as its layer expects. The order the nodes get propagated in is worked out again whenever
+// the graph changes, rather than on every propagation.
+#[derive(Debug, Clone)]
+pub struct GraphNeuralNetwork {
+    number_of_inputs: usize,
+    nodes: Vec<GraphNode>,
+    output_node: Option<usize>,
+    topological_order: Option<Vec<usize>>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SerializedGraphNode {
+    #[serde(flatten)]
+    layer: TaggedNetworkLayer,
+    sources: Vec<usize>,
+    merge: Merges,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SerializedGraphNeuralNetwork {
+    number_of_inputs: usize,
+    nodes: Vec<SerializedGraphNode>,
+    output_node: Option<usize>,
+}
+
+impl GraphNeuralNetwork {
+    pub fn new(number_of_inputs: usize) -> Self {
+        GraphNeuralNetwork {
+            number_of_inputs,
+            nodes: Vec::new(),
+            output_node: None,
+            topological_order: None,
+        }
+    }
+
+    // Returns the identifier of the new node, which has no connections yet.
+    pub fn add_node(&mut self, layer: Box<dyn NetworkLayerTrait>, merge: Merges) -> usize {
+        self.nodes.push(GraphNode {
+            layer,
+            sources: Vec::new(),
+            merge,
+        });
+
+        // The new node isn't reachable until it gets connected.
+        self.topological_order = None;
+
+        self.nodes.len()
+    }
+
+    pub fn connect(&mut self, source: usize, target: usize) -> Result<(), NeuralNetworksError> {
+        if source > self.nodes.len() {
+            return Err(NeuralNetworksError::NodeOutOfRange {
+                node: source,
+                number_of_nodes: self.nodes.len(),
+            });
+        }
+        // The inputs (node 0) can send connections, but not receive them.
+        if target == GRAPH_INPUT_NODE || target > self.nodes.len() {
+            return Err(NeuralNetworksError::NodeOutOfRange {
+                node: target,
+                number_of_nodes: self.nodes.len(),
+            });
+        }
+        if self.nodes[target - 1].sources.contains(&source) {
+            return Err(NeuralNetworksError::AlreadyConnected { source, target });
+        }
+        if source == target || self.is_ancestor(target, source) {
+            return Err(NeuralNetworksError::WouldCloseCycle { source, target });
+        }
+
+        self.nodes[target - 1].sources.push(source);
+        self.topological_order = self.sort_topologically();
+
+        Ok(())
+    }
+
+    pub fn set_output_node(&mut self, output_node: usize) -> Result<(), NeuralNetworksError> {
+        if output_node == GRAPH_INPUT_NODE || output_node > self.nodes.len() {
+            return Err(NeuralNetworksError::NodeOutOfRange {
+                node: output_node,
+                number_of_nodes: self.nodes.len(),
+            });
+        }
+
+        self.output_node = Some(output_node);
+
+        Ok(())
+    }
+
+    // The last node added, unless another one was set.
+    pub fn get_output_node(&self) -> Option<usize> {
+        self.output_node.or(if self.nodes.is_empty() {
+            None
+        } else {
+            Some(self.nodes.len())
+        })
+    }
+
+    pub fn get_number_of_nodes(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn get_layer(&self, node: usize) -> Option<&dyn NetworkLayerTrait> {
+        node.checked_sub(1)
+            .and_then(|index| self.nodes.get(index))
+            .map(|graph_node| graph_node.layer.as_ref())
+    }
+
+    pub fn get_sources(&self, node: usize) -> Option<&[usize]> {
+        node.checked_sub(1)
+            .and_then(|index| self.nodes.get(index))
+            .map(|graph_node| &graph_node.sources[..])
+    }
+
+    fn get_output_size(&self, node: usize) -> usize {
+        if node == GRAPH_INPUT_NODE {
+            self.number_of_inputs
+        } else {
+            self.nodes[node - 1]
+                .layer
+                .get_output_shape()
+                .iter()
+                .product()
+        }
+    }
+
+    fn is_ancestor(&self, ancestor: usize, node: usize) -> bool {
+        let mut pending = vec![node];
+        let mut visited = vec![false; self.nodes.len() + 1];
+
+        while let Some(current) = pending.pop() {
+            if current == ancestor {
+                return true;
+            }
+            if current == GRAPH_INPUT_NODE || visited[current] {
+                continue;
+            }
+
+            visited[current] = true;
+            pending.extend(self.nodes[current - 1].sources.iter());
+        }
+
+        false
+    }
+
+    // Every node comes after all of its sources.
+    pub fn get_topological_order(&self) -> Result<&[usize], NeuralNetworksError> {
+        self.topological_order
+            .as_deref()
+            .ok_or(NeuralNetworksError::UnreachableNodes)
+    }
+
+    fn sort_topologically(&self) -> Option<Vec<usize>> {
+        let mut remaining_sources: Vec<usize> = std::iter::once(0)
+            .chain(self.nodes.iter().map(|graph_node| graph_node.sources.len()))
+            .collect();
+
+        let mut ready = vec![GRAPH_INPUT_NODE];
+        let mut order = Vec::with_capacity(self.nodes.len());
+
+        while let Some(node) = ready.pop() {
+            if node != GRAPH_INPUT_NODE {
+                order.push(node);
+            }
+
+            for (index, graph_node) in self.nodes.iter().enumerate() {
+                for _ in graph_node.sources.iter().filter(|source| **source == node) {
+                    remaining_sources[index + 1] -= 1;
+
+                    if remaining_sources[index + 1] == 0 {
+                        ready.push(index + 1);
+                    }
+                }
+            }
+        }
+
+        if order.len() == self.nodes.len() {
+            Some(order)
+        } else {
+            None
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), NeuralNetworksError> {
+        if self.nodes.is_empty() {
+            return Err(NeuralNetworksError::NoLayers);
+        }
+
+        for (index, graph_node) in self.nodes.iter().enumerate() {
+            if graph_node.sources.is_empty() {
+                return Err(NeuralNetworksError::UnconnectedNode(index + 1));
+            }
+
+            let source_sizes: Vec<usize> = graph_node
+                .sources
+                .iter()
+                .map(|source| self.get_output_size(*source))
+                .collect();
+
+            let merged_size = match graph_node.merge {
+                Merges::Concatenate => source_sizes.iter().sum(),
+                Merges::Sum => {
+                    if source_sizes.windows(2).any(|sizes| sizes[0] != sizes[1]) {
+                        return Err(NeuralNetworksError::SumOfDifferentSizes(source_sizes));
+                    }
+
+                    source_sizes.first().cloned().unwrap_or(0)
+                }
+            };
+
+            let expected_size: usize = graph_node.layer.get_input_shape().iter().product();
+
+            if merged_size != expected_size {
+                return Err(NeuralNetworksError::LayersDontConnect {
+                    outputs_of_last_layer: merged_size,
+                    inputs_of_new_layer: expected_size,
+                });
+            }
+        }
+
+        self.get_topological_order()?;
+
+        Ok(())
+    }
+
+    pub fn propagate(&self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError> {
+        let mut outputs = self.start_outputs(inputs)?;
+
+        for node in self.get_topological_order()? {
+            let merged_inputs = self.merge_inputs(*node, &outputs);
+            outputs[*node] = Some(self.nodes[*node - 1].layer.forward(&merged_inputs)?);
+        }
+
+        self.take_output(outputs)
+    }
+
+    pub fn propagate_step(&mut self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError> {
+        let order = self.get_topological_order()?.to_vec();
+        let mut outputs = self.start_outputs(inputs)?;
+
+        for node in order {
+            let merged_inputs = self.merge_inputs(node, &outputs);
+            outputs[node] = Some(self.nodes[node - 1].layer.forward_step(&merged_inputs)?);
+        }
+
+        self.take_output(outputs)
+    }
+
+    pub fn reset_state(&mut self) {
+        for graph_node in self.nodes.iter_mut() {
+            graph_node.layer.reset_state();
+        }
+    }
+
+    pub fn set_training(&mut self, training: bool) {
+        for graph_node in self.nodes.iter_mut() {
+            graph_node.layer.set_training(training);
+        }
+    }
+
+    // Node by node, in the order they were added.
+    pub fn parameters(&self) -> Vec<f64> {
+        self.nodes
+            .iter()
+            .flat_map(|graph_node| graph_node.layer.parameters())
+            .collect()
+    }
+
+    pub fn set_parameters(&mut self, parameters: &[f64]) -> Result<(), NeuralNetworksError> {
+        if parameters.len() != self.get_number_of_parameters() {
+            return Err(NeuralNetworksError::WrongNumberOfParameters {
+                expected: self.get_number_of_parameters(),
+                received: parameters.len(),
+            });
+        }
+
+        let mut remaining_parameters = parameters;
+
+        for graph_node in self.nodes.iter_mut() {
+            let (node_parameters, rest) =
+                remaining_parameters.split_at(graph_node.layer.get_number_of_parameters());
+
+            graph_node.layer.set_parameters(node_parameters)?;
+            remaining_parameters = rest;
+        }
+
+        Ok(())
+    }
+
+    pub fn get_number_of_parameters(&self) -> usize {
+        self.nodes
+            .iter()
+            .map(|graph_node| graph_node.layer.get_number_of_parameters())
+            .sum()
+    }
+
+    fn start_outputs(&self, inputs: &[f64]) -> Result<Vec<Option<Vec<f64>>>, NeuralNetworksError> {
+        if self.nodes.is_empty() {
+            return Err(NeuralNetworksError::NoLayers);
+        }
+        if inputs.len() != self.number_of_inputs {
+            return Err(NeuralNetworksError::WrongNumberOfInputs {
+                expected: self.number_of_inputs,
+                received: inputs.len(),
+            });
+        }
+
+        let mut outputs = vec![None; self.nodes.len() + 1];
+        outputs[GRAPH_INPUT_NODE] = Some(inputs.to_vec());
+
+        Ok(outputs)
+    }
+
+    // The topological order guarantees every source already has its outputs.
+    fn merge_inputs(&self, node: usize, outputs: &[Option<Vec<f64>>]) -> Vec<f64> {
+        let graph_node = &self.nodes[node - 1];
+        let mut sources = graph_node
+            .sources
+            .iter()
+            .map(|source| outputs[*source].as_ref().unwrap());
+
+        match graph_node.merge {
+            Merges::Concatenate => sources.flatten().cloned().collect(),
+            Merges::Sum => {
+                let mut merged = sources.next().cloned().unwrap_or_default();
+
+                for source in sources {
+                    for (merged_value, value) in merged.iter_mut().zip(source.iter()) {
+                        *merged_value += value;
+                    }
+                }
+
+                merged
+            }
+        }
+    }
+
+    fn take_output(
+        &self,
+        mut outputs: Vec<Option<Vec<f64>>>,
+    ) -> Result<Vec<f64>, NeuralNetworksError> {
+        self.get_output_node()
+            .and_then(|output_node| outputs[output_node].take())
+            .ok_or(NeuralNetworksError::NoLayers)
+    }
+}
+
+impl NetworkLayerTrait for GraphNeuralNetwork {
+    fn get_layer_type(&self) -> &str {
+        "Graph"
+    }
+
+    fn get_input_shape(&self) -> Vec<usize> {
+        vec![self.number_of_inputs]
+    }
+
+    fn get_output_shape(&self) -> Vec<usize> {
+        self.get_output_node()
+            .map(|output_node| self.nodes[output_node - 1].layer.get_output_shape())
+            .unwrap_or_default()
+    }
+
+    fn forward(&self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError> {
+        self.propagate(inputs)
+    }
+
+    fn to_json(&self) -> Result<serde_json::Value, NeuralNetworksError> {
+        serde_json::to_value(self).map_err(|error| NeuralNetworksError::LayerSerialization {
+            layer_type: self.get_layer_type().to_string(),
+            message: error.to_string(),
+        })
+    }
+
+    fn clone_box(&self) -> Box<dyn NetworkLayerTrait> {
+        Box::new(self.clone())
+    }
+
+    fn forward_step(&mut self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError> {
+        self.propagate_step(inputs)
+    }
+
+    fn reset_state(&mut self) {
+        GraphNeuralNetwork::reset_state(self);
+    }
+
+    fn set_training(&mut self, training: bool) {
+        GraphNeuralNetwork::set_training(self, training);
+    }
+
+    fn parameters(&self) -> Vec<f64> {
+        GraphNeuralNetwork::parameters(self)
+    }
+
+    fn set_parameters(&mut self, parameters: &[f64]) -> Result<(), NeuralNetworksError> {
+        GraphNeuralNetwork::set_parameters(self, parameters)
+    }
+
+    fn get_number_of_parameters(&self) -> usize {
+        GraphNeuralNetwork::get_number_of_parameters(self)
+    }
+}
+
+// Evolves and gets evaluated like any other network, through the parameters of its nodes.
+impl NeuralNetworkTrait<Neuron> for GraphNeuralNetwork {
+    fn propagate(&self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError> {
+        GraphNeuralNetwork::propagate(self, inputs)
+    }
+
+    fn propagate_batch(
+        &self,
+        inputs: &[f64],
+        outputs: &mut Vec<f64>,
+    ) -> Result<(), NeuralNetworksError> {
+        if self.number_of_inputs == 0 || !inputs.len().is_multiple_of(self.number_of_inputs) {
+            return Err(NeuralNetworksError::WrongNumberOfInputs {
+                expected: self.number_of_inputs,
+                received: inputs.len(),
+            });
+        }
+
+        outputs.clear();
+
+        for sample in inputs.chunks(self.number_of_inputs) {
+            outputs.extend(GraphNeuralNetwork::propagate(self, sample)?);
+        }
+
+        Ok(())
+    }
+
+    fn propagate_step(&mut self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError> {
+        GraphNeuralNetwork::propagate_step(self, inputs)
+    }
+
+    fn reset_state(&mut self) {
+        GraphNeuralNetwork::reset_state(self);
+    }
+
+    fn parameters(&self) -> Vec<f64> {
+        GraphNeuralNetwork::parameters(self)
+    }
+
+    fn set_parameters(&mut self, parameters: &[f64]) -> Result<(), NeuralNetworksError> {
+        GraphNeuralNetwork::set_parameters(self, parameters)
+    }
+
+    fn get_number_of_parameters(&self) -> usize {
+        GraphNeuralNetwork::get_number_of_parameters(self)
+    }
+
+    // Same kinds of layers in the same nodes, with as many parameters each.
+    fn has_same_parameter_layout(&self, other: &Self) -> bool {
+        self.nodes.len() == other.nodes.len()
+            && self
+                .nodes
+                .iter()
+                .zip(other.nodes.iter())
+                .all(|(graph_node, other_graph_node)| {
+                    graph_node.layer.get_layer_type() == other_graph_node.layer.get_layer_type()
+                        && graph_node.layer.get_number_of_parameters()
+                            == other_graph_node.layer.get_number_of_parameters()
+                })
+    }
+}
+
+impl Serialize for GraphNeuralNetwork {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let nodes = self
+            .nodes
+            .iter()
+            .map(|graph_node| {
+                Ok(SerializedGraphNode {
+                    layer: TaggedNetworkLayer::new(graph_node.layer.as_ref())?,
+                    sources: graph_node.sources.clone(),
+                    merge: graph_node.merge,
+                })
+            })
+            .collect::<Result<Vec<SerializedGraphNode>, NeuralNetworksError>>()
+            .map_err(S::Error::custom)?;
+
+        SerializedGraphNeuralNetwork {
+            number_of_inputs: self.number_of_inputs,
+            nodes,
+            output_node: self.output_node,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for GraphNeuralNetwork {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let serialized = SerializedGraphNeuralNetwork::deserialize(deserializer)?;
+
+        let mut graph_neural_network = GraphNeuralNetwork::new(serialized.number_of_inputs);
+        let mut connections = Vec::new();
+
+        for serialized_node in serialized.nodes {
+            let node = graph_neural_network.add_node(
+                serialized_node
+                    .layer
+                    .into_layer()
+                    .map_err(D::Error::custom)?,
+                serialized_node.merge,
+            );
+
+            connections.extend(
+                serialized_node
+                    .sources
+                    .into_iter()
+                    .map(|source| (source, node)),
+            );
+        }
+
+        // Connecting again goes through the same checks as building the graph did.
+        for (source, target) in connections {
+            graph_neural_network
+                .connect(source, target)
+                .map_err(D::Error::custom)?;
+        }
+
+        if let Some(output_node) = serialized.output_node {
+            graph_neural_network
+                .set_output_node(output_node)
+                .map_err(D::Error::custom)?;
+        }
+
+        graph_neural_network.validate().map_err(D::Error::custom)?;
+
+        Ok(graph_neural_network)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use layer::Layer;
+    use neuron::Neuron;
+    use neuron_activation::activation_functions::ActivationFunctions;
+
+    extern crate randomization;
+    use self::randomization::randomizer::RandomizerTrait;
+    use self::randomization::seeded_randomizer::SeededRandomizer;
+
+    fn create_dense_layer(
+        number_of_inputs: u32,
+        number_of_neurons: u32,
+        randomizer: &mut SeededRandomizer,
+    ) -> Result<Box<dyn NetworkLayerTrait>, NeuralNetworksError> {
+        Ok(Box::new(Layer::<Neuron>::create_layer(
+            number_of_inputs,
+            number_of_neurons,
+            randomizer,
+            |number_of_inputs, randomizer| {
+                Neuron::new(number_of_inputs, ActivationFunctions::Identity, randomizer)
+            },
+        )?))
+    }
+
+    // inputs -> 1 -> 2, with 2 summed with the inputs in 3 (a residual connection), and 4
+    // reading the concatenation of 1 and 3.
+    fn setup_graph_neural_network() -> Result<GraphNeuralNetwork, NeuralNetworksError> {
+        let mut randomizer = SeededRandomizer::new(2);
+
+        let mut graph_neural_network = GraphNeuralNetwork::new(3);
+
+        let first = graph_neural_network.add_node(
+            create_dense_layer(3, 4, &mut randomizer)?,
+            Merges::Concatenate,
+        );
+        let second = graph_neural_network.add_node(
+            create_dense_layer(4, 3, &mut randomizer)?,
+            Merges::Concatenate,
+        );
+        let residual =
+            graph_neural_network.add_node(create_dense_layer(3, 3, &mut randomizer)?, Merges::Sum);
+        let last = graph_neural_network.add_node(
+            create_dense_layer(7, 2, &mut randomizer)?,
+            Merges::Concatenate,
+        );
+
+        graph_neural_network.connect(GRAPH_INPUT_NODE, first)?;
+        graph_neural_network.connect(first, second)?;
+        graph_neural_network.connect(second, residual)?;
+        graph_neural_network.connect(GRAPH_INPUT_NODE, residual)?;
+        graph_neural_network.connect(first, last)?;
+        graph_neural_network.connect(residual, last)?;
+
+        graph_neural_network.validate()?;
+
+        Ok(graph_neural_network)
+    }
+
+    #[test]
+    fn test_skip_connections_get_merged_before_the_layers_they_reach(
+    ) -> Result<(), NeuralNetworksError> {
+        let mut graph_neural_network = setup_graph_neural_network()?;
+
+        // Only the residual node gets a bias, so its output is the sum of its inputs plus one.
+        let mut parameters = vec![0.0; graph_neural_network.get_number_of_parameters()];
+        let residual_start = (3 + 1) * 4 + (4 + 1) * 3;
+        for neuron in 0..3 {
+            parameters[residual_start + neuron * 4] = 1.0;
+            parameters[residual_start + neuron * 4 + 1 + neuron] = 1.0;
+        }
+        graph_neural_network.set_parameters(&parameters)?;
+        graph_neural_network.set_output_node(3)?;
+
+        assert_eq!(
+            graph_neural_network.propagate(&[1.0, 2.0, 3.0])?,
+            vec![2.0, 3.0, 4.0]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cycles_and_mismatched_sizes_get_refused() -> Result<(), NeuralNetworksError> {
+        let mut graph_neural_network = setup_graph_neural_network()?;
+
+        assert_eq!(
+            graph_neural_network.connect(4, 1),
+            Err(NeuralNetworksError::WouldCloseCycle {
+                source: 4,
+                target: 1
+            })
+        );
+        assert_eq!(
+            graph_neural_network.connect(2, 2),
+            Err(NeuralNetworksError::WouldCloseCycle {
+                source: 2,
+                target: 2
+            })
+        );
+        assert_eq!(
+            graph_neural_network.connect(1, 2),
+            Err(NeuralNetworksError::AlreadyConnected {
+                source: 1,
+                target: 2
+            })
+        );
+        assert_eq!(
+            graph_neural_network.connect(1, GRAPH_INPUT_NODE),
+            Err(NeuralNetworksError::NodeOutOfRange {
+                node: GRAPH_INPUT_NODE,
+                number_of_nodes: 4
+            })
+        );
+
+        graph_neural_network.connect(GRAPH_INPUT_NODE, 4)?;
+
+        assert_eq!(
+            graph_neural_network.validate(),
+            Err(NeuralNetworksError::LayersDontConnect {
+                outputs_of_last_layer: 10,
+                inputs_of_new_layer: 7,
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_a_graph_round_trips_through_serialization() -> Result<(), NeuralNetworksError> {
+        let graph_neural_network = setup_graph_neural_network()?;
+
+        let serialized = serde_json::to_string(&graph_neural_network).unwrap();
+        let deserialized: GraphNeuralNetwork = serde_json::from_str(&serialized).unwrap();
+
+        let inputs = [0.2, -0.4, 0.9];
+
+        assert_eq!(deserialized.get_sources(4), Some(&[1, 3][..]));
+        assert_eq!(
+            deserialized.propagate(&inputs)?,
+            graph_neural_network.propagate(&inputs)?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_graphs_get_evolved_through_the_parameters_of_their_nodes(
+    ) -> Result<(), NeuralNetworksError> {
+        use evolution::controllers::create_next_generation_of_parameters::create_next_generation_of_parameters;
+        use evolution::domain::evolution_config::EvolutionConfig;
+        use evolution::domain::genome::{Genome, GenomeTrait};
+        use evolution::domain::mutation_operators::MutationOperators;
+        use evolution::domain::population::{Population, PopulationTrait};
+        use evolution::domain::selection::truncation_selection::TruncationSelection;
+
+        let mut randomizer = SeededRandomizer::new(6);
+        let mut population = Population::new();
+
+        for identifier in 1..=6 {
+            let mut graph_neural_network = setup_graph_neural_network()?;
+            let parameters: Vec<f64> = (0..graph_neural_network.get_number_of_parameters())
+                .map(|_| randomizer.get_normal())
+                .collect();
+            graph_neural_network.set_parameters(&parameters)?;
+
+            let mut genome = Genome::new(identifier, graph_neural_network);
+            genome.set_fitness(f64::from(identifier));
+            population.add(genome)?;
+        }
+
+        let mut evolution_config = EvolutionConfig::new();
+        evolution_config.set_mutation_rate(0.5)?;
+        evolution_config
+            .set_mutation_operator(MutationOperators::GaussianPerturbation { sigma: 0.1 });
+
+        let next_generation = create_next_generation_of_parameters(
+            &population,
+            &TruncationSelection::new(0.5),
+            &evolution_config,
+            Genome::new,
+            &mut randomizer,
+        )?;
+
+        let parameters_before: Vec<Vec<f64>> = population
+            .get_genomes()?
+            .iter()
+            .map(|genome| genome.get_neural_network().parameters())
+            .collect();
+        let genomes = next_generation.get_genomes()?;
+
+        assert_eq!(genomes.len(), 6);
+        assert!(genomes[1..]
+            .iter()
+            .any(|genome| !parameters_before.contains(&genome.get_neural_network().parameters())));
+        assert_eq!(
+            genomes[1]
+                .get_neural_network()
+                .propagate(&[0.2, -0.4, 0.9])?
+                .len(),
+            2
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_nodes_added_without_connections_stop_the_propagation() -> Result<(), NeuralNetworksError>
+    {
+        let mut randomizer = SeededRandomizer::new(2);
+        let mut graph_neural_network = setup_graph_neural_network()?;
+
+        let unconnected = graph_neural_network.add_node(
+            create_dense_layer(2, 2, &mut randomizer)?,
+            Merges::Concatenate,
+        );
+
+        assert!(graph_neural_network.propagate(&[0.2, -0.4, 0.9]).is_err());
+        assert_eq!(
+            graph_neural_network.validate(),
+            Err(NeuralNetworksError::UnconnectedNode(unconnected))
+        );
+        assert_eq!(
+            graph_neural_network.get_topological_order(),
+            Err(NeuralNetworksError::UnreachableNodes)
+        );
+
+        graph_neural_network.connect(4, unconnected)?;
+
+        assert_eq!(graph_neural_network.get_topological_order()?.len(), 5);
+        assert_eq!(graph_neural_network.propagate(&[0.2, -0.4, 0.9])?.len(), 2);
+
+        Ok(())
+    }
+}
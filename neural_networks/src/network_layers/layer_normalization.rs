@@ -0,0 +1,129 @@
+extern crate serde;
+extern crate serde_json;
+
+use self::serde::{Deserialize, Serialize};
+use network_layers::network_layer_trait::NetworkLayerTrait;
+use neural_networks_error::NeuralNetworksError;
+
+// Keeps the variance from this small value so constant inputs don't divide by zero.
+const EPSILON: f64 = 1e-5;
+
+// Normalizes the values of each sample to a zero mean and a unit variance, then scales and shifts
+// them by a learnable gain and bias per value.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LayerNormalization {
+    gains: Vec<f64>,
+    biases: Vec<f64>,
+}
+
+impl LayerNormalization {
+    pub fn new(size: usize) -> Self {
+        LayerNormalization {
+            gains: vec![1.0; size],
+            biases: vec![0.0; size],
+        }
+    }
+
+    pub fn get_gains(&self) -> &[f64] {
+        &self.gains
+    }
+
+    pub fn get_biases(&self) -> &[f64] {
+        &self.biases
+    }
+}
+
+impl NetworkLayerTrait for LayerNormalization {
+    fn get_layer_type(&self) -> &str {
+        "LayerNormalization"
+    }
+
+    fn get_input_shape(&self) -> Vec<usize> {
+        vec![self.gains.len()]
+    }
+
+    fn get_output_shape(&self) -> Vec<usize> {
+        vec![self.gains.len()]
+    }
+
+    fn forward(&self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError> {
+        if inputs.len() != self.gains.len() || inputs.is_empty() {
+            return Err(NeuralNetworksError::WrongNumberOfInputs {
+                expected: self.gains.len(),
+                received: inputs.len(),
+            });
+        }
+
+        let mean = inputs.iter().sum::<f64>() / inputs.len() as f64;
+        let variance = inputs
+            .iter()
+            .map(|input| (input - mean).powi(2))
+            .sum::<f64>()
+            / inputs.len() as f64;
+        let deviation = (variance + EPSILON).sqrt();
+
+        Ok(inputs
+            .iter()
+            .zip(self.gains.iter().zip(self.biases.iter()))
+            .map(|(input, (gain, bias))| gain * (input - mean) / deviation + bias)
+            .collect())
+    }
+
+    fn to_json(&self) -> Result<serde_json::Value, NeuralNetworksError> {
+        serde_json::to_value(self).map_err(|error| NeuralNetworksError::LayerSerialization {
+            layer_type: self.get_layer_type().to_string(),
+            message: error.to_string(),
+        })
+    }
+
+    fn clone_box(&self) -> Box<dyn NetworkLayerTrait> {
+        Box::new(self.clone())
+    }
+
+    // The gains, followed by the biases.
+    fn parameters(&self) -> Vec<f64> {
+        self.gains
+            .iter()
+            .chain(self.biases.iter())
+            .cloned()
+            .collect()
+    }
+
+    fn set_parameters(&mut self, parameters: &[f64]) -> Result<(), NeuralNetworksError> {
+        if parameters.len() != 2 * self.gains.len() {
+            return Err(NeuralNetworksError::WrongNumberOfParameters {
+                expected: 2 * self.gains.len(),
+                received: parameters.len(),
+            });
+        }
+
+        let (gains, biases) = parameters.split_at(self.gains.len());
+
+        self.gains.copy_from_slice(gains);
+        self.biases.copy_from_slice(biases);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_normalized_outputs_have_a_zero_mean_and_a_unit_variance(
+    ) -> Result<(), NeuralNetworksError> {
+        let layer_normalization = LayerNormalization::new(4);
+
+        let outputs = layer_normalization.forward(&[2.0, 4.0, 6.0, 8.0])?;
+
+        let mean = outputs.iter().sum::<f64>() / 4.0;
+        let variance = outputs.iter().map(|output| output.powi(2)).sum::<f64>() / 4.0;
+
+        assert!(mean.abs() < 1e-12);
+        assert!((variance - 1.0).abs() < 1e-5);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+extern crate serde;
+
+use self::serde::{Deserialize, Serialize};
+
+// How a node of a graph neural network combines the outputs it receives, in the order its sources
+// were connected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum Merges {
+    Concatenate,
+    Sum,
+}
@@ -0,0 +1,12 @@
+pub mod conv2d;
+pub mod dense_network_layer;
+pub mod deserialize_network_layer;
+pub mod dropout;
+pub mod flatten;
+pub mod graph_neural_network;
+pub mod layer_normalization;
+pub mod merges;
+pub mod modular_neural_network;
+pub mod network_layer_trait;
+pub mod pooling2d;
+pub mod tagged_network_layer;
@@ -0,0 +1,350 @@
+extern crate serde;
+extern crate serde_json;
+
+use self::serde::de::Error as DeserializationError;
+use self::serde::ser::Error as SerializationError;
+use self::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use network_layers::network_layer_trait::NetworkLayerTrait;
+use network_layers::tagged_network_layer::TaggedNetworkLayer;
+use neural_network::NeuralNetworkTrait;
+use neural_networks_error::NeuralNetworksError;
+use neuron::Neuron;
+
+// Unlike NeuralNetwork, the layers may be of any kind and mixed freely. Each layer gets serialized
+// along with its layer type, as in {"layers": [{"type": "Dense", "layer": {...}}, ...]}.
+#[derive(Debug, Clone, Default)]
+pub struct ModularNeuralNetwork {
+    layers: Vec<Box<dyn NetworkLayerTrait>>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SerializedModularNeuralNetwork {
+    layers: Vec<TaggedNetworkLayer>,
+}
+
+impl ModularNeuralNetwork {
+    pub fn new() -> Self {
+        ModularNeuralNetwork { layers: Vec::new() }
+    }
+
+    pub fn add(&mut self, layer: Box<dyn NetworkLayerTrait>) -> Result<(), NeuralNetworksError> {
+        if let Some(last_layer) = self.layers.last() {
+            let outputs_of_last_layer: usize = last_layer.get_output_shape().iter().product();
+            let inputs_of_new_layer: usize = layer.get_input_shape().iter().product();
+
+            if outputs_of_last_layer != inputs_of_new_layer {
+                return Err(NeuralNetworksError::LayersDontConnect {
+                    outputs_of_last_layer,
+                    inputs_of_new_layer,
+                });
+            }
+        }
+
+        self.layers.push(layer);
+
+        Ok(())
+    }
+
+    pub fn get_number_of_layers(&self) -> usize {
+        self.layers.len()
+    }
+
+    pub fn get_layers(&self) -> &[Box<dyn NetworkLayerTrait>] {
+        &self.layers
+    }
+
+    pub fn get_layer(&self, index: usize) -> Option<&dyn NetworkLayerTrait> {
+        self.layers.get(index).map(|layer| layer.as_ref())
+    }
+
+    pub fn get_layer_mut(&mut self, index: usize) -> Option<&mut Box<dyn NetworkLayerTrait>> {
+        self.layers.get_mut(index)
+    }
+
+    pub fn get_input_shape(&self) -> Vec<usize> {
+        self.layers
+            .first()
+            .map(|layer| layer.get_input_shape())
+            .unwrap_or_default()
+    }
+
+    pub fn get_output_shape(&self) -> Vec<usize> {
+        self.layers
+            .last()
+            .map(|layer| layer.get_output_shape())
+            .unwrap_or_default()
+    }
+
+    pub fn set_training(&mut self, training: bool) {
+        for layer in self.layers.iter_mut() {
+            layer.set_training(training);
+        }
+    }
+}
+
+// Evolves and gets evaluated like any other network. Its dense and recurrent layers hold Neurons,
+// so that is the neuron type it goes by.
+impl NeuralNetworkTrait<Neuron> for ModularNeuralNetwork {
+    fn propagate(&self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError> {
+        if self.layers.is_empty() {
+            return Err(NeuralNetworksError::NoLayers);
+        }
+
+        let mut outputs = inputs.to_vec();
+
+        for layer in &self.layers {
+            outputs = layer.forward(&outputs)?;
+        }
+
+        Ok(outputs)
+    }
+
+    fn propagate_batch(
+        &self,
+        inputs: &[f64],
+        outputs: &mut Vec<f64>,
+    ) -> Result<(), NeuralNetworksError> {
+        let number_of_inputs: usize = self.get_input_shape().iter().product();
+
+        if number_of_inputs == 0 || !inputs.len().is_multiple_of(number_of_inputs) {
+            return Err(NeuralNetworksError::WrongNumberOfInputs {
+                expected: number_of_inputs,
+                received: inputs.len(),
+            });
+        }
+
+        outputs.clear();
+
+        for sample in inputs.chunks(number_of_inputs) {
+            outputs.extend(self.propagate(sample)?);
+        }
+
+        Ok(())
+    }
+
+    fn propagate_step(&mut self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError> {
+        if self.layers.is_empty() {
+            return Err(NeuralNetworksError::NoLayers);
+        }
+
+        let mut outputs = inputs.to_vec();
+
+        for layer in self.layers.iter_mut() {
+            outputs = layer.forward_step(&outputs)?;
+        }
+
+        Ok(outputs)
+    }
+
+    fn reset_state(&mut self) {
+        for layer in self.layers.iter_mut() {
+            layer.reset_state();
+        }
+    }
+
+    // Layer by layer, in the order each layer lays out its own parameters.
+    fn parameters(&self) -> Vec<f64> {
+        self.layers
+            .iter()
+            .flat_map(|layer| layer.parameters())
+            .collect()
+    }
+
+    fn set_parameters(&mut self, parameters: &[f64]) -> Result<(), NeuralNetworksError> {
+        if parameters.len() != self.get_number_of_parameters() {
+            return Err(NeuralNetworksError::WrongNumberOfParameters {
+                expected: self.get_number_of_parameters(),
+                received: parameters.len(),
+            });
+        }
+
+        let mut remaining_parameters = parameters;
+
+        for layer in self.layers.iter_mut() {
+            let (layer_parameters, rest) =
+                remaining_parameters.split_at(layer.get_number_of_parameters());
+
+            layer.set_parameters(layer_parameters)?;
+            remaining_parameters = rest;
+        }
+
+        Ok(())
+    }
+
+    fn get_number_of_parameters(&self) -> usize {
+        self.layers
+            .iter()
+            .map(|layer| layer.get_number_of_parameters())
+            .sum()
+    }
+
+    // Same kinds of layers, in the same order, with as many parameters each.
+    fn has_same_parameter_layout(&self, other: &Self) -> bool {
+        self.layers.len() == other.layers.len()
+            && self
+                .layers
+                .iter()
+                .zip(other.layers.iter())
+                .all(|(layer, other_layer)| {
+                    layer.get_layer_type() == other_layer.get_layer_type()
+                        && layer.get_number_of_parameters()
+                            == other_layer.get_number_of_parameters()
+                })
+    }
+}
+
+impl Serialize for ModularNeuralNetwork {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let layers = self
+            .layers
+            .iter()
+            .map(|layer| TaggedNetworkLayer::new(layer.as_ref()))
+            .collect::<Result<Vec<TaggedNetworkLayer>, NeuralNetworksError>>()
+            .map_err(S::Error::custom)?;
+
+        SerializedModularNeuralNetwork { layers }.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ModularNeuralNetwork {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let serialized = SerializedModularNeuralNetwork::deserialize(deserializer)?;
+
+        let mut modular_neural_network = ModularNeuralNetwork::new();
+
+        for tagged_layer in serialized.layers {
+            modular_neural_network
+                .add(tagged_layer.into_layer().map_err(D::Error::custom)?)
+                .map_err(D::Error::custom)?;
+        }
+
+        Ok(modular_neural_network)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use layer::Layer;
+    use network_layers::deserialize_network_layer::register_network_layer_type;
+    use network_layers::dropout::Dropout;
+    use network_layers::layer_normalization::LayerNormalization;
+    use neuron::Neuron;
+    use neuron_activation::activation_functions::ActivationFunctions;
+    use recurrence::recurrences::Recurrences;
+
+    extern crate randomization;
+    use self::randomization::seeded_randomizer::SeededRandomizer;
+
+    // Stands in for a layer type defined outside this crate.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct Doubling {
+        size: usize,
+    }
+
+    impl NetworkLayerTrait for Doubling {
+        fn get_layer_type(&self) -> &str {
+            "Doubling"
+        }
+        fn get_input_shape(&self) -> Vec<usize> {
+            vec![self.size]
+        }
+        fn get_output_shape(&self) -> Vec<usize> {
+            vec![self.size]
+        }
+        fn forward(&self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError> {
+            Ok(inputs.iter().map(|input| 2.0 * input).collect())
+        }
+        fn to_json(&self) -> Result<serde_json::Value, NeuralNetworksError> {
+            Ok(serde_json::to_value(self).unwrap())
+        }
+        fn clone_box(&self) -> Box<dyn NetworkLayerTrait> {
+            Box::new(self.clone())
+        }
+    }
+
+    fn setup_modular_neural_network() -> Result<ModularNeuralNetwork, NeuralNetworksError> {
+        let mut randomizer = SeededRandomizer::new(8);
+        let neuron_creator = |number_of_inputs, randomizer: &mut SeededRandomizer| {
+            Neuron::new(number_of_inputs, ActivationFunctions::Tanh, randomizer)
+        };
+
+        let mut modular_neural_network = ModularNeuralNetwork::new();
+
+        modular_neural_network.add(Box::new(Layer::create_layer(
+            3,
+            4,
+            &mut randomizer,
+            neuron_creator,
+        )?))?;
+        modular_neural_network.add(Box::new(LayerNormalization::new(4)))?;
+        modular_neural_network.add(Box::new(Dropout::new(4, 0.5, 3)?))?;
+        modular_neural_network.add(Box::new(Doubling { size: 4 }))?;
+        modular_neural_network.add(Box::new(Layer::create_recurrent_layer(
+            4,
+            2,
+            Recurrences::Gru,
+            &mut randomizer,
+            neuron_creator,
+        )?))?;
+
+        Ok(modular_neural_network)
+    }
+
+    #[test]
+    fn test_mixed_layers_propagate_and_round_trip_through_serialization(
+    ) -> Result<(), NeuralNetworksError> {
+        let _ = register_network_layer_type("Doubling", |value| {
+            Ok(Box::new(serde_json::from_value::<Doubling>(value).unwrap()))
+        });
+
+        let mut modular_neural_network = setup_modular_neural_network()?;
+
+        assert_eq!(modular_neural_network.get_input_shape(), vec![3]);
+        assert_eq!(modular_neural_network.get_output_shape(), vec![2]);
+        assert_eq!(
+            modular_neural_network.get_number_of_parameters(),
+            4 * 4 + 2 * 4 + 3 * 2 * (4 + 2 + 1)
+        );
+
+        let inputs = [0.3, -0.8, 1.2];
+        let outputs = modular_neural_network.propagate(&inputs)?;
+
+        assert_eq!(outputs.len(), 2);
+        assert_eq!(modular_neural_network.propagate_step(&inputs)?, outputs);
+
+        let serialized = serde_json::to_string(&modular_neural_network).unwrap();
+        assert!(serialized.contains("\"type\":\"LayerNormalization\""));
+
+        let deserialized: ModularNeuralNetwork = serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(deserialized.propagate(&inputs)?, outputs);
+        assert_eq!(
+            deserialized.parameters(),
+            modular_neural_network.parameters()
+        );
+
+        let parameters = vec![0.1; modular_neural_network.get_number_of_parameters()];
+        modular_neural_network.set_parameters(&parameters)?;
+
+        assert_eq!(modular_neural_network.parameters(), parameters);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_layers_that_dont_connect_get_rejected() -> Result<(), NeuralNetworksError> {
+        let mut modular_neural_network = setup_modular_neural_network()?;
+
+        assert_eq!(
+            modular_neural_network.add(Box::new(LayerNormalization::new(3))),
+            Err(NeuralNetworksError::LayersDontConnect {
+                outputs_of_last_layer: 2,
+                inputs_of_new_layer: 3,
+            })
+        );
+
+        Ok(())
+    }
+}
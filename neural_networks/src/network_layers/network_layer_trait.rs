@@ -0,0 +1,48 @@
+extern crate serde_json;
+
+use neural_networks_error::NeuralNetworksError;
+use std::fmt::Debug;
+
+// Any kind of layer a ModularNeuralNetwork can hold. Values flow between layers as flat vectors,
+// and the shapes only tell how a layer interprets them, so consecutive layers have to agree on
+// the number of values rather than on the exact shape. The layer type is the tag the layer gets
+// serialized under; layer types from outside this crate have to be registered with
+// register_network_layer_type before they can be deserialized.
+pub trait NetworkLayerTrait: Debug + Send + Sync {
+    fn get_layer_type(&self) -> &str;
+    fn get_input_shape(&self) -> Vec<usize>;
+    fn get_output_shape(&self) -> Vec<usize>;
+    fn forward(&self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError>;
+    fn to_json(&self) -> Result<serde_json::Value, NeuralNetworksError>;
+    fn clone_box(&self) -> Box<dyn NetworkLayerTrait>;
+
+    // Stateful layers (recurrent, dropout while training) override these.
+    fn forward_step(&mut self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError> {
+        self.forward(inputs)
+    }
+    fn reset_state(&mut self) {}
+    fn set_training(&mut self, _training: bool) {}
+
+    fn parameters(&self) -> Vec<f64> {
+        Vec::new()
+    }
+    fn set_parameters(&mut self, parameters: &[f64]) -> Result<(), NeuralNetworksError> {
+        if parameters.is_empty() {
+            Ok(())
+        } else {
+            Err(NeuralNetworksError::WrongNumberOfParameters {
+                expected: 0,
+                received: parameters.len(),
+            })
+        }
+    }
+    fn get_number_of_parameters(&self) -> usize {
+        self.parameters().len()
+    }
+}
+
+impl Clone for Box<dyn NetworkLayerTrait> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
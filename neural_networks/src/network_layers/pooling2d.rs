@@ -0,0 +1,137 @@
+extern crate serde;
+extern crate serde_json;
+
+use self::serde::{Deserialize, Serialize};
+use network_layers::network_layer_trait::NetworkLayerTrait;
+use neural_networks_error::NeuralNetworksError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Poolings {
+    Max,
+    Average,
+}
+
+// Shrinks each channel of a (channels, height, width) tensor by pooling windows of the given size,
+// moving by the size of the window so they don't overlap.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Pooling2d {
+    input_shape: [usize; 3],
+    pool_size: [usize; 2],
+    pooling: Poolings,
+}
+
+impl Pooling2d {
+    pub fn new(
+        input_shape: [usize; 3],
+        pool_size: [usize; 2],
+        pooling: Poolings,
+    ) -> Result<Self, NeuralNetworksError> {
+        if pool_size.contains(&0) || pool_size[0] > input_shape[1] || pool_size[1] > input_shape[2]
+        {
+            return Err(NeuralNetworksError::PoolDoesntFit {
+                pool_size,
+                input_shape,
+            });
+        }
+
+        Ok(Pooling2d {
+            input_shape,
+            pool_size,
+            pooling,
+        })
+    }
+
+    pub fn get_pooling(&self) -> &Poolings {
+        &self.pooling
+    }
+}
+
+impl NetworkLayerTrait for Pooling2d {
+    fn get_layer_type(&self) -> &str {
+        "Pooling2d"
+    }
+
+    fn get_input_shape(&self) -> Vec<usize> {
+        self.input_shape.to_vec()
+    }
+
+    fn get_output_shape(&self) -> Vec<usize> {
+        vec![
+            self.input_shape[0],
+            self.input_shape[1] / self.pool_size[0],
+            self.input_shape[2] / self.pool_size[1],
+        ]
+    }
+
+    fn forward(&self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError> {
+        let [channels, height, width] = self.input_shape;
+
+        if inputs.len() != channels * height * width {
+            return Err(NeuralNetworksError::WrongNumberOfInputs {
+                expected: channels * height * width,
+                received: inputs.len(),
+            });
+        }
+
+        let [pool_height, pool_width] = self.pool_size;
+        let mut outputs = Vec::with_capacity(self.get_output_shape().iter().product());
+
+        for channel in inputs.chunks(height * width) {
+            for output_y in 0..height / pool_height {
+                for output_x in 0..width / pool_width {
+                    let window = (0..pool_height).flat_map(|pool_y| {
+                        let row = (output_y * pool_height + pool_y) * width + output_x * pool_width;
+
+                        channel[row..row + pool_width].iter()
+                    });
+
+                    outputs.push(match self.pooling {
+                        Poolings::Max => window.cloned().fold(f64::NEG_INFINITY, f64::max),
+                        Poolings::Average => {
+                            window.sum::<f64>() / (pool_height * pool_width) as f64
+                        }
+                    });
+                }
+            }
+        }
+
+        Ok(outputs)
+    }
+
+    fn to_json(&self) -> Result<serde_json::Value, NeuralNetworksError> {
+        serde_json::to_value(self).map_err(|error| NeuralNetworksError::LayerSerialization {
+            layer_type: self.get_layer_type().to_string(),
+            message: error.to_string(),
+        })
+    }
+
+    fn clone_box(&self) -> Box<dyn NetworkLayerTrait> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_pooling_takes_the_maximum_or_the_average_of_each_window(
+    ) -> Result<(), NeuralNetworksError> {
+        let inputs = [
+            1.0, 2.0, 5.0, 6.0, //
+            3.0, 4.0, 7.0, 8.0, //
+            0.0, 0.0, 1.0, 1.0, //
+            0.0, 4.0, 1.0, 1.0,
+        ];
+
+        let max_pooling = Pooling2d::new([1, 4, 4], [2, 2], Poolings::Max)?;
+        let average_pooling = Pooling2d::new([1, 4, 4], [2, 2], Poolings::Average)?;
+
+        assert_eq!(max_pooling.get_output_shape(), vec![1, 2, 2]);
+        assert_eq!(max_pooling.forward(&inputs)?, vec![4.0, 8.0, 4.0, 1.0]);
+        assert_eq!(average_pooling.forward(&inputs)?, vec![2.5, 6.5, 1.0, 1.0]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+extern crate serde;
+extern crate serde_json;
+
+use self::serde::{Deserialize, Serialize};
+use network_layers::deserialize_network_layer::deserialize_network_layer;
+use network_layers::network_layer_trait::NetworkLayerTrait;
+use neural_networks_error::NeuralNetworksError;
+
+// How a layer of any kind gets serialized: its layer type next to the layer itself.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaggedNetworkLayer {
+    #[serde(rename = "type")]
+    layer_type: String,
+    layer: serde_json::Value,
+}
+
+impl TaggedNetworkLayer {
+    pub fn new(layer: &dyn NetworkLayerTrait) -> Result<Self, NeuralNetworksError> {
+        Ok(TaggedNetworkLayer {
+            layer_type: layer.get_layer_type().to_string(),
+            layer: layer.to_json()?,
+        })
+    }
+
+    pub fn get_layer_type(&self) -> &str {
+        &self.layer_type
+    }
+
+    pub fn into_layer(self) -> Result<Box<dyn NetworkLayerTrait>, NeuralNetworksError> {
+        deserialize_network_layer(&self.layer_type, self.layer)
+    }
+}
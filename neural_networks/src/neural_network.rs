@@ -5,30 +5,195 @@ use self::randomization::randomizer::RandomizerTrait;
 use self::serde::{Deserialize, Serialize};
 use layer::Layer;
 use layer::LayerTrait;
+use named_io::io_schema::IoSchema;
+use neural_networks_error::NeuralNetworksError;
+use neuron::MutableNeuronTrait;
 use neuron::NeuronTrait;
+use neuron_initialization::draw_initial_bias::draw_initial_bias;
+use neuron_initialization::draw_initial_weight::draw_initial_weight;
+use neuron_initialization::initializers::Initializers;
+use parameter_layout::ParameterLayout;
+use std::collections::HashMap;
 
+// What evolving, evaluating and saving a network takes, whatever its architecture is.
 pub trait NeuralNetworkTrait<T: NeuronTrait> {
+    fn propagate(&self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError>;
+    // The samples go one after the other in the inputs, and their outputs the same way in the
+    // outputs, which get cleared first.
+    fn propagate_batch(
+        &self,
+        inputs: &[f64],
+        outputs: &mut Vec<f64>,
+    ) -> Result<(), NeuralNetworksError>;
+    // Like propagate, but recurrent layers carry their state on to the next step.
+    fn propagate_step(&mut self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError>;
+    fn reset_state(&mut self);
+    fn parameters(&self) -> Vec<f64>;
+    fn set_parameters(&mut self, parameters: &[f64]) -> Result<(), NeuralNetworksError>;
+    fn get_number_of_parameters(&self) -> usize;
+
+    // Whether each position in the parameters of both networks stands for the same weight or bias.
+    fn has_same_parameter_layout(&self, other: &Self) -> bool
+    where
+        Self: Sized,
+    {
+        self.get_number_of_parameters() == other.get_number_of_parameters()
+    }
+}
+
+// Networks made of a stack of layers of neurons, which the layer by layer evolution operators and
+// backpropagation work with.
+pub trait LayeredNeuralNetworkTrait<T: NeuronTrait>: NeuralNetworkTrait<T> {
     fn new_with_specified_layers<U: RandomizerTrait, V: Fn(u32, &mut U) -> T>(
         layers_definition: &[[usize; 2]],
         randomizer: &mut U,
         neuron_creator: V,
-    ) -> Self;
+    ) -> Result<Self, NeuralNetworksError>
+    where
+        Self: Sized;
+    fn new_with_specified_layers_and_initializer<U: RandomizerTrait, V: Fn(u32, &mut U) -> T>(
+        layers_definition: &[[usize; 2]],
+        initializer: Initializers,
+        randomizer: &mut U,
+        neuron_creator: V,
+    ) -> Result<Self, NeuralNetworksError>
+    where
+        Self: Sized,
+    {
+        let mut neural_network =
+            Self::new_with_specified_layers(layers_definition, randomizer, neuron_creator)?;
+
+        neural_network.initialize_parameters(initializer, randomizer)?;
+
+        Ok(neural_network)
+    }
     fn get_number_of_layers(&self) -> u32;
     fn get_layer(&self, index: usize) -> &Layer<T>;
     fn get_layers(&self) -> &Vec<Layer<T>>;
     fn get_layers_mut(&mut self) -> &mut Vec<Layer<T>>;
-    fn add(&mut self, layer: Layer<T>) -> Result<(), String>;
-    fn propagate(&self, inputs: &[f64]) -> Result<Vec<f64>, String>;
+    fn add(&mut self, layer: Layer<T>) -> Result<(), NeuralNetworksError>;
+
+    // Redraws every weight and bias, taking the fan-in and fan-out of each layer into account.
+    fn initialize_parameters<U: RandomizerTrait>(
+        &mut self,
+        initializer: Initializers,
+        randomizer: &mut U,
+    ) -> Result<(), NeuralNetworksError> {
+        for layer in self.get_layers_mut().iter_mut() {
+            let fan_in = layer.get_number_of_weights_per_neuron() as usize;
+            let fan_out = layer.get_number_of_outputs() as usize;
+
+            for neuron in layer.get_neurons_mut().iter_mut() {
+                neuron.set_bias(draw_initial_bias(initializer, randomizer));
+
+                for index in 0..neuron.get_number_of_weights() as usize {
+                    neuron.set_weight(
+                        index,
+                        draw_initial_weight(initializer, fan_in, fan_out, randomizer),
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn get_parameter_layout(&self) -> ParameterLayout {
+        ParameterLayout::new(
+            self.get_layers()
+                .iter()
+                .map(|layer| {
+                    [
+                        layer.get_number_of_weights_per_neuron() as usize,
+                        layer.get_number_of_neurons() as usize,
+                    ]
+                })
+                .collect(),
+        )
+    }
+
+    // Copies what a child built out of crossed over layers should keep from its parent besides
+    // the layers themselves.
+    fn inherit_from(&mut self, _parent: &Self) -> Result<(), NeuralNetworksError>
+    where
+        Self: Sized,
+    {
+        Ok(())
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct NeuralNetwork<T: NeuronTrait> {
     layers: Vec<Layer<T>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    io_schema: Option<IoSchema>,
 }
 
 impl<T: NeuronTrait> NeuralNetwork<T> {
     pub fn new() -> Self {
-        Self { layers: Vec::new() }
+        Self {
+            layers: Vec::new(),
+            io_schema: None,
+        }
+    }
+
+    pub fn get_io_schema(&self) -> Option<&IoSchema> {
+        self.io_schema.as_ref()
+    }
+
+    pub fn set_io_schema(&mut self, io_schema: IoSchema) -> Result<(), NeuralNetworksError> {
+        io_schema.validate()?;
+        self.check_io_schema_fits_layers(&io_schema)?;
+
+        self.io_schema = Some(io_schema);
+
+        Ok(())
+    }
+
+    // Meant for networks loaded from a file. The layers have to fit the expected schema, and so
+    // does the stored schema, if there is one. Files saved before schemas existed only get the
+    // first check.
+    pub fn check_io_schema(&self, expected: &IoSchema) -> Result<(), NeuralNetworksError> {
+        self.check_io_schema_fits_layers(expected)?;
+
+        if let Some(io_schema) = &self.io_schema {
+            io_schema.validate()?;
+            io_schema.check_matches(expected)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn propagate_named(
+        &self,
+        named_inputs: &HashMap<&str, f64>,
+    ) -> Result<HashMap<String, f64>, NeuralNetworksError> {
+        let io_schema = self
+            .io_schema
+            .as_ref()
+            .ok_or(NeuralNetworksError::NoIoSchema)?;
+
+        io_schema.name_outputs(&self.propagate(&io_schema.order_inputs(named_inputs)?)?)
+    }
+
+    fn check_io_schema_fits_layers(&self, io_schema: &IoSchema) -> Result<(), NeuralNetworksError> {
+        if let (Some(first_layer), Some(last_layer)) = (self.layers.first(), self.layers.last()) {
+            let number_of_inputs = first_layer.get_number_of_inputs() as usize;
+            let number_of_outputs = last_layer.get_number_of_outputs() as usize;
+
+            if number_of_inputs != io_schema.get_number_of_inputs()
+                || number_of_outputs != io_schema.get_number_of_outputs()
+            {
+                return Err(NeuralNetworksError::IoSchemaDoesntFitLayers {
+                    schema_inputs: io_schema.get_number_of_inputs(),
+                    schema_outputs: io_schema.get_number_of_outputs(),
+                    network_inputs: number_of_inputs,
+                    network_outputs: number_of_outputs,
+                });
+            }
+        }
+
+        Ok(())
     }
 }
 
@@ -39,25 +204,129 @@ impl<T: NeuronTrait> Default for NeuralNetwork<T> {
 }
 
 impl<T: NeuronTrait> NeuralNetworkTrait<T> for NeuralNetwork<T> {
+    fn propagate(&self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError> {
+        if self.layers.is_empty() {
+            return Err(NeuralNetworksError::NoLayers);
+        }
+        if self.layers[0].get_number_of_inputs() != inputs.len() as u32 {
+            return Err(NeuralNetworksError::WrongNumberOfInputs {
+                expected: self.layers[0].get_number_of_inputs() as usize,
+                received: inputs.len(),
+            });
+        }
+
+        // Both buffers get reused from layer to layer.
+        let mut this_in = inputs.to_vec();
+        let mut this_out: Vec<f64> = Vec::new();
+
+        for layer in &self.layers {
+            layer.feed_forward_batch(&this_in, &mut this_out)?;
+
+            std::mem::swap(&mut this_in, &mut this_out);
+        }
+
+        Ok(this_in)
+    }
+    fn propagate_batch(
+        &self,
+        inputs: &[f64],
+        outputs: &mut Vec<f64>,
+    ) -> Result<(), NeuralNetworksError> {
+        if self.layers.is_empty() {
+            return Err(NeuralNetworksError::NoLayers);
+        }
+
+        outputs.clear();
+
+        if inputs.is_empty() {
+            return Ok(());
+        }
+
+        self.layers[0].feed_forward_batch(inputs, outputs)?;
+
+        // The outputs and this buffer take turns as the inputs of the next layer.
+        let mut this_out: Vec<f64> = Vec::with_capacity(outputs.len());
+
+        for layer in &self.layers[1..] {
+            layer.feed_forward_batch(outputs, &mut this_out)?;
+
+            std::mem::swap(outputs, &mut this_out);
+        }
+
+        Ok(())
+    }
+    fn propagate_step(&mut self, inputs: &[f64]) -> Result<Vec<f64>, NeuralNetworksError> {
+        if self.layers.is_empty() {
+            return Err(NeuralNetworksError::NoLayers);
+        }
+
+        let mut outputs = inputs.to_vec();
+
+        for layer in self.layers.iter_mut() {
+            outputs = layer.feed_forward_step(&outputs)?;
+        }
+
+        Ok(outputs)
+    }
+
+    fn reset_state(&mut self) {
+        for layer in self.layers.iter_mut() {
+            layer.reset_state();
+        }
+    }
+
+    // Layer by layer and neuron by neuron, the bias of each neuron followed by its weights.
+    fn parameters(&self) -> Vec<f64> {
+        self.layers
+            .iter()
+            .flat_map(|layer| layer.get_parameters())
+            .collect()
+    }
+
+    fn set_parameters(&mut self, parameters: &[f64]) -> Result<(), NeuralNetworksError> {
+        if parameters.len() != self.get_number_of_parameters() {
+            return Err(NeuralNetworksError::WrongNumberOfParameters {
+                expected: self.get_number_of_parameters(),
+                received: parameters.len(),
+            });
+        }
+
+        let mut remaining_parameters = parameters;
+
+        for layer in self.layers.iter_mut() {
+            let (layer_parameters, rest) =
+                remaining_parameters.split_at(layer.get_number_of_parameters());
+
+            layer.set_parameters(layer_parameters)?;
+            remaining_parameters = rest;
+        }
+
+        Ok(())
+    }
+
+    fn get_number_of_parameters(&self) -> usize {
+        self.get_parameter_layout().get_number_of_parameters()
+    }
+}
+
+impl<T: NeuronTrait> LayeredNeuralNetworkTrait<T> for NeuralNetwork<T> {
     fn new_with_specified_layers<U: RandomizerTrait, V: Fn(u32, &mut U) -> T>(
         layers_definition: &[[usize; 2]],
         randomizer: &mut U,
         neuron_creator: V,
-    ) -> Self {
+    ) -> Result<Self, NeuralNetworksError> {
         let mut neural_network = NeuralNetwork::new();
 
         for layer in layers_definition {
-            if let Err(error) = neural_network.add(Layer::<T>::create_layer(
+            neural_network.add(Layer::<T>::create_layer(
                 layer[0] as u32,
                 layer[1] as u32,
                 randomizer,
                 &neuron_creator,
-            )) {
-                panic!("Failed to add a layer to Neural Network: {:?}", error);
-            }
+            )?)?;
         }
 
-        neural_network
+        Ok(neural_network)
     }
 
     fn get_number_of_layers(&self) -> u32 {
@@ -68,47 +337,33 @@ impl<T: NeuronTrait> NeuralNetworkTrait<T> for NeuralNetwork<T> {
         &self.layers[index]
     }
 
-    fn add(&mut self, layer: Layer<T>) -> std::result::Result<(), std::string::String> {
+    fn add(&mut self, layer: Layer<T>) -> Result<(), NeuralNetworksError> {
         if self.layers.is_empty()
-            || self.layers.last().unwrap().get_number_of_neurons() == layer.get_number_of_inputs()
+            || self.layers.last().unwrap().get_number_of_outputs() == layer.get_number_of_inputs()
         {
             self.layers.push(layer);
             Ok(())
         } else {
-            Err("Attempted to add a layer to the neural network, but the number of neurons in the last stored layer didn't match the number of inputs in the new one!".to_string())
+            Err(NeuralNetworksError::LayersDontConnect {
+                outputs_of_last_layer: self.layers.last().unwrap().get_number_of_outputs() as usize,
+                inputs_of_new_layer: layer.get_number_of_inputs() as usize,
+            })
         }
     }
-    fn propagate(
-        &self,
-        inputs: &[f64],
-    ) -> std::result::Result<std::vec::Vec<f64>, std::string::String> {
-        if self.layers.is_empty() {
-            panic!("Attempted to propagate inputs through a neural network when there were no layers set up!");
-        }
-        if self.layers[0].get_number_of_inputs() != inputs.len() as u32 {
-            panic!("The number of inputs doesn't match the set inputs in the first layer of the neural network!");
-        }
-
-        let mut this_in = inputs;
-
-        let mut this_out: Vec<f64> = Vec::new();
-
-        for layer in &self.layers {
-            let temporary = layer.feed_forward(&this_in);
-
-            this_out = temporary;
-
-            this_in = &this_out;
-        }
-
-        Ok(this_out)
-    }
     fn get_layers(&self) -> &Vec<Layer<T>> {
         &self.layers
     }
     fn get_layers_mut(&mut self) -> &mut Vec<Layer<T>> {
         &mut self.layers
     }
+
+    fn inherit_from(&mut self, parent: &Self) -> Result<(), NeuralNetworksError> {
+        if let Some(io_schema) = &parent.io_schema {
+            self.set_io_schema(io_schema.clone())?;
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -121,13 +376,15 @@ mod tests {
     use neural_network::tests::file_system::save_json::save_json;
     use neuron::Neuron;
     use neuron_activation::activation_functions::ActivationFunctions;
+    use recurrence::recurrences::Recurrences;
+    use std::error::Error;
 
     use neural_network::randomization::randomizer::Randomizer;
 
     extern crate file_system;
 
     #[test]
-    fn test_when_creating_an_empty_nn_it_has_no_layers() -> Result<(), String> {
+    fn test_when_creating_an_empty_nn_it_has_no_layers() -> Result<(), NeuralNetworksError> {
         let nn = NeuralNetwork::<Neuron>::new();
 
         assert_eq!(nn.get_number_of_layers(), 0);
@@ -135,9 +392,80 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_when_creating_a_neural_network_with_an_initializer_the_weights_follow_each_layers_fan_in(
+    ) -> Result<(), NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+
+        let nn = NeuralNetwork::<Neuron>::new_with_specified_layers_and_initializer(
+            &[[16, 4], [4, 1]],
+            Initializers::HeUniform,
+            &mut randomizer,
+            |number_of_inputs, randomizer| {
+                Neuron::new(number_of_inputs, ActivationFunctions::Relu, randomizer)
+            },
+        )?;
+
+        assert!(nn
+            .get_layer(0)
+            .get_weights()
+            .iter()
+            .all(|weight| weight.abs() <= (6.0_f64 / 16.0).sqrt()));
+        assert!(nn
+            .get_layer(1)
+            .get_weights()
+            .iter()
+            .all(|weight| weight.abs() <= (6.0_f64 / 4.0).sqrt()));
+        assert!(nn.get_layer(0).get_biases().iter().all(|bias| *bias == 0.0));
+
+        let nn = NeuralNetwork::<Neuron>::new_with_specified_layers_and_initializer(
+            &[[3, 2]],
+            Initializers::Constant { value: 0.5 },
+            &mut randomizer,
+            |number_of_inputs, randomizer| {
+                Neuron::new(number_of_inputs, ActivationFunctions::Relu, randomizer)
+            },
+        )?;
+
+        assert!(nn.parameters().iter().all(|parameter| *parameter == 0.5));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_propagating_steps_through_a_recurrent_layer_remembers_the_previous_outputs(
+    ) -> Result<(), NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+
+        let mut nn = NeuralNetwork::<Neuron>::new();
+        nn.add(Layer::create_recurrent_layer(
+            1,
+            1,
+            Recurrences::Elman,
+            &mut randomizer,
+            |number_of_inputs, randomizer| {
+                Neuron::new(number_of_inputs, ActivationFunctions::Identity, randomizer)
+            },
+        )?)?;
+
+        // Bias, the weight of the input and the weight of the previous output.
+        nn.set_parameters(&[0.0, 1.0, 0.5])?;
+
+        assert_eq!(nn.propagate_step(&[1.0])?, vec![1.0]);
+        assert_eq!(nn.propagate_step(&[1.0])?, vec![1.5]);
+        assert_eq!(nn.propagate_step(&[1.0])?, vec![1.75]);
+        assert_eq!(nn.propagate(&[1.0])?, vec![1.0]);
+
+        nn.reset_state();
+
+        assert_eq!(nn.propagate_step(&[1.0])?, vec![1.0]);
+
+        Ok(())
+    }
+
     #[test]
     fn test_when_creating_a_neural_network_with_defined_layers_the_created_neural_networks_layers_have_expected_properties(
-    ) -> Result<(), String> {
+    ) -> Result<(), NeuralNetworksError> {
         let mut randomizer = Randomizer::new();
 
         let nn = NeuralNetwork::<Neuron>::new_with_specified_layers(
@@ -146,7 +474,7 @@ mod tests {
             |number_of_inputs, randomizer| {
                 Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
             },
-        );
+        )?;
         assert_eq!(nn.get_number_of_layers(), 3);
         assert_eq!(nn.get_layer(0).get_number_of_inputs(), 4);
         assert_eq!(nn.get_layer(0).get_number_of_neurons(), 3);
@@ -167,15 +495,18 @@ mod tests {
         let layer1 =
             Layer::<Neuron>::create_layer(3, 2, &mut randomizer, |number_of_inputs, randomizer| {
                 Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
-            });
+            })
+            .unwrap();
         let layer2 =
             Layer::<Neuron>::create_layer(2, 1, &mut randomizer, |number_of_inputs, randomizer| {
                 Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
-            });
+            })
+            .unwrap();
         let layer3 =
             Layer::<Neuron>::create_layer(3, 1, &mut randomizer, |number_of_inputs, randomizer| {
                 Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
-            });
+            })
+            .unwrap();
 
         if let Err(error) = neural_network.add(layer1) {
             panic!("Adding the first layer failed: {:?}", error);
@@ -189,9 +520,29 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
+    fn test_creating_a_neural_network_with_layers_that_dont_connect_fails() {
+        let mut randomizer = Randomizer::new();
+
+        let result = NeuralNetwork::<Neuron>::new_with_specified_layers(
+            &[[3, 2], [4, 1]],
+            &mut randomizer,
+            |number_of_inputs, randomizer| {
+                Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+            },
+        );
+
+        assert_eq!(
+            result.err(),
+            Some(NeuralNetworksError::LayersDontConnect {
+                outputs_of_last_layer: 2,
+                inputs_of_new_layer: 4
+            })
+        );
+    }
+
+    #[test]
     fn test_attempting_to_propagate_inputs_through_neural_network_fails_if_passed_wrong_number_of_inputs(
-    ) {
+    ) -> Result<(), NeuralNetworksError> {
         let mut randomizer = Randomizer::new();
 
         let neural_network = NeuralNetwork::<Neuron>::new_with_specified_layers(
@@ -200,20 +551,26 @@ mod tests {
             |number_of_inputs, randomizer| {
                 Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
             },
+        )?;
+
+        assert_eq!(
+            neural_network.propagate(&[0.0_f64, 1.0_f64]),
+            Err(NeuralNetworksError::WrongNumberOfInputs {
+                expected: 3,
+                received: 2
+            })
+        );
+        assert_eq!(
+            NeuralNetwork::<Neuron>::new().propagate(&[0.0_f64]),
+            Err(NeuralNetworksError::NoLayers)
         );
 
-        let inputs = vec![0.0_f64, 1.0_f64];
-        if let Err(error) = neural_network.propagate(&inputs) {
-            panic!(format!(
-                "Failed when propagating inputs through network: {:?}",
-                error
-            ));
-        }
+        Ok(())
     }
 
     #[test]
     fn test_propagating_inputs_through_neural_network_the_produced_output_should_be_in_expected_range(
-    ) -> Result<(), String> {
+    ) -> Result<(), NeuralNetworksError> {
         let mut randomizer = Randomizer::new();
 
         let neural_network = NeuralNetwork::<Neuron>::new_with_specified_layers(
@@ -222,7 +579,7 @@ mod tests {
             |number_of_inputs, randomizer| {
                 Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
             },
-        );
+        )?;
 
         let inputs = vec![0.0_f64, 1.0_f64, 0.0_f64];
         let outputs = neural_network.propagate(&inputs)?;
@@ -236,7 +593,7 @@ mod tests {
     }
 
     #[test]
-    fn test_can_lock_down_behavior_of_neural_network() -> Result<(), String> {
+    fn test_can_lock_down_behavior_of_neural_network() -> Result<(), NeuralNetworksError> {
         struct FakeRandomizer {}
 
         impl RandomizerTrait for FakeRandomizer {
@@ -263,7 +620,7 @@ mod tests {
             |number_of_inputs, randomizer| {
                 Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
             },
-        );
+        )?;
 
         let inputs = vec![0.0_f64, 1.0_f64, 0.0_f64];
         let outputs = neural_network.propagate(&inputs)?;
@@ -279,7 +636,7 @@ mod tests {
     }
 
     #[test]
-    fn test_can_serde_a_neural_network() -> Result<(), String> {
+    fn test_can_serde_a_neural_network() -> Result<(), NeuralNetworksError> {
         let mut randomizer = Randomizer::new();
 
         let neural_network = NeuralNetwork::<Neuron>::new_with_specified_layers(
@@ -288,7 +645,7 @@ mod tests {
             |number_of_inputs, randomizer| {
                 Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
             },
-        );
+        )?;
 
         let serialized = serde_json::to_string(&neural_network).unwrap();
 
@@ -315,7 +672,7 @@ mod tests {
     }
 
     #[test]
-    fn test_can_save_and_load_a_serialized_neural_network() -> Result<(), String> {
+    fn test_can_save_and_load_a_serialized_neural_network() -> Result<(), Box<dyn Error>> {
         let mut randomizer = Randomizer::new();
 
         let neural_network = NeuralNetwork::<Neuron>::new_with_specified_layers(
@@ -324,7 +681,7 @@ mod tests {
             |number_of_inputs, randomizer| {
                 Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
             },
-        );
+        )?;
 
         use self::file_system::does_file_exist::does_file_exist;
         use self::file_system::remove_file::remove_file;
@@ -372,4 +729,157 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_propagating_a_batch_produces_the_same_outputs_as_one_by_one(
+    ) -> Result<(), NeuralNetworksError> {
+        let mut randomizer = Randomizer::new();
+
+        let neural_network = NeuralNetwork::<Neuron>::new_with_specified_layers(
+            &[[3, 4], [4, 2]],
+            &mut randomizer,
+            |number_of_inputs, randomizer| {
+                Neuron::new(number_of_inputs, ActivationFunctions::Tanh, randomizer)
+            },
+        )?;
+
+        let inputs = [
+            0.0_f64, 1.0_f64, 0.0_f64, 0.3_f64, -1.0_f64, 0.7_f64, 1.0_f64, 1.0_f64, 1.0_f64,
+        ];
+
+        let mut outputs = Vec::new();
+        neural_network.propagate_batch(&inputs, &mut outputs)?;
+
+        assert_eq!(outputs.len(), 3 * 2);
+
+        for (sample, sample_outputs) in inputs.chunks(3).zip(outputs.chunks(2)) {
+            assert_eq!(&neural_network.propagate(sample)?[..], sample_outputs);
+        }
+
+        neural_network.propagate_batch(&[], &mut outputs)?;
+        assert!(outputs.is_empty());
+
+        assert!(neural_network
+            .propagate_batch(&[0.0_f64, 1.0_f64], &mut outputs)
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_setting_the_parameters_round_trips_in_a_stable_order() -> Result<(), NeuralNetworksError>
+    {
+        let mut randomizer = Randomizer::new();
+
+        let mut neural_network = NeuralNetwork::<Neuron>::new_with_specified_layers(
+            &[[2, 3], [3, 1]],
+            &mut randomizer,
+            |number_of_inputs, randomizer| {
+                Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+            },
+        )?;
+
+        let parameters: Vec<f64> = (0..13).map(|value| value as f64).collect();
+
+        neural_network.set_parameters(&parameters)?;
+
+        assert_eq!(neural_network.get_number_of_parameters(), 13);
+        assert_eq!(neural_network.parameters(), parameters);
+        assert_eq!(neural_network.get_layer(0).get_biases(), &[0.0, 3.0, 6.0]);
+        assert_eq!(
+            neural_network.get_layer(1).get_weights(),
+            &[10.0, 11.0, 12.0]
+        );
+
+        let layout = neural_network.get_parameter_layout();
+        assert_eq!(
+            parameters[layout.get_weight_index(0, 1, 1)?],
+            neural_network.get_layer(0).get_neuron(1)?.get_weight(1)?
+        );
+
+        assert!(neural_network.set_parameters(&parameters[1..]).is_err());
+        assert!(neural_network
+            .set_parameters(&[parameters.clone(), vec![0.0]].concat())
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_a_neural_network_with_an_io_schema_propagates_by_name_and_checks_its_wiring(
+    ) -> Result<(), NeuralNetworksError> {
+        use named_io::named_value::NamedValue;
+
+        let mut randomizer = Randomizer::new();
+
+        let mut neural_network = NeuralNetwork::<Neuron>::new_with_specified_layers(
+            &[[2, 3], [3, 2]],
+            &mut randomizer,
+            |number_of_inputs, randomizer| {
+                Neuron::new(number_of_inputs, ActivationFunctions::Sigmoid, randomizer)
+            },
+        )?;
+
+        let io_schema = IoSchema::new(
+            vec![
+                NamedValue::new("Hunger", 0.0, 1.0),
+                NamedValue::new("FoodNearby", 0.0, 1.0),
+            ],
+            vec![
+                NamedValue::new("Eat", 0.0, 1.0),
+                NamedValue::new("Wander", 0.0, 1.0),
+            ],
+        )?;
+
+        assert_eq!(
+            neural_network.propagate_named(&HashMap::new()),
+            Err(NeuralNetworksError::NoIoSchema)
+        );
+
+        neural_network.set_io_schema(io_schema.clone())?;
+
+        let named_inputs: HashMap<&str, f64> = [("FoodNearby", 1.0), ("Hunger", 0.2)]
+            .iter()
+            .cloned()
+            .collect();
+        let named_outputs = neural_network.propagate_named(&named_inputs)?;
+        let outputs = neural_network.propagate(&[0.2, 1.0])?;
+
+        assert_eq!(named_outputs["Eat"], outputs[0]);
+        assert_eq!(named_outputs["Wander"], outputs[1]);
+
+        // The schema gets stored along with the layers.
+        let deserialized: NeuralNetwork<Neuron> =
+            serde_json::from_str(&serde_json::to_string(&neural_network).unwrap()).unwrap();
+
+        assert_eq!(deserialized.get_io_schema(), Some(&io_schema));
+        assert!(deserialized.check_io_schema(&io_schema).is_ok());
+
+        let rewired = IoSchema::new(
+            vec![
+                NamedValue::new("FoodNearby", 0.0, 1.0),
+                NamedValue::new("Hunger", 0.0, 1.0),
+            ],
+            io_schema.get_outputs().to_vec(),
+        )?;
+
+        assert!(deserialized.check_io_schema(&rewired).is_err());
+
+        let too_small = IoSchema::new(
+            vec![NamedValue::new("Hunger", 0.0, 1.0)],
+            io_schema.get_outputs().to_vec(),
+        )?;
+
+        assert!(neural_network.set_io_schema(too_small.clone()).is_err());
+
+        let mut without_schema = NeuralNetwork::<Neuron>::new();
+        for layer in neural_network.get_layers() {
+            without_schema.add(layer.clone())?;
+        }
+
+        assert!(without_schema.check_io_schema(&io_schema).is_ok());
+        assert!(without_schema.check_io_schema(&too_small).is_err());
+
+        Ok(())
+    }
 }
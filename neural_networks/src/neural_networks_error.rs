@@ -0,0 +1,607 @@
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NeuralNetworksError {
+    NoLayers,
+    WrongNumberOfInputs {
+        expected: usize,
+        received: usize,
+    },
+    LayersDontConnect {
+        outputs_of_last_layer: usize,
+        inputs_of_new_layer: usize,
+    },
+    NeuronOutOfRange {
+        index: usize,
+        number_of_neurons: usize,
+    },
+    WeightOutOfRange {
+        index: usize,
+        number_of_weights: usize,
+    },
+    WrongNumberOfParameters {
+        expected: usize,
+        received: usize,
+    },
+    WrongNumberOfWeights {
+        neuron: usize,
+        expected: usize,
+        received: usize,
+    },
+    NoOutputsToSelectFrom,
+    WrongStateSize {
+        expected: usize,
+        received: usize,
+    },
+    ActivationFunctionAlreadyRegistered(String),
+    UnknownActivationFunction(String),
+    LayerTypeAlreadyRegistered(String),
+    UnknownLayerType(String),
+    NodeOutOfRange {
+        node: usize,
+        number_of_nodes: usize,
+    },
+    ConnectionOutOfRange {
+        index: usize,
+        number_of_connections: usize,
+    },
+    AlreadyConnected {
+        source: usize,
+        target: usize,
+    },
+    WouldCloseCycle {
+        source: usize,
+        target: usize,
+    },
+    UnconnectedNode(usize),
+    UnreachableNodes,
+    SumOfDifferentSizes(Vec<usize>),
+    HiddenLayersInNeatDefinition {
+        number_of_layers: usize,
+    },
+    RepeatedNode(u32),
+    UnknownNode(u32),
+    ConnectionIntoInputNode(u32),
+    CycleInConnections,
+    MissingInputOrOutputNode(u32),
+    NonPositiveTemperature(f64),
+    DropoutRateOutOfRange(f64),
+    PoolDoesntFit {
+        pool_size: [usize; 2],
+        input_shape: [usize; 3],
+    },
+    KernelDoesntFit {
+        kernel_size: [usize; 2],
+        input_shape: [usize; 3],
+    },
+    EmptyConvolution,
+    ZeroStride,
+    LayerSerialization {
+        layer_type: String,
+        message: String,
+    },
+    LayerDeserialization {
+        layer_type: String,
+        message: String,
+    },
+    RepeatedName(String),
+    EmptyRange {
+        name: String,
+        minimum: f64,
+        maximum: f64,
+    },
+    IoSchemaMismatch {
+        inputs: Vec<String>,
+        outputs: Vec<String>,
+        expected_inputs: Vec<String>,
+        expected_outputs: Vec<String>,
+    },
+    IoSchemaDoesntFitLayers {
+        schema_inputs: usize,
+        schema_outputs: usize,
+        network_inputs: usize,
+        network_outputs: usize,
+    },
+    WrongNumberOfOutputs {
+        expected: usize,
+        received: usize,
+    },
+    NoIoSchema,
+    MissingNamedInput(String),
+    UnknownNamedInput(String),
+    NamedInputOutOfRange {
+        name: String,
+        value: f64,
+    },
+    EmptyPopulation,
+    TooManyElites {
+        number_of_elites: usize,
+        population_size: usize,
+    },
+    NoParameters,
+    DifferentParameterLayouts,
+    LayerOutOfRange {
+        index: usize,
+        number_of_layers: usize,
+    },
+    MutationRateOutOfRange(f64),
+    CrossoverRateOutOfRange(f64),
+    ObjectiveWeightOutOfRange(f64),
+    NothingToSelectFrom,
+    NotEnoughGenomesToMigrate {
+        number_of_migrants: usize,
+    },
+    ObjectivesDontMatchGenomes {
+        number_of_objectives: usize,
+        number_of_genomes: usize,
+    },
+    MissingObjectiveValues {
+        number_of_genomes: usize,
+        objective_names: Vec<String>,
+    },
+    GenomeOutOfRange {
+        index: usize,
+        number_of_genomes: usize,
+    },
+    UnknownObjective {
+        name: String,
+        objective_names: Vec<String>,
+    },
+    BehavioursOfDifferentLengths {
+        behaviour: usize,
+        neighbour: usize,
+    },
+    NonPositiveStepSize(f64),
+    NonPositiveNoiseOrLearningRate {
+        noise_standard_deviation: f64,
+        learning_rate: f64,
+    },
+    NegativeWeightDecay(f64),
+    EvolutionStrategyNotInitialized,
+    TooManyParametersForCovarianceMatrixAdaptation {
+        number_of_parameters: usize,
+        maximum_number_of_parameters: usize,
+    },
+    OddNumberOfCandidates(usize),
+    NotEnoughFitnesses {
+        number_of_candidates: usize,
+        number_of_fitnesses: usize,
+    },
+    WrongCandidateSize {
+        expected: usize,
+        received: usize,
+    },
+    NoSamples,
+    RecurrentLayersInBackpropagation,
+    WrongNumberOfTargets {
+        outputs: usize,
+        targets: usize,
+    },
+    GradientsOfDifferentSizes {
+        expected: usize,
+        received: usize,
+    },
+    WrongNumberOfDeltas {
+        expected: usize,
+        received: usize,
+    },
+}
+
+impl fmt::Display for NeuralNetworksError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NeuralNetworksError::NoLayers => write!(
+                f,
+                "Attempted to propagate inputs through a neural network when there were no layers set up."
+            ),
+            NeuralNetworksError::WrongNumberOfInputs { expected, received } => write!(
+                f,
+                "Got {:?} inputs, but {:?} were expected.",
+                received, expected
+            ),
+            NeuralNetworksError::LayersDontConnect {
+                outputs_of_last_layer,
+                inputs_of_new_layer,
+            } => write!(
+                f,
+                "Attempted to add a layer with {:?} inputs after a layer with {:?} neurons.",
+                inputs_of_new_layer, outputs_of_last_layer
+            ),
+            NeuralNetworksError::NeuronOutOfRange {
+                index,
+                number_of_neurons,
+            } => write!(
+                f,
+                "Asked for neuron {:?} of a layer that only has {:?}.",
+                index, number_of_neurons
+            ),
+            NeuralNetworksError::WeightOutOfRange {
+                index,
+                number_of_weights,
+            } => write!(
+                f,
+                "Asked for weight {:?} of a neuron that only has {:?}.",
+                index, number_of_weights
+            ),
+            NeuralNetworksError::WrongNumberOfParameters { expected, received } => write!(
+                f,
+                "Got {:?} parameters, but the neural network has {:?}.",
+                received, expected
+            ),
+            NeuralNetworksError::WrongNumberOfWeights {
+                neuron,
+                expected,
+                received,
+            } => write!(
+                f,
+                "Neuron {:?} has {:?} weights, but its layer expects {:?}.",
+                neuron, received, expected
+            ),
+            NeuralNetworksError::NoOutputsToSelectFrom => write!(
+                f,
+                "Attempted to select an action from outputs that held no numbers."
+            ),
+            NeuralNetworksError::WrongStateSize { expected, received } => write!(
+                f,
+                "Got a state of {:?} values, but the layer keeps {:?}.",
+                received, expected
+            ),
+            NeuralNetworksError::ActivationFunctionAlreadyRegistered(name) => write!(
+                f,
+                "An activation function named {:?} was already registered.",
+                name
+            ),
+            NeuralNetworksError::UnknownActivationFunction(name) => write!(
+                f,
+                "No activation function named {:?} was registered.",
+                name
+            ),
+            NeuralNetworksError::LayerTypeAlreadyRegistered(layer_type) => write!(
+                f,
+                "A layer type named {:?} was already registered.",
+                layer_type
+            ),
+            NeuralNetworksError::UnknownLayerType(layer_type) => write!(
+                f,
+                "No layer type named {:?} was registered.",
+                layer_type
+            ),
+            NeuralNetworksError::NodeOutOfRange {
+                node,
+                number_of_nodes,
+            } => write!(
+                f,
+                "Referred to node {:?} of a network that has {:?} nodes.",
+                node, number_of_nodes
+            ),
+            NeuralNetworksError::ConnectionOutOfRange {
+                index,
+                number_of_connections,
+            } => write!(
+                f,
+                "Asked for connection {:?} of a network that only has {:?}.",
+                index, number_of_connections
+            ),
+            NeuralNetworksError::AlreadyConnected { source, target } => write!(
+                f,
+                "Node {:?} is already connected to node {:?}.",
+                source, target
+            ),
+            NeuralNetworksError::WouldCloseCycle { source, target } => write!(
+                f,
+                "Connecting node {:?} to node {:?} would close a cycle.",
+                source, target
+            ),
+            NeuralNetworksError::UnconnectedNode(node) => write!(
+                f,
+                "Node {:?} isn't connected from any other node.",
+                node
+            ),
+            NeuralNetworksError::UnreachableNodes => write!(
+                f,
+                "Some nodes can't be reached from the inputs, or are part of a cycle."
+            ),
+            NeuralNetworksError::SumOfDifferentSizes(sizes) => write!(
+                f,
+                "Attempted to sum outputs of different sizes: {:?}.",
+                sizes
+            ),
+            NeuralNetworksError::HiddenLayersInNeatDefinition { number_of_layers } => write!(
+                f,
+                "A NEAT network starts without hidden nodes, but got a definition of {:?} layers.",
+                number_of_layers
+            ),
+            NeuralNetworksError::RepeatedNode(identifier) => write!(
+                f,
+                "The NEAT network already has a node {:?}.",
+                identifier
+            ),
+            NeuralNetworksError::UnknownNode(identifier) => write!(
+                f,
+                "A connection of the NEAT network goes through node {:?}, which doesn't exist.",
+                identifier
+            ),
+            NeuralNetworksError::ConnectionIntoInputNode(identifier) => write!(
+                f,
+                "A connection of the NEAT network goes into the input node {:?}.",
+                identifier
+            ),
+            NeuralNetworksError::CycleInConnections => write!(
+                f,
+                "The connections of a NEAT network formed a cycle, so it couldn't be sorted topologically."
+            ),
+            NeuralNetworksError::MissingInputOrOutputNode(identifier) => write!(
+                f,
+                "The NEAT network is missing its input or output node {:?}.",
+                identifier
+            ),
+            NeuralNetworksError::NonPositiveTemperature(temperature) => write!(
+                f,
+                "Attempted to sample an action with a temperature of {:?}, but it has to be positive.",
+                temperature
+            ),
+            NeuralNetworksError::DropoutRateOutOfRange(rate) => write!(
+                f,
+                "Attempted to create a dropout layer with a rate of {:?}, but it has to be at least 0 and below 1.",
+                rate
+            ),
+            NeuralNetworksError::PoolDoesntFit {
+                pool_size,
+                input_shape,
+            } => write!(
+                f,
+                "Attempted to pool {:?} windows over a {:?} input.",
+                pool_size, input_shape
+            ),
+            NeuralNetworksError::KernelDoesntFit {
+                kernel_size,
+                input_shape,
+            } => write!(
+                f,
+                "Attempted to create a convolutional layer with a {:?} kernel over a {:?} input.",
+                kernel_size, input_shape
+            ),
+            NeuralNetworksError::EmptyConvolution => write!(
+                f,
+                "Attempted to create a convolutional layer without channels, filters or a kernel."
+            ),
+            NeuralNetworksError::ZeroStride => write!(
+                f,
+                "Attempted to set a stride of 0 on a convolutional layer."
+            ),
+            NeuralNetworksError::LayerSerialization {
+                layer_type,
+                message,
+            } => write!(
+                f,
+                "Failed to serialize a layer of type {:?}. Error: {}",
+                layer_type, message
+            ),
+            NeuralNetworksError::LayerDeserialization {
+                layer_type,
+                message,
+            } => write!(
+                f,
+                "Failed to deserialize a layer of type {:?}. Error: {}",
+                layer_type, message
+            ),
+            NeuralNetworksError::RepeatedName(name) => {
+                write!(f, "The name {:?} is used more than once.", name)
+            }
+            NeuralNetworksError::EmptyRange {
+                name,
+                minimum,
+                maximum,
+            } => write!(
+                f,
+                "The range of {:?} goes from {:?} to {:?}.",
+                name, minimum, maximum
+            ),
+            NeuralNetworksError::IoSchemaMismatch {
+                inputs,
+                outputs,
+                expected_inputs,
+                expected_outputs,
+            } => write!(
+                f,
+                "Got inputs {:?} and outputs {:?}, but inputs {:?} and outputs {:?} were expected (ranges included).",
+                inputs, outputs, expected_inputs, expected_outputs
+            ),
+            NeuralNetworksError::IoSchemaDoesntFitLayers {
+                schema_inputs,
+                schema_outputs,
+                network_inputs,
+                network_outputs,
+            } => write!(
+                f,
+                "The schema names {:?} inputs and {:?} outputs, but the neural network has {:?} inputs and {:?} outputs.",
+                schema_inputs, schema_outputs, network_inputs, network_outputs
+            ),
+            NeuralNetworksError::WrongNumberOfOutputs { expected, received } => write!(
+                f,
+                "Got {:?} outputs, but the schema names {:?}.",
+                received, expected
+            ),
+            NeuralNetworksError::NoIoSchema => write!(
+                f,
+                "Attempted to use names for the inputs of a neural network that has no schema."
+            ),
+            NeuralNetworksError::MissingNamedInput(name) => {
+                write!(f, "No value was given for the input {:?}.", name)
+            }
+            NeuralNetworksError::UnknownNamedInput(name) => {
+                write!(f, "The neural network has no input named {:?}.", name)
+            }
+            NeuralNetworksError::NamedInputOutOfRange { name, value } => write!(
+                f,
+                "Got {:?} for the input {:?}, which is outside of its range.",
+                value, name
+            ),
+            NeuralNetworksError::EmptyPopulation => write!(
+                f,
+                "Attempted to create the next generation out of an empty population."
+            ),
+            NeuralNetworksError::TooManyElites {
+                number_of_elites,
+                population_size,
+            } => write!(
+                f,
+                "Asked for {:?} elites, but the population only has {:?} genomes.",
+                number_of_elites, population_size
+            ),
+            NeuralNetworksError::NoParameters => write!(
+                f,
+                "Attempted to evolve neural networks without parameters."
+            ),
+            NeuralNetworksError::DifferentParameterLayouts => write!(
+                f,
+                "The parameters of every genome have to be laid out the same way to evolve them."
+            ),
+            NeuralNetworksError::LayerOutOfRange {
+                index,
+                number_of_layers,
+            } => write!(
+                f,
+                "Asked for layer {:?}, but there are only {:?} layers.",
+                index, number_of_layers
+            ),
+            NeuralNetworksError::MutationRateOutOfRange(mutation_rate) => write!(
+                f,
+                "The mutation rate should be between 0 and 1, but got {:?}.",
+                mutation_rate
+            ),
+            NeuralNetworksError::CrossoverRateOutOfRange(crossover_rate) => write!(
+                f,
+                "The crossover rate should be between 0 and 1, but got {:?}.",
+                crossover_rate
+            ),
+            NeuralNetworksError::ObjectiveWeightOutOfRange(objective_weight) => write!(
+                f,
+                "The objective weight should be between 0 and 1, but got {:?}.",
+                objective_weight
+            ),
+            NeuralNetworksError::NothingToSelectFrom => {
+                write!(f, "Attempted to select a genome amongst no genomes.")
+            }
+            NeuralNetworksError::NotEnoughGenomesToMigrate { number_of_migrants } => write!(
+                f,
+                "Every island needs more than {:?} genomes to exchange that many migrants.",
+                number_of_migrants
+            ),
+            NeuralNetworksError::ObjectivesDontMatchGenomes {
+                number_of_objectives,
+                number_of_genomes,
+            } => write!(
+                f,
+                "Got the objectives of {:?} genomes, but there are {:?} genomes.",
+                number_of_objectives, number_of_genomes
+            ),
+            NeuralNetworksError::MissingObjectiveValues {
+                number_of_genomes,
+                objective_names,
+            } => write!(
+                f,
+                "Every one of the {:?} genomes in a Pareto front needs a value for each of the objectives {:?}.",
+                number_of_genomes, objective_names
+            ),
+            NeuralNetworksError::GenomeOutOfRange {
+                index,
+                number_of_genomes,
+            } => write!(
+                f,
+                "Asked for genome {:?}, but there are only {:?}.",
+                index, number_of_genomes
+            ),
+            NeuralNetworksError::UnknownObjective {
+                name,
+                objective_names,
+            } => write!(
+                f,
+                "There is no objective named {:?}. The objectives are {:?}.",
+                name, objective_names
+            ),
+            NeuralNetworksError::BehavioursOfDifferentLengths {
+                behaviour,
+                neighbour,
+            } => write!(
+                f,
+                "Can't compare behaviours of different lengths ({:?} and {:?}).",
+                behaviour, neighbour
+            ),
+            NeuralNetworksError::NonPositiveStepSize(step_size) => write!(
+                f,
+                "The step size of CMA-ES must be positive, but got {:?}.",
+                step_size
+            ),
+            NeuralNetworksError::NonPositiveNoiseOrLearningRate {
+                noise_standard_deviation,
+                learning_rate,
+            } => write!(
+                f,
+                "The noise standard deviation and learning rate must be positive, but got {:?} and {:?}.",
+                noise_standard_deviation, learning_rate
+            ),
+            NeuralNetworksError::NegativeWeightDecay(weight_decay) => write!(
+                f,
+                "The weight decay can't be negative, but got {:?}.",
+                weight_decay
+            ),
+            NeuralNetworksError::EvolutionStrategyNotInitialized => write!(
+                f,
+                "Used the evolution strategy before initializing it."
+            ),
+            NeuralNetworksError::TooManyParametersForCovarianceMatrixAdaptation {
+                number_of_parameters,
+                maximum_number_of_parameters,
+            } => write!(
+                f,
+                "CMA-ES would need two {:?}x{:?} matrices, past its maximum of {:?} parameters. Raise the maximum, or use the natural evolution strategy instead.",
+                number_of_parameters, number_of_parameters, maximum_number_of_parameters
+            ),
+            NeuralNetworksError::OddNumberOfCandidates(number_of_candidates) => write!(
+                f,
+                "Candidates get sampled in mirrored pairs, so their number must be even, but got {:?}.",
+                number_of_candidates
+            ),
+            NeuralNetworksError::NotEnoughFitnesses {
+                number_of_candidates,
+                number_of_fitnesses,
+            } => write!(
+                f,
+                "Got {:?} candidates and {:?} fitnesses, but every candidate needs its fitness.",
+                number_of_candidates, number_of_fitnesses
+            ),
+            NeuralNetworksError::WrongCandidateSize { expected, received } => write!(
+                f,
+                "The evolution strategy searches over {:?} parameters, but a candidate had {:?}.",
+                expected, received
+            ),
+            NeuralNetworksError::NoSamples => write!(
+                f,
+                "Attempted to train a neural network through backpropagation without any samples."
+            ),
+            NeuralNetworksError::RecurrentLayersInBackpropagation => write!(
+                f,
+                "Attempted to compute gradients for a neural network with recurrent layers, which backpropagation doesn't support."
+            ),
+            NeuralNetworksError::WrongNumberOfTargets { outputs, targets } => write!(
+                f,
+                "Attempted to compare {:?} outputs against {:?} targets.",
+                outputs, targets
+            ),
+            NeuralNetworksError::GradientsOfDifferentSizes { expected, received } => write!(
+                f,
+                "Attempted to accumulate gradients for {:?} neurons into gradients for {:?} neurons.",
+                received, expected
+            ),
+            NeuralNetworksError::WrongNumberOfDeltas { expected, received } => write!(
+                f,
+                "Attempted to apply deltas for {:?} layers to a neural network with {:?} layers.",
+                received, expected
+            ),
+        }
+    }
+}
+
+impl Error for NeuralNetworksError {}
@@ -2,7 +2,7 @@ extern crate randomization;
 extern crate serde;
 
 use self::randomization::randomizer::RandomizerTrait;
-use evolution::domain::constants::MUTATION_PROBABILITY;
+use neural_networks_error::NeuralNetworksError;
 use neuron_activation::activate_neuron::activate_neuron;
 use neuron_activation::activation_functions::ActivationFunctions;
 use neuron_activation::choose_random_activation_function::choose_random_activation_function;
@@ -10,25 +10,99 @@ use neuron_activation::choose_random_activation_function::choose_random_activati
 use self::serde::{Deserialize, Serialize};
 use std::fmt;
 
+// What can be read of a neuron, whether it stands on its own or is a row of a layer's matrix.
 pub trait NeuronTrait {
-    fn new<T: RandomizerTrait>(
-        number_of_inputs: u32,
-        activation_function: ActivationFunctions,
-        randomizer: &mut T,
-    ) -> Self;
-    fn get_number_of_weights(&self) -> u32;
-    fn activate(&self, inputs: &[f64]) -> Result<f64, String>;
+    fn get_weights(&self) -> &[f64];
     fn get_bias(&self) -> f64;
-    fn set_bias(&mut self, bias: f64);
-    fn get_weight(&self, index: usize) -> Result<f64, String>;
-    fn set_weight(&mut self, index: usize, weight: f64) -> Result<(), String>;
     fn get_activation_function(&self) -> &ActivationFunctions;
+
+    fn get_number_of_weights(&self) -> u32 {
+        self.get_weights().len() as u32
+    }
+
+    fn get_weight(&self, index: usize) -> Result<f64, NeuralNetworksError> {
+        self.get_weights()
+            .get(index)
+            .cloned()
+            .ok_or(NeuralNetworksError::WeightOutOfRange {
+                index,
+                number_of_weights: self.get_weights().len(),
+            })
+    }
+
+    fn get_weighted_sum(&self, inputs: &[f64]) -> Result<f64, NeuralNetworksError> {
+        let weights = self.get_weights();
+
+        if inputs.len() != weights.len() {
+            return Err(NeuralNetworksError::WrongNumberOfInputs {
+                expected: weights.len(),
+                received: inputs.len(),
+            });
+        }
+
+        Ok(weights
+            .iter()
+            .zip(inputs.iter())
+            .map(|(w, x)| w * x)
+            .sum::<f64>()
+            + self.get_bias())
+    }
+
+    fn activate(&self, inputs: &[f64]) -> Result<f64, NeuralNetworksError> {
+        Ok(activate_neuron(
+            self.get_weighted_sum(inputs)?,
+            self.get_activation_function(),
+        ))
+    }
+
+    fn should_mutate<T: RandomizerTrait>(
+        mutation_rate: f64,
+        randomizer: &mut T,
+    ) -> Result<bool, NeuralNetworksError>
+    where
+        Self: Sized,
+    {
+        Ok(randomizer.generate_float_from_0_to_1() > 1f64 - mutation_rate)
+    }
+}
+
+// What can be changed of a neuron, the same way.
+pub trait MutableNeuronTrait: NeuronTrait {
+    fn set_bias(&mut self, bias: f64);
+    fn set_weight(&mut self, index: usize, weight: f64) -> Result<(), NeuralNetworksError>;
     fn set_activation_function(
         &mut self,
         activation_function: ActivationFunctions,
-    ) -> Result<(), String>;
-    fn should_mutate<T: RandomizerTrait>(randomizer: &mut T) -> Result<bool, String>;
-    fn mutate<T: RandomizerTrait>(&mut self, randomizer: &mut T) -> Result<(), String>;
+    ) -> Result<(), NeuralNetworksError>;
+
+    fn mutate<T: RandomizerTrait>(
+        &mut self,
+        mutation_rate: f64,
+        randomizer: &mut T,
+    ) -> Result<(), NeuralNetworksError>
+    where
+        Self: Sized,
+    {
+        if Self::should_mutate(mutation_rate, randomizer)? {
+            self.set_bias(randomizer.get_normal());
+        }
+
+        // Ask for the possibility of mutation for each weight in this neuron.
+        for index in 0..self.get_number_of_weights() as usize {
+            if Self::should_mutate(mutation_rate, randomizer)? {
+                // Mutate corresponding weight.
+                self.set_weight(index, randomizer.get_normal())?;
+            }
+        }
+
+        // Ask again whether it should mutate, and if it should, attempt to
+        // change its activation function
+        if Self::should_mutate(mutation_rate, randomizer)? {
+            self.set_activation_function(choose_random_activation_function(randomizer))?;
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -52,8 +126,8 @@ impl fmt::Debug for Neuron {
     }
 }
 
-impl NeuronTrait for Neuron {
-    fn new<T>(
+impl Neuron {
+    pub fn new<T>(
         number_of_inputs: u32,
         activation_function: ActivationFunctions,
         randomizer: &mut T,
@@ -69,83 +143,47 @@ impl NeuronTrait for Neuron {
             activation_function,
         }
     }
+}
 
-    fn get_number_of_weights(&self) -> u32 {
-        self.weights.len() as u32
-    }
-
-    fn activate(&self, inputs: &[f64]) -> std::result::Result<f64, std::string::String> {
-        Ok(activate_neuron(
-            self.weights
-                .iter()
-                .zip(inputs.iter())
-                .map(|(w, x)| w * x)
-                .sum::<f64>()
-                + self.bias,
-            &self.activation_function,
-        ))
-    }
-
-    fn set_bias(&mut self, bias: f64) {
-        self.bias = bias
+impl NeuronTrait for Neuron {
+    fn get_weights(&self) -> &[f64] {
+        &self.weights
     }
 
     fn get_bias(&self) -> f64 {
         self.bias
     }
 
-    fn get_weight(&self, index: usize) -> std::result::Result<f64, std::string::String> {
-        Ok(self.weights[index])
+    fn get_activation_function(&self) -> &ActivationFunctions {
+        &self.activation_function
     }
+}
 
-    fn set_weight(
-        &mut self,
-        index: usize,
-        weight: f64,
-    ) -> std::result::Result<(), std::string::String> {
-        self.weights[index] = weight;
-
-        Ok(())
+impl MutableNeuronTrait for Neuron {
+    fn set_bias(&mut self, bias: f64) {
+        self.bias = bias
     }
 
-    fn should_mutate<T>(randomizer: &mut T) -> std::result::Result<bool, std::string::String>
-    where
-        T: RandomizerTrait,
-    {
-        Ok(randomizer.generate_float_from_0_to_1() > 1f64 - MUTATION_PROBABILITY)
-    }
+    fn set_weight(&mut self, index: usize, weight: f64) -> Result<(), NeuralNetworksError> {
+        let number_of_weights = self.weights.len();
 
-    fn mutate<T>(&mut self, randomizer: &mut T) -> std::result::Result<(), std::string::String>
-    where
-        T: RandomizerTrait,
-    {
-        if Neuron::should_mutate(randomizer)? {
-            self.bias = randomizer.get_normal();
-        }
+        match self.weights.get_mut(index) {
+            Some(stored_weight) => {
+                *stored_weight = weight;
 
-        // Ask for the possibility of mutation for each weight in this neuron.
-        for index in 0..self.weights.len() {
-            if Neuron::should_mutate(randomizer)? {
-                // Mutate corresponding weight.
-                self.weights[index] = randomizer.get_normal();
+                Ok(())
             }
+            None => Err(NeuralNetworksError::WeightOutOfRange {
+                index,
+                number_of_weights,
+            }),
         }
-
-        // Ask again whether it should mutate, and if it should, attempt to
-        // change its activation function
-        if Neuron::should_mutate(randomizer)? {
-            self.activation_function = choose_random_activation_function(randomizer);
-        }
-
-        Ok(())
-    }
-    fn get_activation_function(&self) -> &ActivationFunctions {
-        &self.activation_function
     }
+
     fn set_activation_function(
         &mut self,
         activation_function: ActivationFunctions,
-    ) -> std::result::Result<(), std::string::String> {
+    ) -> Result<(), NeuralNetworksError> {
         self.activation_function = activation_function;
         Ok(())
     }
@@ -158,7 +196,8 @@ mod tests {
     use neuron_activation::activation_functions::ActivationFunctions;
 
     #[test]
-    fn test_after_creating_a_neuron_it_has_expected_properties() -> Result<(), String> {
+    fn test_after_creating_a_neuron_it_has_expected_properties() -> Result<(), NeuralNetworksError>
+    {
         let number_of_inputs = 3;
 
         struct FakeRandomizer {}
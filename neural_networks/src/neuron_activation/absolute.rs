@@ -0,0 +1,13 @@
+pub fn absolute(z: f64) -> f64 {
+    z.abs()
+}
+
+pub fn absolute_derivative(z: f64) -> f64 {
+    if z > 0.0 {
+        1.0
+    } else if z < 0.0 {
+        -1.0
+    } else {
+        0.0
+    }
+}
@@ -1,9 +1,18 @@
+use neuron_activation::absolute::absolute;
 use neuron_activation::activation_functions::ActivationFunctions;
 use neuron_activation::cosine::cosine;
+use neuron_activation::elu::elu;
+use neuron_activation::gaussian::gaussian;
+use neuron_activation::gelu::gelu;
+use neuron_activation::identity::identity;
+use neuron_activation::inverse::inverse;
+use neuron_activation::leaky_relu::leaky_relu;
 use neuron_activation::relu::relu;
 use neuron_activation::sigmoid::sigmoid;
 use neuron_activation::sinusoid::sinusoid;
 use neuron_activation::softplus::softplus;
+use neuron_activation::softsign::softsign;
+use neuron_activation::step::step;
 use neuron_activation::swish::swish;
 use neuron_activation::tanh::tanh;
 
@@ -16,5 +25,15 @@ pub fn activate_neuron(value: f64, activation_function: &ActivationFunctions) ->
         ActivationFunctions::Tanh => tanh(value),
         ActivationFunctions::Cosine => cosine(value),
         ActivationFunctions::Swish => swish(value),
+        ActivationFunctions::LeakyRelu => leaky_relu(value),
+        ActivationFunctions::Elu => elu(value),
+        ActivationFunctions::Gelu => gelu(value),
+        ActivationFunctions::Gaussian => gaussian(value),
+        ActivationFunctions::Step => step(value),
+        ActivationFunctions::Identity => identity(value),
+        ActivationFunctions::Absolute => absolute(value),
+        ActivationFunctions::Softsign => softsign(value),
+        ActivationFunctions::Inverse => inverse(value),
+        ActivationFunctions::Custom(custom) => custom.activate(value),
     }
 }
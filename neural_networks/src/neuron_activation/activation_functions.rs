@@ -1,6 +1,7 @@
 extern crate serde;
 
 use self::serde::{Deserialize, Serialize};
+use neuron_activation::custom_activation_function::CustomActivationFunction;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub enum ActivationFunctions {
@@ -11,4 +12,33 @@ pub enum ActivationFunctions {
     Tanh,
     Cosine,
     Swish,
+    LeakyRelu,
+    Elu,
+    Gelu,
+    Gaussian,
+    Step,
+    Identity,
+    Absolute,
+    Softsign,
+    Inverse,
+    Custom(CustomActivationFunction),
 }
+
+pub const BUILT_IN_ACTIVATION_FUNCTIONS: [ActivationFunctions; 16] = [
+    ActivationFunctions::Sigmoid,
+    ActivationFunctions::Relu,
+    ActivationFunctions::Softplus,
+    ActivationFunctions::Sinusoid,
+    ActivationFunctions::Tanh,
+    ActivationFunctions::Cosine,
+    ActivationFunctions::Swish,
+    ActivationFunctions::LeakyRelu,
+    ActivationFunctions::Elu,
+    ActivationFunctions::Gelu,
+    ActivationFunctions::Gaussian,
+    ActivationFunctions::Step,
+    ActivationFunctions::Identity,
+    ActivationFunctions::Absolute,
+    ActivationFunctions::Softsign,
+    ActivationFunctions::Inverse,
+];
@@ -2,17 +2,12 @@ extern crate randomization;
 
 use self::randomization::randomizer::RandomizerTrait;
 use neuron_activation::activation_functions::ActivationFunctions;
+use neuron_activation::activation_functions::BUILT_IN_ACTIVATION_FUNCTIONS;
 
+// Custom activation functions are only chosen when asked for by name, through
+// choose_random_activation_function_including.
 pub fn choose_random_activation_function<T: RandomizerTrait>(
     randomizer: &mut T,
 ) -> ActivationFunctions {
-    randomizer.choose_random_from_vec(&[
-        ActivationFunctions::Sigmoid,
-        ActivationFunctions::Relu,
-        ActivationFunctions::Softplus,
-        ActivationFunctions::Sinusoid,
-        ActivationFunctions::Cosine,
-        ActivationFunctions::Tanh,
-        ActivationFunctions::Swish,
-    ])
+    randomizer.choose_random_from_vec(&BUILT_IN_ACTIVATION_FUNCTIONS)
 }
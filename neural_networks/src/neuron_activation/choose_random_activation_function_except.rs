@@ -2,17 +2,17 @@ extern crate randomization;
 
 use self::randomization::randomizer::RandomizerTrait;
 use neuron_activation::activation_functions::ActivationFunctions;
-use neuron_activation::choose_random_activation_function::choose_random_activation_function;
+use neuron_activation::activation_functions::BUILT_IN_ACTIVATION_FUNCTIONS;
 
 pub fn choose_random_activation_function_except<T: RandomizerTrait>(
     randomizer: &mut T,
     exceptions: &[ActivationFunctions],
 ) -> ActivationFunctions {
-    loop {
-        let choice = choose_random_activation_function(randomizer);
+    let candidates: Vec<ActivationFunctions> = BUILT_IN_ACTIVATION_FUNCTIONS
+        .iter()
+        .filter(|candidate| !exceptions.contains(candidate))
+        .copied()
+        .collect();
 
-        if !exceptions.iter().any(|exception| exception == &choice) {
-            return choice;
-        }
-    }
+    randomizer.choose_random_from_vec(&candidates)
 }
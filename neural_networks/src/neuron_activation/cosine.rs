@@ -1,3 +1,7 @@
 pub fn cosine(z: f64) -> f64 {
     z.cos()
 }
+
+pub fn cosine_derivative(z: f64) -> f64 {
+    -z.sin()
+}
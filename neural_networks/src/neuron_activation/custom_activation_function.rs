@@ -0,0 +1,170 @@
+extern crate serde;
+
+use self::serde::de::Error;
+use self::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use neural_networks_error::NeuralNetworksError;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::RwLock;
+
+struct RegisteredActivationFunction {
+    name: String,
+    activation: fn(f64) -> f64,
+    derivative: fn(f64) -> f64,
+}
+
+// Registrations live for the whole process, so a handle stays valid once it has been handed out.
+// Only looked into to register, and to go between names and handles when (de)serializing.
+static REGISTRY: RwLock<Vec<RegisteredActivationFunction>> = RwLock::new(Vec::new());
+
+// An activation function registered from outside this crate. It is serialized by its name, so the
+// same name has to be registered again before deserializing a neural network that uses it.
+//
+// The handle carries the functions themselves, so activating a neuron doesn't go through the lock.
+#[derive(Clone, Copy)]
+pub struct CustomActivationFunction {
+    index: usize,
+    activation: fn(f64) -> f64,
+    derivative: fn(f64) -> f64,
+}
+
+impl CustomActivationFunction {
+    pub fn register(
+        name: &str,
+        activation: fn(f64) -> f64,
+        derivative: fn(f64) -> f64,
+    ) -> Result<Self, NeuralNetworksError> {
+        let mut registry = REGISTRY
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        if registry.iter().any(|registered| registered.name == name) {
+            return Err(NeuralNetworksError::ActivationFunctionAlreadyRegistered(
+                name.to_string(),
+            ));
+        }
+
+        registry.push(RegisteredActivationFunction {
+            name: name.to_string(),
+            activation,
+            derivative,
+        });
+
+        Ok(Self {
+            index: registry.len() - 1,
+            activation,
+            derivative,
+        })
+    }
+
+    pub fn find(name: &str) -> Result<Self, NeuralNetworksError> {
+        REGISTRY
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .iter()
+            .enumerate()
+            .find(|(_, registered)| registered.name == name)
+            .map(|(index, registered)| Self {
+                index,
+                activation: registered.activation,
+                derivative: registered.derivative,
+            })
+            .ok_or_else(|| NeuralNetworksError::UnknownActivationFunction(name.to_string()))
+    }
+
+    pub fn get_name(&self) -> String {
+        REGISTRY
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())[self.index]
+            .name
+            .clone()
+    }
+
+    pub fn activate(&self, z: f64) -> f64 {
+        (self.activation)(z)
+    }
+
+    pub fn derive(&self, z: f64) -> f64 {
+        (self.derivative)(z)
+    }
+}
+
+// Names are unique, so the position in the registry alone tells the handles apart.
+impl PartialEq for CustomActivationFunction {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl Eq for CustomActivationFunction {}
+
+impl Hash for CustomActivationFunction {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.index.hash(state);
+    }
+}
+
+impl fmt::Debug for CustomActivationFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self.get_name())
+    }
+}
+
+impl Serialize for CustomActivationFunction {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.get_name())
+    }
+}
+
+impl<'de> Deserialize<'de> for CustomActivationFunction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+
+        CustomActivationFunction::find(&name).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use neuron_activation::activate_neuron::activate_neuron;
+    use neuron_activation::activation_functions::ActivationFunctions;
+    use neuron_activation::derive_activation::derive_activation;
+
+    fn cube(z: f64) -> f64 {
+        z.powi(3)
+    }
+
+    fn cube_derivative(z: f64) -> f64 {
+        3.0 * z.powi(2)
+    }
+
+    #[test]
+    fn test_a_registered_activation_function_is_used_and_round_trips_by_name(
+    ) -> Result<(), NeuralNetworksError> {
+        let cube = CustomActivationFunction::register("cube", cube, cube_derivative)?;
+        let activation_function = ActivationFunctions::Custom(cube);
+
+        assert_eq!(activate_neuron(2.0, &activation_function), 8.0);
+        assert_eq!(derive_activation(2.0, &activation_function), 12.0);
+
+        assert_eq!(
+            CustomActivationFunction::register("cube", |z| z, |_| 1.0),
+            Err(NeuralNetworksError::ActivationFunctionAlreadyRegistered(
+                "cube".to_string()
+            ))
+        );
+
+        let serialized = serde_json::to_string(&activation_function).unwrap();
+        assert_eq!(serialized, "{\"Custom\":\"cube\"}");
+        assert_eq!(
+            serde_json::from_str::<ActivationFunctions>(&serialized).unwrap(),
+            activation_function
+        );
+
+        assert!(serde_json::from_str::<ActivationFunctions>("{\"Custom\":\"unknown\"}").is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use neuron_activation::absolute::absolute_derivative;
+use neuron_activation::activation_functions::ActivationFunctions;
+use neuron_activation::cosine::cosine_derivative;
+use neuron_activation::elu::elu_derivative;
+use neuron_activation::gaussian::gaussian_derivative;
+use neuron_activation::gelu::gelu_derivative;
+use neuron_activation::identity::identity_derivative;
+use neuron_activation::inverse::inverse_derivative;
+use neuron_activation::leaky_relu::leaky_relu_derivative;
+use neuron_activation::relu::relu_derivative;
+use neuron_activation::sigmoid::sigmoid_derivative;
+use neuron_activation::sinusoid::sinusoid_derivative;
+use neuron_activation::softplus::softplus_derivative;
+use neuron_activation::softsign::softsign_derivative;
+use neuron_activation::step::step_derivative;
+use neuron_activation::swish::swish_derivative;
+use neuron_activation::tanh::tanh_derivative;
+
+pub fn derive_activation(value: f64, activation_function: &ActivationFunctions) -> f64 {
+    match activation_function {
+        ActivationFunctions::Sigmoid => sigmoid_derivative(value),
+        ActivationFunctions::Softplus => softplus_derivative(value),
+        ActivationFunctions::Relu => relu_derivative(value),
+        ActivationFunctions::Sinusoid => sinusoid_derivative(value),
+        ActivationFunctions::Tanh => tanh_derivative(value),
+        ActivationFunctions::Cosine => cosine_derivative(value),
+        ActivationFunctions::Swish => swish_derivative(value),
+        ActivationFunctions::LeakyRelu => leaky_relu_derivative(value),
+        ActivationFunctions::Elu => elu_derivative(value),
+        ActivationFunctions::Gelu => gelu_derivative(value),
+        ActivationFunctions::Gaussian => gaussian_derivative(value),
+        ActivationFunctions::Step => step_derivative(value),
+        ActivationFunctions::Identity => identity_derivative(value),
+        ActivationFunctions::Absolute => absolute_derivative(value),
+        ActivationFunctions::Softsign => softsign_derivative(value),
+        ActivationFunctions::Inverse => inverse_derivative(value),
+        ActivationFunctions::Custom(custom) => custom.derive(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use neuron_activation::activate_neuron::activate_neuron;
+    use neuron_activation::activation_functions::BUILT_IN_ACTIVATION_FUNCTIONS;
+
+    #[test]
+    fn test_derivatives_match_numerical_approximations() {
+        let step = 1e-6;
+
+        for activation_function in &BUILT_IN_ACTIVATION_FUNCTIONS {
+            for value in &[-2.5f64, -0.7f64, 0.3f64, 1.9f64] {
+                let approximation = (activate_neuron(value + step, activation_function)
+                    - activate_neuron(value - step, activation_function))
+                    / (2.0 * step);
+
+                let derivative = derive_activation(*value, activation_function);
+
+                assert!(
+                    (approximation - derivative).abs() < 1e-5,
+                    "Derivative of {:?} at {:?} was {:?}, but expected around {:?}",
+                    activation_function,
+                    value,
+                    derivative,
+                    approximation
+                );
+            }
+        }
+    }
+}
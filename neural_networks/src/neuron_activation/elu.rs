@@ -0,0 +1,15 @@
+pub fn elu(z: f64) -> f64 {
+    if z > 0.0 {
+        z
+    } else {
+        z.exp() - 1.0
+    }
+}
+
+pub fn elu_derivative(z: f64) -> f64 {
+    if z > 0.0 {
+        1.0
+    } else {
+        z.exp()
+    }
+}
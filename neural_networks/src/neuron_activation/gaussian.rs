@@ -0,0 +1,7 @@
+pub fn gaussian(z: f64) -> f64 {
+    (-z.powi(2)).exp()
+}
+
+pub fn gaussian_derivative(z: f64) -> f64 {
+    -2.0 * z * gaussian(z)
+}
@@ -0,0 +1,18 @@
+// The tanh approximation, so there is no need for an error function.
+const COEFFICIENT: f64 = 0.044715;
+
+fn inner(z: f64) -> f64 {
+    (2.0 / std::f64::consts::PI).sqrt() * (z + COEFFICIENT * z.powi(3))
+}
+
+pub fn gelu(z: f64) -> f64 {
+    0.5 * z * (1.0 + inner(z).tanh())
+}
+
+pub fn gelu_derivative(z: f64) -> f64 {
+    let t = inner(z).tanh();
+    let inner_derivative =
+        (2.0 / std::f64::consts::PI).sqrt() * (1.0 + 3.0 * COEFFICIENT * z.powi(2));
+
+    0.5 * (1.0 + t) + 0.5 * z * (1.0 - t.powi(2)) * inner_derivative
+}
@@ -0,0 +1,16 @@
+// Zero stays at zero instead of blowing up.
+pub fn inverse(z: f64) -> f64 {
+    if z == 0.0 {
+        0.0
+    } else {
+        1.0 / z
+    }
+}
+
+pub fn inverse_derivative(z: f64) -> f64 {
+    if z == 0.0 {
+        0.0
+    } else {
+        -1.0 / z.powi(2)
+    }
+}
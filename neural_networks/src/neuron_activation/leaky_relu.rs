@@ -0,0 +1,17 @@
+const NEGATIVE_SLOPE: f64 = 0.01;
+
+pub fn leaky_relu(z: f64) -> f64 {
+    if z > 0.0 {
+        z
+    } else {
+        NEGATIVE_SLOPE * z
+    }
+}
+
+pub fn leaky_relu_derivative(z: f64) -> f64 {
+    if z > 0.0 {
+        1.0
+    } else {
+        NEGATIVE_SLOPE
+    }
+}
@@ -1,12 +1,23 @@
+pub mod absolute;
 pub mod activate_neuron;
 pub mod activation_functions;
 pub mod choose_random_activation_function;
 pub mod choose_random_activation_function_except;
 pub mod choose_random_activation_function_including;
 pub mod cosine;
+pub mod custom_activation_function;
+pub mod derive_activation;
+pub mod elu;
+pub mod gaussian;
+pub mod gelu;
+pub mod identity;
+pub mod inverse;
+pub mod leaky_relu;
 pub mod relu;
 pub mod sigmoid;
 pub mod sinusoid;
 pub mod softplus;
+pub mod softsign;
+pub mod step;
 pub mod swish;
 pub mod tanh;
@@ -4,3 +4,11 @@ use self::float_ord::FloatOrd;
 pub fn relu(z: f64) -> f64 {
     std::cmp::max(FloatOrd(0.0), FloatOrd(z)).0
 }
+
+pub fn relu_derivative(z: f64) -> f64 {
+    if z > 0.0 {
+        1.0
+    } else {
+        0.0
+    }
+}
@@ -3,6 +3,12 @@ pub fn sigmoid(z: f64) -> f64 {
     1.0 / (1.0 + e.powf(-z))
 }
 
+pub fn sigmoid_derivative(z: f64) -> f64 {
+    let s = sigmoid(z);
+
+    s * (1.0 - s)
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -16,4 +22,11 @@ mod tests {
         assert!(sigmoid(1000f64) <= 1f64);
         assert_eq!(sigmoid(0f64), 0.5f64);
     }
+
+    #[test]
+    fn test_sigmoid_derivative() {
+        assert_eq!(sigmoid_derivative(0f64), 0.25f64);
+        assert!(sigmoid_derivative(1000f64) < 0.0001f64);
+        assert!(sigmoid_derivative(-1000f64) < 0.0001f64);
+    }
 }
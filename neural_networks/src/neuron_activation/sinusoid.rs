@@ -1,3 +1,7 @@
 pub fn sinusoid(z: f64) -> f64 {
     z.sin()
 }
+
+pub fn sinusoid_derivative(z: f64) -> f64 {
+    z.cos()
+}
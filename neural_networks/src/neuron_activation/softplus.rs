@@ -1,3 +1,9 @@
+use neuron_activation::sigmoid::sigmoid;
+
 pub fn softplus(z: f64) -> f64 {
     (1.0 + z.exp()).ln()
 }
+
+pub fn softplus_derivative(z: f64) -> f64 {
+    sigmoid(z)
+}
@@ -0,0 +1,7 @@
+pub fn softsign(z: f64) -> f64 {
+    z / (1.0 + z.abs())
+}
+
+pub fn softsign_derivative(z: f64) -> f64 {
+    1.0 / (1.0 + z.abs()).powi(2)
+}
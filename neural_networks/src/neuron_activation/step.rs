@@ -0,0 +1,11 @@
+pub fn step(z: f64) -> f64 {
+    if z > 0.0 {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+pub fn step_derivative(_z: f64) -> f64 {
+    0.0
+}
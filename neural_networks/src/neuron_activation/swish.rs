@@ -3,3 +3,9 @@ use neuron_activation::sigmoid::sigmoid;
 pub fn swish(z: f64) -> f64 {
     z * sigmoid(z)
 }
+
+pub fn swish_derivative(z: f64) -> f64 {
+    let s = sigmoid(z);
+
+    s + z * s * (1.0 - s)
+}
@@ -1,3 +1,7 @@
 pub fn tanh(z: f64) -> f64 {
     z.tanh()
 }
+
+pub fn tanh_derivative(z: f64) -> f64 {
+    1.0 - z.tanh().powi(2)
+}
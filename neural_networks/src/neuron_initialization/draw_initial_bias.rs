@@ -0,0 +1,12 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use neuron_initialization::initializers::Initializers;
+
+pub fn draw_initial_bias<T: RandomizerTrait>(initializer: Initializers, randomizer: &mut T) -> f64 {
+    match initializer {
+        Initializers::StandardNormal => randomizer.get_normal(),
+        Initializers::Constant { value } => value,
+        _ => 0.0,
+    }
+}
@@ -0,0 +1,72 @@
+extern crate randomization;
+
+use self::randomization::randomizer::RandomizerTrait;
+use neuron_initialization::initializers::Initializers;
+
+pub fn draw_initial_weight<T: RandomizerTrait>(
+    initializer: Initializers,
+    fan_in: usize,
+    fan_out: usize,
+    randomizer: &mut T,
+) -> f64 {
+    let fan_in = fan_in.max(1) as f64;
+    let fan_out = fan_out.max(1) as f64;
+
+    match initializer {
+        Initializers::StandardNormal => randomizer.get_normal(),
+        Initializers::XavierNormal => randomizer.get_normal() * (2.0 / (fan_in + fan_out)).sqrt(),
+        Initializers::XavierUniform => draw_uniform((6.0 / (fan_in + fan_out)).sqrt(), randomizer),
+        Initializers::HeNormal => randomizer.get_normal() * (2.0 / fan_in).sqrt(),
+        Initializers::HeUniform => draw_uniform((6.0 / fan_in).sqrt(), randomizer),
+        Initializers::LeCunNormal => randomizer.get_normal() * (1.0 / fan_in).sqrt(),
+        Initializers::LeCunUniform => draw_uniform((3.0 / fan_in).sqrt(), randomizer),
+        Initializers::ScaledUniform { scale } => draw_uniform(scale / fan_in.sqrt(), randomizer),
+        Initializers::Constant { value } => value,
+    }
+}
+
+// Uniformly between -limit and limit.
+fn draw_uniform<T: RandomizerTrait>(limit: f64, randomizer: &mut T) -> f64 {
+    (2.0 * randomizer.generate_float_from_0_to_1() - 1.0) * limit
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    extern crate randomization;
+    use self::randomization::seeded_randomizer::SeededRandomizer;
+
+    #[test]
+    fn test_the_spread_of_the_weights_shrinks_with_the_fan_in() {
+        let mut randomizer = SeededRandomizer::new(6);
+
+        let standard_deviation = |initializer, fan_in, randomizer: &mut SeededRandomizer| {
+            let weights: Vec<f64> = (0..4000)
+                .map(|_| draw_initial_weight(initializer, fan_in, 50, randomizer))
+                .collect();
+
+            (weights.iter().map(|weight| weight * weight).sum::<f64>() / weights.len() as f64)
+                .sqrt()
+        };
+
+        let he = standard_deviation(Initializers::HeNormal, 50, &mut randomizer);
+        assert!((he - 0.2).abs() < 0.01);
+
+        let xavier = standard_deviation(Initializers::XavierUniform, 150, &mut randomizer);
+        assert!((xavier - 0.1).abs() < 0.01);
+
+        for _ in 0..100 {
+            assert!(
+                draw_initial_weight(Initializers::LeCunUniform, 12, 1, &mut randomizer).abs()
+                    <= 0.5
+            );
+        }
+
+        assert_eq!(
+            draw_initial_weight(Initializers::Constant { value: 0.3 }, 4, 4, &mut randomizer),
+            0.3
+        );
+    }
+}
@@ -0,0 +1,19 @@
+extern crate serde;
+
+use self::serde::{Deserialize, Serialize};
+
+// How the weights and biases of a new layer get drawn. StandardNormal is what neurons get when
+// created on their own; the rest scale the weights by the fan-in (and fan-out) of the layer, so deep
+// stacks of sigmoid or tanh neurons don't saturate from the start. Their biases start at zero.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub enum Initializers {
+    StandardNormal,
+    XavierNormal,
+    XavierUniform,
+    HeNormal,
+    HeUniform,
+    LeCunNormal,
+    LeCunUniform,
+    ScaledUniform { scale: f64 },
+    Constant { value: f64 },
+}
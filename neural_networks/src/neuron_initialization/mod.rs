@@ -0,0 +1,3 @@
+pub mod draw_initial_bias;
+pub mod draw_initial_weight;
+pub mod initializers;
@@ -0,0 +1,38 @@
+extern crate serde;
+
+use self::serde::Serialize;
+use neuron::NeuronTrait;
+use neuron_activation::activation_functions::ActivationFunctions;
+
+// A neuron of a layer, read straight out of the layer's matrix. It serializes the same way as a
+// Neuron does, which is how layers keep their JSON format.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct NeuronView<'a> {
+    weights: &'a [f64],
+    bias: f64,
+    activation_function: ActivationFunctions,
+}
+
+impl<'a> NeuronView<'a> {
+    pub fn new(weights: &'a [f64], bias: f64, activation_function: ActivationFunctions) -> Self {
+        NeuronView {
+            weights,
+            bias,
+            activation_function,
+        }
+    }
+}
+
+impl NeuronTrait for NeuronView<'_> {
+    fn get_weights(&self) -> &[f64] {
+        self.weights
+    }
+
+    fn get_bias(&self) -> f64 {
+        self.bias
+    }
+
+    fn get_activation_function(&self) -> &ActivationFunctions {
+        &self.activation_function
+    }
+}
@@ -0,0 +1,71 @@
+use neural_networks_error::NeuralNetworksError;
+use neuron::MutableNeuronTrait;
+use neuron::NeuronTrait;
+use neuron_activation::activation_functions::ActivationFunctions;
+
+// Same as NeuronView, but changing the neuron writes straight into the layer's matrix.
+#[derive(Debug)]
+pub struct NeuronViewMut<'a> {
+    weights: &'a mut [f64],
+    bias: &'a mut f64,
+    activation_function: &'a mut ActivationFunctions,
+}
+
+impl<'a> NeuronViewMut<'a> {
+    pub fn new(
+        weights: &'a mut [f64],
+        bias: &'a mut f64,
+        activation_function: &'a mut ActivationFunctions,
+    ) -> Self {
+        NeuronViewMut {
+            weights,
+            bias,
+            activation_function,
+        }
+    }
+}
+
+impl NeuronTrait for NeuronViewMut<'_> {
+    fn get_weights(&self) -> &[f64] {
+        self.weights
+    }
+
+    fn get_bias(&self) -> f64 {
+        *self.bias
+    }
+
+    fn get_activation_function(&self) -> &ActivationFunctions {
+        self.activation_function
+    }
+}
+
+impl MutableNeuronTrait for NeuronViewMut<'_> {
+    fn set_bias(&mut self, bias: f64) {
+        *self.bias = bias;
+    }
+
+    fn set_weight(&mut self, index: usize, weight: f64) -> Result<(), NeuralNetworksError> {
+        let number_of_weights = self.weights.len();
+
+        match self.weights.get_mut(index) {
+            Some(stored_weight) => {
+                *stored_weight = weight;
+
+                Ok(())
+            }
+            None => Err(NeuralNetworksError::WeightOutOfRange {
+                index,
+                number_of_weights,
+            }),
+        }
+    }
+
+    fn set_activation_function(
+        &mut self,
+        activation_function: ActivationFunctions,
+    ) -> Result<(), NeuralNetworksError> {
+        *self.activation_function = activation_function;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use output_transformation::output_transforms::OutputTransforms;
+use output_transformation::softmax::softmax;
+
+// Pushes the errors with respect to the transformed outputs back to the errors with respect to
+// the outputs as they were before the transform.
+pub fn derive_output_transform(
+    untransformed_outputs: &[f64],
+    errors: &mut [f64],
+    output_transform: &OutputTransforms,
+) {
+    match output_transform {
+        OutputTransforms::None => {}
+        OutputTransforms::Softmax => {
+            let mut probabilities = untransformed_outputs.to_vec();
+            softmax(&mut probabilities);
+
+            let weighted_sum: f64 = errors
+                .iter()
+                .zip(probabilities.iter())
+                .map(|(error, probability)| error * probability)
+                .sum();
+
+            for (error, probability) in errors.iter_mut().zip(probabilities.iter()) {
+                *error = probability * (*error - weighted_sum);
+            }
+        }
+        OutputTransforms::LogSoftmax => {
+            let mut probabilities = untransformed_outputs.to_vec();
+            softmax(&mut probabilities);
+
+            let sum: f64 = errors.iter().sum();
+
+            for (error, probability) in errors.iter_mut().zip(probabilities.iter()) {
+                *error -= probability * sum;
+            }
+        }
+    }
+}
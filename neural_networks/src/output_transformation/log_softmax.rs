@@ -0,0 +1,13 @@
+pub fn log_softmax(values: &mut [f64]) {
+    let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let log_sum = values
+        .iter()
+        .map(|value| (value - max).exp())
+        .sum::<f64>()
+        .ln()
+        + max;
+
+    for value in values.iter_mut() {
+        *value -= log_sum;
+    }
+}
@@ -0,0 +1,5 @@
+pub mod derive_output_transform;
+pub mod log_softmax;
+pub mod output_transforms;
+pub mod softmax;
+pub mod transform_outputs;
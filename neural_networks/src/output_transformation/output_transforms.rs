@@ -0,0 +1,19 @@
+extern crate serde;
+
+use self::serde::{Deserialize, Serialize};
+
+// Applied to the outputs of a whole layer after its neurons got activated, for when the outputs
+// have to be read as probabilities over the neurons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize, Default)]
+pub enum OutputTransforms {
+    #[default]
+    None,
+    Softmax,
+    LogSoftmax,
+}
+
+impl OutputTransforms {
+    pub fn is_none(&self) -> bool {
+        *self == OutputTransforms::None
+    }
+}
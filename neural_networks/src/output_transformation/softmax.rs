@@ -0,0 +1,42 @@
+// The maximum gets subtracted first so that large outputs don't overflow.
+pub fn softmax(values: &mut [f64]) {
+    let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+    for value in values.iter_mut() {
+        *value = (*value - max).exp();
+    }
+
+    let sum: f64 = values.iter().sum();
+
+    for value in values.iter_mut() {
+        *value /= sum;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_softmax_sums_to_one_and_keeps_the_order() {
+        let mut values = [1.0, 3.0, 2.0];
+
+        softmax(&mut values);
+
+        assert!((values.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+        assert!(values[0] < values[2] && values[2] < values[1]);
+
+        let mut values = [1.0, 1000.0];
+
+        softmax(&mut values);
+
+        assert_eq!(values, [0.0, 1.0]);
+
+        let mut values = [0.0, 0.0];
+
+        softmax(&mut values);
+
+        assert_eq!(values, [0.5, 0.5]);
+    }
+}
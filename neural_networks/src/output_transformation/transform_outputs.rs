@@ -0,0 +1,11 @@
+use output_transformation::log_softmax::log_softmax;
+use output_transformation::output_transforms::OutputTransforms;
+use output_transformation::softmax::softmax;
+
+pub fn transform_outputs(outputs: &mut [f64], output_transform: &OutputTransforms) {
+    match output_transform {
+        OutputTransforms::None => {}
+        OutputTransforms::Softmax => softmax(outputs),
+        OutputTransforms::LogSoftmax => log_softmax(outputs),
+    }
+}
@@ -0,0 +1,119 @@
+extern crate serde;
+
+use self::serde::{Deserialize, Serialize};
+use neural_networks_error::NeuralNetworksError;
+
+// Where the parameters of each layer sit inside the flat vector of a network's parameters. Every
+// neuron takes its bias followed by one weight per input of its layer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParameterLayout {
+    layer_offsets: Vec<usize>,
+    layer_shapes: Vec<[usize; 2]>,
+    number_of_parameters: usize,
+}
+
+impl ParameterLayout {
+    pub fn new(layer_shapes: Vec<[usize; 2]>) -> Self {
+        let mut layer_offsets = Vec::with_capacity(layer_shapes.len());
+        let mut number_of_parameters = 0;
+
+        for [number_of_inputs, number_of_neurons] in layer_shapes.iter() {
+            layer_offsets.push(number_of_parameters);
+            number_of_parameters += number_of_neurons * (number_of_inputs + 1);
+        }
+
+        ParameterLayout {
+            layer_offsets,
+            layer_shapes,
+            number_of_parameters,
+        }
+    }
+
+    pub fn get_number_of_parameters(&self) -> usize {
+        self.number_of_parameters
+    }
+
+    pub fn get_number_of_layers(&self) -> usize {
+        self.layer_shapes.len()
+    }
+
+    // [number of inputs, number of neurons], same as a layers definition.
+    pub fn get_layer_shapes(&self) -> &Vec<[usize; 2]> {
+        &self.layer_shapes
+    }
+
+    pub fn get_layer_range(
+        &self,
+        layer: usize,
+    ) -> Result<std::ops::Range<usize>, NeuralNetworksError> {
+        let [number_of_inputs, number_of_neurons] = self.get_layer_shape(layer)?;
+        let offset = self.layer_offsets[layer];
+
+        Ok(offset..offset + number_of_neurons * (number_of_inputs + 1))
+    }
+
+    pub fn get_bias_index(
+        &self,
+        layer: usize,
+        neuron: usize,
+    ) -> Result<usize, NeuralNetworksError> {
+        let [number_of_inputs, number_of_neurons] = self.get_layer_shape(layer)?;
+
+        if neuron >= number_of_neurons {
+            return Err(NeuralNetworksError::NeuronOutOfRange {
+                index: neuron,
+                number_of_neurons,
+            });
+        }
+
+        Ok(self.layer_offsets[layer] + neuron * (number_of_inputs + 1))
+    }
+
+    pub fn get_weight_index(
+        &self,
+        layer: usize,
+        neuron: usize,
+        weight: usize,
+    ) -> Result<usize, NeuralNetworksError> {
+        let [number_of_inputs, _] = self.get_layer_shape(layer)?;
+
+        if weight >= number_of_inputs {
+            return Err(NeuralNetworksError::WeightOutOfRange {
+                index: weight,
+                number_of_weights: number_of_inputs,
+            });
+        }
+
+        Ok(self.get_bias_index(layer, neuron)? + 1 + weight)
+    }
+
+    fn get_layer_shape(&self, layer: usize) -> Result<[usize; 2], NeuralNetworksError> {
+        match self.layer_shapes.get(layer) {
+            Some(layer_shape) => Ok(*layer_shape),
+            None => Err(NeuralNetworksError::LayerOutOfRange {
+                index: layer,
+                number_of_layers: self.layer_shapes.len(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_the_layout_locates_every_bias_and_weight() -> Result<(), NeuralNetworksError> {
+        let sut = ParameterLayout::new(vec![[2, 3], [3, 1]]);
+
+        assert_eq!(sut.get_number_of_parameters(), 13);
+        assert_eq!(sut.get_layer_range(1)?, 9..13);
+        assert_eq!(sut.get_bias_index(0, 2)?, 6);
+        assert_eq!(sut.get_weight_index(1, 0, 2)?, 12);
+        assert!(sut.get_weight_index(0, 0, 2).is_err());
+        assert!(sut.get_bias_index(2, 0).is_err());
+
+        Ok(())
+    }
+}
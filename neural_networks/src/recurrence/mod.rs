@@ -0,0 +1,2 @@
+pub mod recurrences;
+pub mod step_recurrent_layer;
@@ -0,0 +1,54 @@
+extern crate serde;
+
+use self::serde::{Deserialize, Serialize};
+
+// What a layer remembers between steps. Recurrent layers hold one neuron per gate and unit, gate
+// after gate, and every one of those neurons weighs the inputs of the layer followed by its
+// previous outputs. Gates always squash with a sigmoid; the activation functions of the neurons
+// only apply to the Elman units and to the candidates of GRU and LSTM layers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize, Default)]
+pub enum Recurrences {
+    #[default]
+    None,
+    Elman,
+    // Update gate, reset gate, candidate.
+    Gru,
+    // Input gate, forget gate, output gate, candidate.
+    Lstm,
+}
+
+impl Recurrences {
+    pub fn is_none(&self) -> bool {
+        *self == Recurrences::None
+    }
+
+    pub fn get_number_of_gates(&self) -> usize {
+        match self {
+            Recurrences::None | Recurrences::Elman => 1,
+            Recurrences::Gru => 3,
+            Recurrences::Lstm => 4,
+        }
+    }
+
+    // Neurons of recurrent layers also weigh the previous outputs of their layer.
+    pub fn get_number_of_weights_per_neuron(
+        &self,
+        number_of_inputs: usize,
+        number_of_neurons: usize,
+    ) -> usize {
+        if self.is_none() {
+            number_of_inputs
+        } else {
+            number_of_inputs + number_of_neurons / self.get_number_of_gates()
+        }
+    }
+
+    // The hidden state, followed by the cell state for LSTM layers.
+    pub fn get_state_size(&self, number_of_units: usize) -> usize {
+        match self {
+            Recurrences::None => 0,
+            Recurrences::Elman | Recurrences::Gru => number_of_units,
+            Recurrences::Lstm => 2 * number_of_units,
+        }
+    }
+}
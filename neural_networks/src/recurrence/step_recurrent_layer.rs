@@ -0,0 +1,104 @@
+use neuron_activation::activate_neuron::activate_neuron;
+use neuron_activation::activation_functions::ActivationFunctions;
+use neuron_activation::sigmoid::sigmoid;
+use recurrence::recurrences::Recurrences;
+
+// Moves a layer one step forward. The state gets updated in place, and starts out as zeroes when
+// it doesn't have the expected size yet. The weights are the matrix of the layer, one row per
+// neuron, and each neuron has its own bias and activation function.
+pub fn step_recurrent_layer(
+    recurrence: &Recurrences,
+    activation_functions: &[ActivationFunctions],
+    weights: &[f64],
+    biases: &[f64],
+    inputs: &[f64],
+    state: &mut Vec<f64>,
+) -> Vec<f64> {
+    let number_of_units = biases.len() / recurrence.get_number_of_gates();
+    let state_size = recurrence.get_state_size(number_of_units);
+
+    if state.len() != state_size {
+        state.clear();
+        state.resize(state_size, 0.0);
+    }
+
+    let row_length = if biases.is_empty() {
+        0
+    } else {
+        weights.len() / biases.len()
+    };
+
+    let weighted_sum = |neuron: usize, previous_outputs: &[f64]| -> f64 {
+        let row = &weights[neuron * row_length..(neuron + 1) * row_length];
+        let (input_weights, recurrent_weights) = row.split_at(inputs.len());
+
+        input_weights
+            .iter()
+            .zip(inputs.iter())
+            .chain(recurrent_weights.iter().zip(previous_outputs.iter()))
+            .map(|(w, x)| w * x)
+            .sum::<f64>()
+            + biases[neuron]
+    };
+    let activate =
+        |neuron: usize, value: f64| activate_neuron(value, &activation_functions[neuron]);
+
+    let hidden = state[..number_of_units.min(state.len())].to_vec();
+
+    match recurrence {
+        Recurrences::None => (0..biases.len())
+            .map(|neuron| activate(neuron, weighted_sum(neuron, &[])))
+            .collect(),
+        Recurrences::Elman => {
+            let outputs: Vec<f64> = (0..number_of_units)
+                .map(|unit| activate(unit, weighted_sum(unit, &hidden)))
+                .collect();
+
+            state.copy_from_slice(&outputs);
+
+            outputs
+        }
+        Recurrences::Gru => {
+            let reset_hidden: Vec<f64> = (0..number_of_units)
+                .map(|unit| sigmoid(weighted_sum(number_of_units + unit, &hidden)) * hidden[unit])
+                .collect();
+
+            let outputs: Vec<f64> = (0..number_of_units)
+                .map(|unit| {
+                    let update = sigmoid(weighted_sum(unit, &hidden));
+                    let candidate_neuron = 2 * number_of_units + unit;
+                    let candidate = activate(
+                        candidate_neuron,
+                        weighted_sum(candidate_neuron, &reset_hidden),
+                    );
+
+                    (1.0 - update) * candidate + update * hidden[unit]
+                })
+                .collect();
+
+            state.copy_from_slice(&outputs);
+
+            outputs
+        }
+        Recurrences::Lstm => {
+            let mut outputs = Vec::with_capacity(number_of_units);
+
+            for unit in 0..number_of_units {
+                let input = sigmoid(weighted_sum(unit, &hidden));
+                let forget = sigmoid(weighted_sum(number_of_units + unit, &hidden));
+                let output = sigmoid(weighted_sum(2 * number_of_units + unit, &hidden));
+                let candidate_neuron = 3 * number_of_units + unit;
+                let candidate = activate(candidate_neuron, weighted_sum(candidate_neuron, &hidden));
+
+                let cell = forget * state[number_of_units + unit] + input * candidate;
+
+                state[number_of_units + unit] = cell;
+                outputs.push(output * cell.tanh());
+            }
+
+            state[..number_of_units].copy_from_slice(&outputs);
+
+            outputs
+        }
+    }
+}
@@ -1 +1,3 @@
 pub mod randomizer;
+pub mod seeded_randomizer;
+pub mod splittable_randomizer_trait;
@@ -0,0 +1,192 @@
+extern crate serde;
+
+use self::serde::{Deserialize, Serialize};
+use randomizer::RandomizerTrait;
+use splittable_randomizer_trait::SplittableRandomizerTrait;
+
+const JUMP: [u64; 4] = [
+    0x180e_c6d3_3cfd_0aba,
+    0xd5a6_1266_f0c9_392c,
+    0xa958_2618_e03f_c9aa,
+    0x39ab_dc45_29b1_661c,
+];
+
+// A xoshiro256** generator. Unlike Randomizer, the same seed always produces the same values, and
+// its whole state can be serialized to be restored later on, so runs can be replayed exactly.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SeededRandomizer {
+    state: [u64; 4],
+}
+
+impl SeededRandomizer {
+    pub fn new(seed: u64) -> SeededRandomizer {
+        // The seed gets spread over the state through SplitMix64, as recommended by the authors
+        // of xoshiro, so the state can never be all zeros.
+        let mut seed = seed;
+        let mut state = [0u64; 4];
+
+        for value in state.iter_mut() {
+            seed = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+            let mut z = seed;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+            *value = z ^ (z >> 31);
+        }
+
+        SeededRandomizer { state }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.state[1] << 17;
+
+        self.state[2] ^= self.state[0];
+        self.state[3] ^= self.state[1];
+        self.state[1] ^= self.state[2];
+        self.state[0] ^= self.state[3];
+
+        self.state[2] ^= t;
+        self.state[3] = self.state[3].rotate_left(45);
+
+        result
+    }
+}
+
+impl RandomizerTrait for SeededRandomizer {
+    fn get_normal(&mut self) -> f64 {
+        // Box-Muller transform. The first uniform value is taken from (0, 1] so the logarithm
+        // stays finite.
+        let u1 = 1.0 - self.generate_float_from_0_to_1();
+        let u2 = self.generate_float_from_0_to_1();
+
+        (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    }
+
+    fn generate_float_from_0_to_1(&mut self) -> f64 {
+        // The 53 upper bits fill the mantissa of a float in [0, 1).
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    fn choose_random_from_vec<T: Clone>(&mut self, vector: &[T]) -> T {
+        let index = (self.generate_float_from_0_to_1() * vector.len() as f64) as usize;
+
+        vector[index.min(vector.len() - 1)].clone()
+    }
+}
+
+impl SplittableRandomizerTrait for SeededRandomizer {
+    // Returns a randomizer that continues from the current state, while this one jumps 2^128
+    // values ahead. The two streams can't overlap in practice, so each child can be handed to a
+    // different part of a run (a thread, an island...) without affecting the others.
+    fn derive_child(&mut self) -> SeededRandomizer {
+        let child = self.clone();
+
+        let mut jumped_state = [0u64; 4];
+
+        for jump in JUMP.iter() {
+            for bit in 0..64 {
+                if jump & (1u64 << bit) != 0 {
+                    for (jumped, current) in jumped_state.iter_mut().zip(self.state.iter()) {
+                        *jumped ^= current;
+                    }
+                }
+
+                self.next_u64();
+            }
+        }
+
+        self.state = jumped_state;
+
+        child
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    extern crate serde_json;
+
+    #[test]
+    fn test_the_same_seed_produces_the_same_values() {
+        let mut first = SeededRandomizer::new(80);
+        let mut second = SeededRandomizer::new(80);
+        let mut other = SeededRandomizer::new(81);
+
+        let first_values: Vec<f64> = (0..10).map(|_| first.get_normal()).collect();
+        let second_values: Vec<f64> = (0..10).map(|_| second.get_normal()).collect();
+        let other_values: Vec<f64> = (0..10).map(|_| other.get_normal()).collect();
+
+        assert_eq!(first_values, second_values);
+        assert_ne!(first_values, other_values);
+    }
+
+    #[test]
+    fn test_a_restored_randomizer_continues_where_it_was_saved() {
+        let mut randomizer = SeededRandomizer::new(42);
+
+        for _ in 0..100 {
+            randomizer.generate_float_from_0_to_1();
+        }
+
+        let serialized = serde_json::to_string(&randomizer).unwrap();
+        let mut restored: SeededRandomizer = serde_json::from_str(&serialized).unwrap();
+
+        for _ in 0..10 {
+            assert_eq!(restored.next_u64(), randomizer.next_u64());
+        }
+    }
+
+    #[test]
+    fn test_child_streams_differ_from_each_other_and_their_parent() {
+        let mut parent = SeededRandomizer::new(7);
+
+        let mut first_child = parent.derive_child();
+        let mut second_child = parent.derive_child();
+
+        let parent_values: Vec<u64> = (0..10).map(|_| parent.next_u64()).collect();
+        let first_values: Vec<u64> = (0..10).map(|_| first_child.next_u64()).collect();
+        let second_values: Vec<u64> = (0..10).map(|_| second_child.next_u64()).collect();
+
+        assert_ne!(parent_values, first_values);
+        assert_ne!(parent_values, second_values);
+        assert_ne!(first_values, second_values);
+
+        // Deriving is deterministic as well.
+        let mut same_parent = SeededRandomizer::new(7);
+
+        assert_eq!(same_parent.derive_child().next_u64(), first_values[0]);
+    }
+
+    #[test]
+    fn test_values_follow_their_distributions() {
+        let mut randomizer = SeededRandomizer::new(3);
+
+        let floats: Vec<f64> = (0..10000)
+            .map(|_| randomizer.generate_float_from_0_to_1())
+            .collect();
+        let normals: Vec<f64> = (0..10000).map(|_| randomizer.get_normal()).collect();
+
+        assert!(floats.iter().all(|value| *value >= 0.0 && *value < 1.0));
+        assert!((floats.iter().sum::<f64>() / 10000.0 - 0.5).abs() < 0.02);
+
+        let mean = normals.iter().sum::<f64>() / 10000.0;
+        let variance = normals
+            .iter()
+            .map(|value| (value - mean).powi(2))
+            .sum::<f64>()
+            / 10000.0;
+
+        assert!(mean.abs() < 0.05);
+        assert!((variance - 1.0).abs() < 0.1);
+
+        let chosen: Vec<u32> = (0..100)
+            .map(|_| randomizer.choose_random_from_vec(&[1, 2, 3]))
+            .collect();
+
+        assert!(chosen.iter().all(|value| [1, 2, 3].contains(value)));
+    }
+}
@@ -0,0 +1,7 @@
+use randomizer::RandomizerTrait;
+
+// Randomizers that can hand out independent streams of values, for instance one for each thread.
+// The children are derived deterministically, so results don't depend on how threads get scheduled.
+pub trait SplittableRandomizerTrait: RandomizerTrait {
+    fn derive_child(&mut self) -> Self;
+}
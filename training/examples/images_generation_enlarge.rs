@@ -1,11 +1,8 @@
 extern crate file_system;
 extern crate gym;
 extern crate neural_networks;
-extern crate randomization;
 extern crate user_interface;
 
-use self::randomization::randomizer::Randomizer;
-
 use user_interface::controllers::console_input_controller::ConsoleInputController;
 use user_interface::controllers::console_input_controller_trait::ConsoleInputControllerTrait;
 
@@ -39,8 +36,6 @@ fn main() {
         );
     }
 
-    let mut randomizer = Randomizer::new();
-
     console_display_controller
         .write_section("Rendering enlarged images")
         .unwrap();
@@ -108,7 +103,6 @@ fn main() {
                     image_dimension,
                     genome.get_neural_network(),
                     genome_filename.as_str(),
-                    &mut randomizer,
                 )
                 .unwrap();
 
@@ -21,13 +21,14 @@ use self::user_interface::controllers::console_display_controller::ConsoleDispla
 use self::user_interface::controllers::display_controller_trait::DisplayControllerTrait;
 use file_system::create_all_directories_on_path::create_all_directories_on_path;
 use gym::controllers::gym_controller::GymController;
+use neural_networks::evolution::domain::evolution_config::EvolutionConfig;
 use neural_networks::evolution::domain::genome::Genome;
 use neural_networks::evolution::domain::population::Population;
 use neural_networks::evolution::domain::population::PopulationTrait;
+use neural_networks::evolution::domain::selection::truncation_selection::TruncationSelection;
+use neural_networks::neural_network::LayeredNeuralNetworkTrait;
 use neural_networks::neural_network::NeuralNetwork;
-use neural_networks::neural_network::NeuralNetworkTrait;
 use neural_networks::neuron::Neuron;
-use neural_networks::neuron::NeuronTrait;
 use neural_networks::neuron_activation::activation_functions::ActivationFunctions;
 use randomization::randomizer::Randomizer;
 
@@ -50,6 +51,7 @@ fn main() {
 
     let saved_genomes_as_strings: Vec<String> = {
         get_filenames_from_directory_that_end_with_extension(SAVED_GENOMES_DIRECTORY, "json")
+            .unwrap()
             .iter()
             .map(|stored_genome| read_file_to_string(stored_genome).unwrap())
             .collect()
@@ -63,7 +65,7 @@ fn main() {
                     NUMBER_OF_NEURAL_NETWORKS,
                     LAYERS_DEFINITION,
                     |genome_identifier, layers_definition, randomizer| {
-                        Genome::new(
+                        Ok(Genome::new(
                             genome_identifier,
                             NeuralNetwork::new_with_specified_layers(
                                 layers_definition,
@@ -82,8 +84,8 @@ fn main() {
                                         randomizer,
                                     )
                                 },
-                            ),
-                        )
+                            )?,
+                        ))
                     },
                     randomizer,
                 )
@@ -129,18 +131,19 @@ fn main() {
         population,
         |generation_number, _current_winner| if generation_number >= 1 { false } else { true },
         |_genomes, _randomizer| Ok(()),
-        |evolved_population, randomizer| {
+        |evolved_population, _randomizer| {
             process_generation_of_images_from_neural_networks(
                 evolved_population.get_genomes().unwrap(),
-                randomizer,
                 &console_display_controller,
             )
             .unwrap();
             save_evolved_population(evolved_population)
         },
+        TruncationSelection::new(0.5),
+        EvolutionConfig::new(),
     );
 
-    let new_population = gym_controller
+    let (new_population, _training_history) = gym_controller
         .train(
             |genome_identifier, neural_network| Genome::new(genome_identifier, neural_network),
             || NeuralNetwork::new(),
@@ -158,7 +161,7 @@ fn main() {
                     randomizer,
                 )
             },
-            |_generation_number, _population| {
+            |_generation_number, _population, _generation_statistics| {
                 console_display_controller
                     .write_information("Training population created.")
                     .unwrap()
@@ -4,10 +4,12 @@ extern crate gym;
 extern crate neural_networks;
 extern crate randomization;
 
+use self::cosmopolitan_collapse::components::domain::create_settling_in_io_schema::create_settling_in_io_schema;
 use self::file_system::deserialize_json_from_string::deserialize_json_from_string;
 use self::file_system::read_file_to_string::read_file_to_string;
 use self::file_system::save_json::save_json;
 use self::gym::domain::models::cosmopolitan_collapse::train_for_domain::train_for_domain;
+use self::gym::gym_error::GymError;
 use self::neural_networks::evolution::domain::genome::Genome;
 use self::neural_networks::evolution::domain::genome::GenomeTrait;
 use self::neural_networks::get_index_max_output::get_index_max_output;
@@ -28,7 +30,7 @@ fn main() {
         },
         |genomes: &mut Vec<Genome<NeuralNetwork<Neuron>, Neuron>>,
          _randomizer: &mut Randomizer|
-         -> Result<(), String> {
+         -> Result<(), GymError> {
             for genome in genomes.iter_mut() {
                 // For this domain:
                 // Inputs: [0] CavesPresent [1] BuildingsPresent [2] IsHomeless
@@ -39,7 +41,7 @@ fn main() {
                     .propagate(&[1.0, 1.0, 1.0])
                     .unwrap();
 
-                if get_index_max_output(&outputs) == 0 {
+                if get_index_max_output(&outputs)? == 0 {
                     let current_fitness = genome.get_fitness();
                     genome.set_fitness(current_fitness + 10.0);
                 }
@@ -49,7 +51,7 @@ fn main() {
                     .propagate(&[1.0, 0.0, 1.0])
                     .unwrap();
 
-                if get_index_max_output(&outputs) == 0 {
+                if get_index_max_output(&outputs)? == 0 {
                     let current_fitness = genome.get_fitness();
                     genome.set_fitness(current_fitness + 10.0);
                 }
@@ -67,7 +69,12 @@ fn main() {
 
     println!("Saving neural network to {:?}", filename);
 
-    save_json(filename, winner.get_neural_network()).unwrap();
+    let mut neural_network = winner.get_neural_network().clone();
+    neural_network
+        .set_io_schema(create_settling_in_io_schema().unwrap())
+        .unwrap();
+
+    save_json(filename, &neural_network).unwrap();
 
     let neural_network = deserialize_json_from_string::<NeuralNetwork<Neuron>>(
         &read_file_to_string(filename).unwrap(),
@@ -2,11 +2,12 @@ extern crate neural_networks;
 extern crate randomization;
 
 use self::neural_networks::evolution::domain::population::Population;
-use self::neural_networks::neuron::NeuronTrait;
 use self::randomization::randomizer::Randomizer;
 use neural_networks::evolution::controllers::create_next_generation::create_next_generation;
 use neural_networks::evolution::domain::create_genome::create_genome;
+use neural_networks::evolution::domain::evolution_config::EvolutionConfig;
 use neural_networks::evolution::domain::genome::Genome;
+use neural_networks::evolution::domain::selection::truncation_selection::TruncationSelection;
 use neural_networks::neural_network::NeuralNetwork;
 use neural_networks::neuron::Neuron;
 use neural_networks::neuron_activation::activation_functions::ActivationFunctions;
@@ -23,6 +24,8 @@ fn main() {
 
         let next_generation = create_next_generation(
             &population,
+            &TruncationSelection::new(0.5),
+            &EvolutionConfig::new(),
             Genome::new,
             NeuralNetwork::new,
             |number_of_inputs, randomizer| {